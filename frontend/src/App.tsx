@@ -1,10 +1,10 @@
 import { useState, useEffect} from 'react';
-import init, { draw_starting_hand, draw_flop, draw_card, evaluate_hand, card_to_str} from "./wasm/odds_engine";
+import init, { draw_starting_hand, draw_flop, draw_card, describe_hand, card_to_str} from "./wasm/odds_engine";
 
 function App() {
   const [hand, setHand] = useState<number[] | null>(null);
   const [flop, setFlop] = useState<number[] | null>(null);
-  const [evaluation, setEvaluation] = useState<number | null>(null);
+  const [evaluation, setEvaluation] = useState<string | null>(null);
   
   useEffect(() => {
     init().then(() => {
@@ -25,8 +25,8 @@ function App() {
   const handleEval = () => {
     if (hand && flop) {
     const input = new Uint32Array([...hand, ...flop]);
-    const ev = evaluate_hand(input)
-    setEvaluation(ev)
+    const ev = describe_hand(input)
+    setEvaluation(ev ?? null)
     }
   };
   
@@ -50,7 +50,7 @@ function App() {
         <button onClick={handleEval}> Evaluate </button>
       )}
       {evaluation && (
-        <p> Your hand is equivalent to {evaluation}</p>
+        <p> You have: {evaluation}</p>
       )}
     </div>
   )
@@ -0,0 +1,196 @@
+// Generates the hand evaluator's lookup tables as static arrays so nothing
+// has to be built when the wasm module starts.
+//
+// Ranks follow Cactus Kev's 1..=7462 scale: 1 is a royal flush and 7462 is
+// 7-5-4-3-2 offsuit.
+
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+#[path = "src/rank_hash.rs"]
+mod rank_hash;
+
+use rank_hash::{rank_count_index, RANK_COUNTS};
+
+// rank bit masks, best first
+const STRAIGHTS: [u32; 10] = [
+    0b1_1111_0000_0000, // A-K-Q-J-T
+    0b1111_1000_0000,
+    0b111_1100_0000,
+    0b11_1110_0000,
+    0b1_1111_0000, // T-9-8-7-6
+    0b1111_1000,
+    0b111_1100,
+    0b11_1110,
+    0b1_1111, // 6-5-4-3-2
+    0b1_0000_0000_1111 // A-5-4-3-2
+];
+
+/// Every `k`-subset of `ranks`, which must be sorted best first, in order
+/// from strongest to weakest.
+fn combinations(ranks: &[usize], k: usize) -> Vec<Vec<usize>> {
+    if k == 0 {
+        return vec![Vec::new()];
+    }
+    let mut out = Vec::new();
+    for i in 0..ranks.len() {
+        for mut rest in combinations(&ranks[i + 1..], k - 1) {
+            rest.insert(0, ranks[i]);
+            out.push(rest);
+        }
+    }
+    out
+}
+
+fn ranks_except(excluded: &[usize]) -> Vec<usize> {
+    (0..13).rev().filter(|r| !excluded.contains(r)).collect()
+}
+
+/// Five-rank masks from best to worst, straights excluded.
+fn high_card_masks() -> Vec<u32> {
+    combinations(&ranks_except(&[]), 5)
+        .iter()
+        .map(|ranks| ranks.iter().fold(0, |acc, &r| acc | (1 << r)))
+        .filter(|mask| !STRAIGHTS.contains(mask))
+        .collect()
+}
+
+/// Best flush rank for every 13-bit rank mask; masks with fewer than five
+/// ranks are 0.
+fn flush_table() -> Vec<u16> {
+    let mut table = vec![0u16; 1 << 13];
+    for (i, &mask) in STRAIGHTS.iter().enumerate() {
+        table[mask as usize] = 1 + i as u16;
+    }
+    // flushes follow the 10 straight flushes, 156 quads and 156 full houses
+    for (i, mask) in high_card_masks().into_iter().enumerate() {
+        table[mask as usize] = 323 + i as u16;
+    }
+
+    for mask in 0..(1u32 << 13) {
+        if mask.count_ones() > 5 {
+            table[mask as usize] = (0..13)
+                .filter(|bit| mask & (1 << bit) != 0)
+                .map(|bit| table[(mask ^ (1 << bit)) as usize])
+                .min()
+                .unwrap();
+        }
+    }
+    table
+}
+
+fn five_card_patterns() -> Vec<[u8; 13]> {
+    let pattern = |groups: &[(usize, u8)]| {
+        let mut counts = [0u8; 13];
+        for &(rank, count) in groups {
+            counts[rank] = count;
+        }
+        counts
+    };
+    let mut out = Vec::new();
+
+    for quads in ranks_except(&[]) {
+        for kicker in ranks_except(&[quads]) {
+            out.push(pattern(&[(quads, 4), (kicker, 1)]));
+        }
+    }
+    for trips in ranks_except(&[]) {
+        for pair in ranks_except(&[trips]) {
+            out.push(pattern(&[(trips, 3), (pair, 2)]));
+        }
+    }
+    // the flushes sit between full houses and straights
+    for &mask in STRAIGHTS.iter() {
+        out.push(pattern(&(0..13).filter(|r| mask & (1 << r) != 0).map(|r| (r, 1)).collect::<Vec<_>>()));
+    }
+    for trips in ranks_except(&[]) {
+        for kickers in combinations(&ranks_except(&[trips]), 2) {
+            out.push(pattern(&[(trips, 3), (kickers[0], 1), (kickers[1], 1)]));
+        }
+    }
+    for pairs in combinations(&ranks_except(&[]), 2) {
+        for kicker in ranks_except(&pairs) {
+            out.push(pattern(&[(pairs[0], 2), (pairs[1], 2), (kicker, 1)]));
+        }
+    }
+    for pair in ranks_except(&[]) {
+        for kickers in combinations(&ranks_except(&[pair]), 3) {
+            out.push(pattern(&[(pair, 2), (kickers[0], 1), (kickers[1], 1), (kickers[2], 1)]));
+        }
+    }
+    for mask in high_card_masks() {
+        out.push(pattern(&(0..13).filter(|r| mask & (1 << r) != 0).map(|r| (r, 1)).collect::<Vec<_>>()));
+    }
+    out
+}
+
+fn for_each_pattern(counts: &mut [u8; 13], rank: usize, remaining: usize, f: &mut impl FnMut(&[u8; 13])) {
+    if rank == 13 {
+        if remaining == 0 {
+            f(counts);
+        }
+        return;
+    }
+    for count in 0..=remaining.min(4) {
+        counts[rank] = count as u8;
+        for_each_pattern(counts, rank + 1, remaining - count, f);
+    }
+    counts[rank] = 0;
+}
+
+/// Best non-flush rank for five, six and seven card rank patterns, indexed
+/// by `rank_count_index`.
+fn unsuited_tables() -> [Vec<u16>; 3] {
+    let mut five = vec![0u16; RANK_COUNTS[13][5] as usize];
+    for (i, counts) in five_card_patterns().iter().enumerate() {
+        // quads and full houses start after the straight flushes, and the
+        // straights after the flushes
+        let rank = if i < 312 { 11 + i } else { 1600 + i - 312 };
+        five[rank_count_index(counts, 5)] = rank as u16;
+    }
+
+    let extend = |smaller: &[u16], n: usize| {
+        let mut table = vec![0u16; RANK_COUNTS[13][n] as usize];
+        for_each_pattern(&mut [0; 13], 0, n, &mut |counts| {
+            let mut best = u16::MAX;
+            let mut fewer = *counts;
+            for r in (0..13).filter(|&r| counts[r] > 0) {
+                fewer[r] -= 1;
+                best = best.min(smaller[rank_count_index(&fewer, n - 1)]);
+                fewer[r] += 1;
+            }
+            table[rank_count_index(counts, n)] = best;
+        });
+        table
+    };
+
+    let six = extend(&five, 6);
+    let seven = extend(&six, 7);
+    [five, six, seven]
+}
+
+fn write_table(out: &mut String, name: &str, table: &[u16]) {
+    writeln!(out, "pub static {}: [u16; {}] = [", name, table.len()).unwrap();
+    for row in table.chunks(16) {
+        let row: Vec<String> = row.iter().map(|v| v.to_string()).collect();
+        writeln!(out, "    {},", row.join(", ")).unwrap();
+    }
+    writeln!(out, "];").unwrap();
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=src/rank_hash.rs");
+
+    let [five, six, seven] = unsuited_tables();
+    let mut out = String::new();
+    write_table(&mut out, "FLUSH_TABLE", &flush_table());
+    write_table(&mut out, "UNSUITED_5", &five);
+    write_table(&mut out, "UNSUITED_6", &six);
+    write_table(&mut out, "UNSUITED_7", &seven);
+
+    let path = Path::new(&env::var("OUT_DIR").unwrap()).join("lookup_tables.rs");
+    fs::write(path, out).unwrap();
+}
@@ -0,0 +1,249 @@
+// Generates src/preflop_tables.rs, the preflop equities the engine ships
+// with, so the trainer can answer preflop questions without simulating:
+//
+//     cargo run --release --example preflop_tables
+//
+// Heads-up figures are exact: every pair of combos is compared on every
+// board, which gives both the class-vs-class matrix and each class against
+// one random hand. Enumerating every board against several random hands is
+// out of reach, so equities against two or more are seeded Monte Carlo
+// estimates with enough trials to be good to a few tenths of a percent.
+// The seed is fixed, so running the generator again reproduces the file.
+//
+// Equities are stored in hundredths of a percent. The heads-up matrix keeps
+// only the classes above the diagonal, row by row: the rest follows from
+// `equity(b, a) == 100 - equity(a, b)` and every class being 50% against
+// itself.
+
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::fs;
+use itertools::Itertools;
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use odds_engine::canonical::{canonicalize, PreflopClass};
+use odds_engine::card::Card;
+use odds_engine::card_set::CardSet;
+use odds_engine::evaluate::evaluate_cards;
+use odds_engine::preflop::MULTIWAY_TRIALS;
+
+const SEED: u64 = 169;
+const MAX_OPPONENTS: usize = 9;
+// C(48, 5): the boards left once two hands are dealt
+const BOARDS_PER_PAIR: u64 = 1_712_304;
+
+fn rank(hole_cards: &[Card], board: &[Card]) -> u32 {
+    let mut cards = [hole_cards[0]; 7];
+    cards[1] = hole_cards[1];
+    cards[2..].copy_from_slice(board);
+    evaluate_cards(&cards)
+}
+
+// Hero's share of the pot against `opponents` random hands, averaged over
+// `trials` random deals.
+fn equity_vs_random(hero: [Card; 2], opponents: usize, trials: u32, rng: &mut ChaCha8Rng) -> f64 {
+    let live = CardSet::FULL - hero.iter().collect();
+    let mut total = 0.0;
+    for _ in 0..trials {
+        let dealt = live.sample(2 * opponents + 5, rng);
+        let (board, hands) = dealt.split_at(5);
+        let hero_rank = rank(&hero, board);
+
+        let mut tied = 1;
+        let mut lost = false;
+        for hand in hands.chunks(2) {
+            let villain_rank = rank(hand, board);
+            if villain_rank < hero_rank {
+                lost = true;
+                break;
+            }
+            if villain_rank == hero_rank {
+                tied += 1;
+            }
+        }
+        if !lost {
+            total += 1.0 / tied as f64;
+        }
+    }
+    100.0 * total / trials as f64
+}
+
+// Ordered pairs of combos, hero's from class `i` and villain's from class
+// `j`, summed over every board: `wins[i * 169 + j]` where hero's hand is
+// better and `ties[i * 169 + j]` where the two are level. Every pair of
+// combos that don't share a card meets on C(48, 5) boards, so each entry
+// divided by that is a whole number of combo pairs.
+struct Showdowns {
+    wins: Vec<u64>,
+    ties: Vec<u64>,
+}
+
+// Every 5-card board up to a change of suits, with how many boards it
+// stands for. A pair of classes does as well on each board as on any other
+// with the suits relabelled, so the totals only need one of each.
+fn canonical_boards() -> Vec<(Vec<Card>, u64)> {
+    let mut boards: HashMap<Vec<Card>, u64> = HashMap::new();
+    for board in Card::all().combinations(5) {
+        *boards.entry(canonicalize(&[], &board).unwrap().board).or_default() += 1;
+    }
+    let mut boards: Vec<(Vec<Card>, u64)> = boards.into_iter().collect();
+    boards.sort();
+    boards
+}
+
+// Compares every pair of hole cards on every board. Rather than visiting
+// the ~580,000 pairs a board has, each combo is credited at once with every
+// worse or equal combo of each class, then debited the few that share one
+// of its cards and so couldn't have been dealt against it.
+fn showdowns() -> Showdowns {
+    let n = PreflopClass::COUNT;
+    let mut totals = Showdowns { wins: vec![0; n * n], ties: vec![0; n * n] };
+    let boards = canonical_boards();
+    eprintln!("{} boards up to suits", boards.len());
+
+    for (done, (board, weight)) in boards.iter().enumerate() {
+        let live: Vec<Card> = (CardSet::FULL - board.iter().collect()).iter().collect();
+        let mut combos: Vec<(u32, usize, [Card; 2])> = live.iter().copied().tuple_combinations()
+            .map(|(a, b)| (rank(&[a, b], board), PreflopClass::from_cards(&[a, b]).unwrap().index(), [a, b]))
+            .collect();
+        // worst first
+        combos.sort_by_key(|&(rank, _, _)| Reverse(rank));
+
+        let mut holding: Vec<Vec<usize>> = vec![Vec::new(); 52];
+        for (k, &(_, _, cards)) in combos.iter().enumerate() {
+            holding[cards[0].index()].push(k);
+            holding[cards[1].index()].push(k);
+        }
+
+        let mut worse = vec![0u64; n];
+        let mut start = 0;
+        while start < combos.len() {
+            let end = start + combos[start..].iter().take_while(|c| c.0 == combos[start].0).count();
+            let mut level = vec![0u64; n];
+            for &(_, class, _) in &combos[start..end] {
+                level[class] += 1;
+            }
+
+            for (k, &(hero_rank, hero, cards)) in combos.iter().enumerate().take(end).skip(start) {
+                let (wins, ties) = (&mut totals.wins[hero * n..(hero + 1) * n], &mut totals.ties[hero * n..(hero + 1) * n]);
+                for villain in 0..n {
+                    wins[villain] += weight * worse[villain];
+                    ties[villain] += weight * level[villain];
+                }
+                ties[hero] -= weight;
+                for &other in holding[cards[0].index()].iter().chain(&holding[cards[1].index()]) {
+                    let (villain_rank, villain, _) = combos[other];
+                    if other == k {
+                        continue;
+                    }
+                    if villain_rank > hero_rank {
+                        wins[villain] -= weight;
+                    } else if villain_rank == hero_rank {
+                        ties[villain] -= weight;
+                    }
+                }
+            }
+
+            for class in 0..n {
+                worse[class] += level[class];
+            }
+            start = end;
+        }
+
+        if done % 10_000 == 0 {
+            eprintln!("board {} of {}", done, boards.len());
+        }
+    }
+    totals
+}
+
+// Ordered pairs of combos from `hero` and `villain` that share no card,
+// times the boards each pair meets on: what `Showdowns` entries add up to.
+fn meetings(hero: PreflopClass, villain: PreflopClass) -> u64 {
+    let pairs = hero.combos().iter()
+        .flat_map(|h| villain.combos().into_iter().filter(move |v| !v.iter().any(|c| h.contains(c))))
+        .count() as u64;
+    pairs * BOARDS_PER_PAIR
+}
+
+// Exact heads-up equity of every class against every other, indexed
+// `hero * 169 + villain`.
+fn heads_up(showdowns: &Showdowns) -> Vec<f64> {
+    let classes: Vec<PreflopClass> = PreflopClass::all().collect();
+    let n = classes.len();
+    let mut equity = vec![0.0; n * n];
+    for (i, &hero) in classes.iter().enumerate() {
+        for (j, &villain) in classes.iter().enumerate() {
+            let (wins, ties) = (showdowns.wins[i * n + j], showdowns.ties[i * n + j]);
+            let meetings = meetings(hero, villain);
+            let settled = if i == j { 2 * wins + ties } else { wins + showdowns.wins[j * n + i] + ties };
+            assert_eq!(settled, meetings, "{} vs {} doesn't add up", hero, villain);
+            equity[i * n + j] = 100.0 * (2 * wins + ties) as f64 / (2 * meetings) as f64;
+        }
+    }
+    equity
+}
+
+// Exact heads-up equity of each class against one random hand: its
+// showdowns against every class, pooled.
+fn heads_up_vs_random(showdowns: &Showdowns) -> Vec<f64> {
+    let classes: Vec<PreflopClass> = PreflopClass::all().collect();
+    let n = classes.len();
+    classes.iter().enumerate().map(|(i, &hero)| {
+        let points: u64 = (0..n).map(|j| 2 * showdowns.wins[i * n + j] + showdowns.ties[i * n + j]).sum();
+        let meetings: u64 = classes.iter().map(|&villain| meetings(hero, villain)).sum();
+        100.0 * points as f64 / (2 * meetings) as f64
+    }).collect()
+}
+
+fn hundredths(equity: f64) -> u16 {
+    (equity * 100.0).round() as u16
+}
+
+fn main() {
+    let classes: Vec<PreflopClass> = PreflopClass::all().collect();
+    let showdowns = showdowns();
+    let matchups = heads_up(&showdowns);
+    let vs_one = heads_up_vs_random(&showdowns);
+
+    let mut rng = ChaCha8Rng::seed_from_u64(SEED);
+    let mut out = String::new();
+    writeln!(out, "// Generated by `cargo run --release --example preflop_tables`. Do not edit.").unwrap();
+    writeln!(out).unwrap();
+
+    writeln!(out, "/// Equity of each preflop class, in `PreflopClass::index` order, against").unwrap();
+    writeln!(out, "/// 1 to {} random hands, in hundredths of a percent. Exact against one", MAX_OPPONENTS).unwrap();
+    writeln!(out, "/// hand and estimated from {} deals against more.", MULTIWAY_TRIALS).unwrap();
+    writeln!(out, "pub static VS_RANDOM: [[u16; {}]; 169] = [", MAX_OPPONENTS).unwrap();
+    for (i, &class) in classes.iter().enumerate() {
+        let hero = class.combos()[0];
+        let mut row = vec![hundredths(vs_one[i]).to_string()];
+        row.extend((2..=MAX_OPPONENTS).map(|opponents| {
+            hundredths(equity_vs_random(hero, opponents, MULTIWAY_TRIALS, &mut rng)).to_string()
+        }));
+        writeln!(out, "    [{}], // {}", row.join(", "), class).unwrap();
+        eprintln!("{}", class);
+    }
+    writeln!(out, "];").unwrap();
+
+    writeln!(out).unwrap();
+    writeln!(out, "/// Heads-up equity of class `i` against class `j`, for every `i < j` in").unwrap();
+    writeln!(out, "/// `PreflopClass::index` order, row by row, in hundredths of a percent.").unwrap();
+    writeln!(out, "pub static HEADS_UP: [u16; {}] = [", 169 * 168 / 2).unwrap();
+    for (i, &hero) in classes.iter().enumerate() {
+        let row: Vec<String> = (i + 1..classes.len())
+            .map(|j| hundredths(matchups[i * classes.len() + j]).to_string())
+            .collect();
+        if !row.is_empty() {
+            writeln!(out, "    // {}", hero).unwrap();
+            for line in row.chunks(16) {
+                writeln!(out, "    {},", line.join(", ")).unwrap();
+            }
+        }
+    }
+    writeln!(out, "];").unwrap();
+
+    let path = concat!(env!("CARGO_MANIFEST_DIR"), "/src/preflop_tables.rs");
+    fs::write(path, out).unwrap();
+}
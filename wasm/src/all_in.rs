@@ -0,0 +1,326 @@
+use itertools::Itertools;
+use rand::thread_rng;
+use crate::card::Card;
+use crate::card_set::CardSet;
+use crate::evaluate::evaluate_board;
+use crate::hand_history::{ActionKind, Currency, HandHistory, Street};
+
+/// One pot of a hand: the main pot, or a side pot only the players who
+/// covered it can win.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Pot {
+    pub amount: f64,
+    /// Indexes into the contributions the pot was built from.
+    pub eligible: Vec<usize>,
+}
+
+/// Splits what each player put in into a main pot and side pots. `live`
+/// marks the players still in the hand; folded players' chips go into the
+/// pots but they can't win any.
+pub fn side_pots(contributions: &[f64], live: &[bool]) -> Vec<Pot> {
+    let mut levels: Vec<f64> = contributions.iter().zip(live)
+        .filter(|&(&c, &l)| l && c > 0.0)
+        .map(|(&c, _)| c)
+        .collect();
+    levels.sort_by(f64::total_cmp);
+    levels.dedup();
+
+    let mut pots: Vec<Pot> = Vec::new();
+    let mut previous = 0.0;
+    for &level in &levels {
+        let amount: f64 = contributions.iter().map(|&c| c.min(level) - c.min(previous)).sum();
+        let eligible = (0..contributions.len()).filter(|&i| live[i] && contributions[i] >= level).collect();
+        pots.push(Pot { amount, eligible });
+        previous = level;
+    }
+
+    // chips from a folded player beyond what anyone still in matched
+    let leftover: f64 = contributions.iter().map(|&c| (c - previous).max(0.0)).sum();
+    if let Some(last) = pots.last_mut() {
+        last.amount += leftover;
+    }
+    pots
+}
+
+/// Each player's expected winnings from `pots` when the rest of the board is
+/// dealt. `hands` is in the same order as the pots' indexes, with `None` for
+/// players out of the hand. Exact once at most two cards are to come,
+/// otherwise averaged over `iterations` random runouts, so `None` if that
+/// is zero.
+pub fn side_pot_equity(hands: &[Option<[Card; 2]>], board: &[Card], pots: &[Pot], iterations: u32) -> Option<Vec<f64>> {
+    let dead: CardSet = hands.iter().flatten().flatten().chain(board).collect();
+    let live = CardSet::FULL - dead;
+    let missing = 5 - board.len();
+    if missing > 2 && iterations == 0 {
+        return None;
+    }
+
+    let mut totals = vec![0.0; hands.len()];
+    let mut runouts = 0;
+    let mut settle = |runout: Vec<Card>| {
+        let mut full_board = board.to_vec();
+        full_board.extend(runout);
+        let ranks: Vec<Option<u32>> = hands.iter().map(|hand| {
+            hand.map(|cards| evaluate_board([&cards[..], &full_board].concat()))
+        }).collect();
+
+        for pot in pots {
+            // lower scores are stronger hands
+            let best = pot.eligible.iter().filter_map(|&i| ranks[i]).min();
+            let winners: Vec<usize> = pot.eligible.iter().copied().filter(|&i| ranks[i] == best).collect();
+            for &i in &winners {
+                totals[i] += pot.amount / winners.len() as f64;
+            }
+        }
+        runouts += 1;
+    };
+
+    if missing <= 2 {
+        live.iter().combinations(missing).for_each(&mut settle);
+    } else {
+        let mut rng = thread_rng();
+        (0..iterations).for_each(|_| settle(live.sample(missing, &mut rng)));
+    }
+
+    Some(totals.iter().map(|t| t / runouts as f64).collect())
+}
+
+/// One player's part in an all-in.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AllInPlayer {
+    pub player: String,
+    /// Chips put in over the whole hand.
+    pub invested: f64,
+    /// Share of the pots, after rake, the player could expect when the
+    /// money went in.
+    pub expected: f64,
+    /// What the player actually collected.
+    pub won: f64,
+    /// `expected` as a percentage of everything in the pots.
+    pub equity: f64,
+}
+
+/// A hand where players were all in with cards still to come.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AllIn {
+    pub hand_id: String,
+    /// The street the last chips went in on.
+    pub street: Street,
+    pub board: Vec<Card>,
+    /// The players still in, in seat order.
+    pub players: Vec<AllInPlayer>,
+}
+
+/// Finds the all-in in `hand`, if there was one that could be measured:
+/// someone all in, at least two players left with cards to come, and every
+/// one of their hands shown. Preflop and flop all-ins also need
+/// `iterations` of at least 1.
+pub fn find_all_in(hand: &HandHistory, iterations: u32) -> Option<AllIn> {
+    if !hand.actions.iter().any(|a| a.all_in) {
+        return None;
+    }
+    let street = hand.actions.iter()
+        .filter(|a| !matches!(a.kind, ActionKind::Uncalled(_)))
+        .map(|a| a.street)
+        .next_back()?;
+    let board = hand.board.get(..street.board_len())?.to_vec();
+    if board.len() == 5 {
+        return None;
+    }
+
+    let live: Vec<bool> = hand.seats.iter().map(|s| !hand.folded(&s.player) && !s.sitting_out).collect();
+    let contributions = hand.contributions();
+    let players: Vec<usize> = (0..hand.seats.len()).filter(|&i| live[i] && contributions[i] > 0.0).collect();
+    if players.len() < 2 || players.iter().any(|&i| hand.seats[i].hole_cards.is_none()) {
+        return None;
+    }
+
+    // rake comes out of every pot in proportion
+    let total: f64 = contributions.iter().sum();
+    let after_rake = if total > 0.0 { (total - hand.rake) / total } else { 1.0 };
+    let mut pots = side_pots(&contributions, &live);
+    pots.iter_mut().for_each(|pot| pot.amount *= after_rake);
+
+    let hands: Vec<Option<[Card; 2]>> = (0..hand.seats.len())
+        .map(|i| if players.contains(&i) { hand.seats[i].hole_cards } else { None })
+        .collect();
+    let expected = side_pot_equity(&hands, &board, &pots, iterations)?;
+    let in_pots: f64 = pots.iter().map(|p| p.amount).sum();
+
+    let players = players.into_iter().map(|i| {
+        let player = hand.seats[i].player.clone();
+        let won = hand.collected.iter().filter(|(p, _)| *p == player).map(|(_, amount)| amount).sum();
+        AllInPlayer { player, invested: contributions[i], expected: expected[i], won, equity: 100.0 * expected[i] / in_pots }
+    }).collect();
+
+    Some(AllIn { hand_id: hand.hand_id.clone(), street, board, players })
+}
+
+/// One player's results over a set of hands played for one currency, with
+/// the luck of their all-ins taken out.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LuckSummary {
+    pub player: String,
+    /// What every amount below is counted in.
+    pub currency: Currency,
+    pub hands: u32,
+    pub all_ins: u32,
+    /// Net money or chips won or lost over every hand.
+    pub net_won: f64,
+    /// How much more the all-ins paid than their equity was worth; negative
+    /// when the player ran below expectation.
+    pub luck: f64,
+    /// `net_won` with each all-in paid out at its equity instead.
+    pub adjusted: f64,
+}
+
+/// Totals every player's results over `hands`, in order of first
+/// appearance, and swaps the actual result of each all-in for its expected
+/// value so skill can be told apart from variance. Cash games in different
+/// currencies and different tournaments are totalled apart, so a player can
+/// have several summaries.
+pub fn luck_summary(hands: &[HandHistory], iterations: u32) -> Vec<LuckSummary> {
+    let mut summaries: Vec<LuckSummary> = Vec::new();
+    for hand in hands {
+        let all_in = find_all_in(hand, iterations);
+        for (seat, invested) in hand.seats.iter().zip(hand.contributions()) {
+            if seat.sitting_out {
+                continue;
+            }
+            let index = match summaries.iter().position(|s| s.player == seat.player && s.currency == hand.currency) {
+                Some(index) => index,
+                None => {
+                    summaries.push(LuckSummary {
+                        player: seat.player.clone(), currency: hand.currency.clone(),
+                        hands: 0, all_ins: 0, net_won: 0.0, luck: 0.0, adjusted: 0.0,
+                    });
+                    summaries.len() - 1
+                }
+            };
+
+            let summary = &mut summaries[index];
+            let won: f64 = hand.collected.iter().filter(|(p, _)| *p == seat.player).map(|(_, amount)| amount).sum();
+            summary.hands += 1;
+            summary.net_won += won - invested;
+            if let Some(player) = all_in.iter().flat_map(|a| &a.players).find(|p| p.player == seat.player) {
+                summary.all_ins += 1;
+                summary.luck += player.won - player.expected;
+            }
+            summary.adjusted = summary.net_won - summary.luck;
+        }
+    }
+    summaries
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::card::parse_cards;
+    use crate::equity::enumerate_equity;
+    use crate::hand_history::{parse_hand_histories, parse_hand_history};
+
+    const CASH_SHOWDOWN: &str = include_str!("../testdata/cash_showdown.txt");
+    const TOURNAMENT_SIDE_POT: &str = include_str!("../testdata/tournament_side_pot.txt");
+    const ALL_IN_FLOP: &str = include_str!("../testdata/all_in_flop.txt");
+
+    fn player<'a>(all_in: &'a AllIn, name: &str) -> &'a AllInPlayer {
+        all_in.players.iter().find(|p| p.player == name).unwrap()
+    }
+
+    #[test]
+    fn builds_side_pots() {
+        let pots = side_pots(&[600.0, 1400.0, 1400.0, 5.0], &[true, true, true, false]);
+        assert_eq!(pots, vec![
+            Pot { amount: 1805.0, eligible: vec![0, 1, 2] },
+            Pot { amount: 1600.0, eligible: vec![1, 2] },
+        ]);
+
+        // a folded player's chips go to the pot they could have won
+        let pots = side_pots(&[10.0, 30.0, 50.0], &[true, false, true]);
+        assert_eq!(pots, vec![
+            Pot { amount: 30.0, eligible: vec![0, 2] },
+            Pot { amount: 60.0, eligible: vec![2] },
+        ]);
+    }
+
+    #[test]
+    fn flop_all_in_is_exact() {
+        let hand = parse_hand_history(ALL_IN_FLOP).unwrap();
+        let all_in = find_all_in(&hand, 0).unwrap();
+        assert_eq!(all_in.street, Street::Flop);
+        assert_eq!(all_in.board, parse_cards("Kd 7c 2h").unwrap());
+        assert_eq!(all_in.players.len(), 2);
+
+        let aces = player(&all_in, "Hero");
+        let exact = enumerate_equity(&parse_cards("AsAh").unwrap(), &parse_cards("KsKh").unwrap(), &all_in.board).unwrap();
+        assert!((aces.equity - exact.equity).abs() < 1e-9);
+        assert!((aces.expected - 157.5 * exact.equity / 100.0).abs() < 1e-9);
+        assert_eq!((aces.invested, aces.won), (80.0, 157.5));
+
+        let kings = player(&all_in, "KingMe");
+        assert!((aces.expected + kings.expected - 157.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn three_way_all_in_with_a_side_pot() {
+        let hand = parse_hand_history(TOURNAMENT_SIDE_POT).unwrap();
+        let all_in = find_all_in(&hand, 20000).unwrap();
+        assert_eq!(all_in.street, Street::Preflop);
+
+        let expected: f64 = all_in.players.iter().map(|p| p.expected).sum();
+        assert!((expected - 3405.0).abs() < 1e-6);
+        // the short stack can only win the main pot
+        let shorty = player(&all_in, "ShortyMcShort");
+        assert!(shorty.expected < 1805.0 && shorty.won == 1805.0);
+        // queens are well ahead of J-T for the side pot and so for the whole
+        let queens = player(&all_in, "Hero");
+        assert!(queens.equity > player(&all_in, "Villain_B").equity);
+        assert!(queens.expected > queens.won);
+    }
+
+    #[test]
+    fn estimates_need_iterations() {
+        let hand = parse_hand_history(TOURNAMENT_SIDE_POT).unwrap();
+        assert_eq!(find_all_in(&hand, 0), None);
+        let aces = parse_cards("AsAh").unwrap();
+        let kings = parse_cards("KsKh").unwrap();
+        let hands = [Some([aces[0], aces[1]]), Some([kings[0], kings[1]])];
+        let pots = [Pot { amount: 10.0, eligible: vec![0, 1] }];
+        assert_eq!(side_pot_equity(&hands, &[], &pots, 0), None);
+        assert!(side_pot_equity(&hands, &parse_cards("Kd 7c 2h").unwrap(), &pots, 0).is_some());
+    }
+
+    #[test]
+    fn no_all_in_without_cards_to_come() {
+        assert_eq!(find_all_in(&parse_hand_history(CASH_SHOWDOWN).unwrap(), 100), None);
+    }
+
+    #[test]
+    fn summary_takes_the_luck_out_of_all_ins() {
+        let text = [CASH_SHOWDOWN, TOURNAMENT_SIDE_POT, ALL_IN_FLOP].join("\n\n");
+        let hands = parse_hand_histories(&text).unwrap();
+        let summaries = luck_summary(&hands, 20000);
+
+        // dollars and tournament chips are kept apart
+        let usd = Currency::Cash(Some("USD".to_string()));
+        let chips = Currency::Chips("3456789012".to_string());
+        let hero: Vec<&LuckSummary> = summaries.iter().filter(|s| s.player == "Hero").collect();
+        assert_eq!(hero.len(), 2);
+        let (cash, tournament) = (hero[0], hero[1]);
+        assert_eq!((&cash.currency, cash.hands, cash.all_ins), (&usd, 2, 1));
+        assert!((cash.net_won - (-0.74 + 77.5)).abs() < 1e-9);
+        assert!((cash.adjusted - (cash.net_won - cash.luck)).abs() < 1e-9);
+        assert_eq!((&tournament.currency, tournament.hands, tournament.all_ins), (&chips, 1, 1));
+        assert!((tournament.net_won - 200.0).abs() < 1e-9);
+
+        // aces got there against a set, which was lucky
+        let flop = find_all_in(&hands[2], 0).unwrap();
+        let aces = player(&flop, "Hero");
+        assert!(aces.won - aces.expected > 100.0);
+
+        let kings = summaries.iter().find(|s| s.player == "KingMe").unwrap();
+        assert_eq!((kings.hands, kings.all_ins), (1, 1));
+        assert!((kings.luck + (aces.won - aces.expected)).abs() < 1e-9);
+        assert!(summaries.iter().all(|s| s.player != "sleepy"));
+    }
+}
@@ -0,0 +1,392 @@
+use std::cmp::Reverse;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+use itertools::Itertools;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use wasm_bindgen::prelude::*;
+use crate::card::{Card, Rank, Suit, cards_from_u32};
+
+/// One of the 169 starting hands once suits are ignored: a pair, or two
+/// ranks either suited or offsuit.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct PreflopClass {
+    pub high: Rank,
+    pub low: Rank,
+    /// Always false for pairs.
+    pub suited: bool,
+}
+
+impl PreflopClass {
+    pub const COUNT: usize = 169;
+
+    pub fn new(a: Rank, b: Rank, suited: bool) -> Option<PreflopClass> {
+        if a == b && suited {
+            return None;
+        }
+        Some(PreflopClass { high: a.max(b), low: a.min(b), suited })
+    }
+
+    /// The class of two hole cards.
+    pub fn from_cards(hole_cards: &[Card]) -> Option<PreflopClass> {
+        match *hole_cards {
+            [a, b] if a != b => PreflopClass::new(a.rank(), b.rank(), a.suit() == b.suit()),
+            _ => None,
+        }
+    }
+
+    pub fn is_pair(self) -> bool {
+        self.high == self.low
+    }
+
+    /// Position in the usual 13x13 grid: pairs on the diagonal, suited hands
+    /// with the high rank as the row, offsuit hands with it as the column.
+    pub fn index(self) -> usize {
+        let (high, low) = (self.high.index(), self.low.index());
+        if self.suited { high * 13 + low } else { low * 13 + high }
+    }
+
+    pub fn from_index(index: usize) -> Option<PreflopClass> {
+        if index >= PreflopClass::COUNT {
+            return None;
+        }
+        let (row, col) = (Rank::ALL[index / 13], Rank::ALL[index % 13]);
+        PreflopClass::new(row, col, row > col)
+    }
+
+    /// All 169 classes in `index` order.
+    pub fn all() -> impl Iterator<Item = PreflopClass> {
+        (0..PreflopClass::COUNT).filter_map(PreflopClass::from_index)
+    }
+
+    /// How many two-card combos fall in this class: 6, 4 or 12.
+    pub fn combo_count(self) -> u32 {
+        if self.is_pair() { 6 } else if self.suited { 4 } else { 12 }
+    }
+
+    /// Every combo in the class, high card first.
+    pub fn combos(self) -> Vec<[Card; 2]> {
+        let mut combos = Vec::new();
+        for (s1, &suit1) in Suit::ALL.iter().enumerate() {
+            for (s2, &suit2) in Suit::ALL.iter().enumerate() {
+                let wanted = if self.is_pair() { s1 < s2 } else { self.suited == (s1 == s2) };
+                if wanted {
+                    combos.push([Card::new(self.high, suit1), Card::new(self.low, suit2)]);
+                }
+            }
+        }
+        combos
+    }
+
+    pub fn group(self) -> StartingHandGroup {
+        let suited = self.suited;
+        if self.is_pair() {
+            StartingHandGroup::Pair
+        } else if self.low >= Rank::Ten {
+            if suited { StartingHandGroup::SuitedBroadway } else { StartingHandGroup::OffsuitBroadway }
+        } else if self.high == Rank::Ace {
+            if suited { StartingHandGroup::SuitedAce } else { StartingHandGroup::OffsuitAce }
+        } else if self.high.index() - self.low.index() <= 2 {
+            if suited { StartingHandGroup::SuitedConnector } else { StartingHandGroup::OffsuitConnector }
+        } else if suited {
+            StartingHandGroup::Suited
+        } else {
+            StartingHandGroup::Offsuit
+        }
+    }
+}
+
+/// A coarse grouping of starting hands for spotting patterns in guesses.
+/// Each class falls in the first group that fits: pairs, two broadway
+/// cards, an ace with a smaller kicker, connectors with at most one gap,
+/// then everything else.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum StartingHandGroup {
+    Pair,
+    SuitedBroadway,
+    OffsuitBroadway,
+    SuitedAce,
+    OffsuitAce,
+    SuitedConnector,
+    OffsuitConnector,
+    Suited,
+    Offsuit,
+}
+
+impl StartingHandGroup {
+    pub const ALL: [StartingHandGroup; 9] = [
+        StartingHandGroup::Pair, StartingHandGroup::SuitedBroadway, StartingHandGroup::OffsuitBroadway,
+        StartingHandGroup::SuitedAce, StartingHandGroup::OffsuitAce, StartingHandGroup::SuitedConnector,
+        StartingHandGroup::OffsuitConnector, StartingHandGroup::Suited, StartingHandGroup::Offsuit,
+    ];
+}
+
+impl fmt::Display for StartingHandGroup {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            StartingHandGroup::Pair => "pair",
+            StartingHandGroup::SuitedBroadway => "suited broadway",
+            StartingHandGroup::OffsuitBroadway => "offsuit broadway",
+            StartingHandGroup::SuitedAce => "suited ace",
+            StartingHandGroup::OffsuitAce => "offsuit ace",
+            StartingHandGroup::SuitedConnector => "suited connector",
+            StartingHandGroup::OffsuitConnector => "offsuit connector",
+            StartingHandGroup::Suited => "other suited",
+            StartingHandGroup::Offsuit => "other offsuit",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+impl fmt::Display for PreflopClass {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.high, self.low)?;
+        match (self.is_pair(), self.suited) {
+            (true, _) => Ok(()),
+            (false, true) => write!(f, "s"),
+            (false, false) => write!(f, "o"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PreflopClassErrorKind {
+    /// The input ran out early or has characters left over.
+    WrongLength,
+    InvalidRank(char),
+    /// Two different ranks must be followed by 's' or 'o'.
+    InvalidSuffix(char),
+    /// A pair can't be suited or offsuit, so takes no suffix.
+    PairWithSuffix,
+}
+
+/// Error from parsing a `PreflopClass`. `position` is the 0-based character
+/// offset in the input where the problem starts.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PreflopClassParseError {
+    pub position: usize,
+    pub kind: PreflopClassErrorKind,
+}
+
+impl fmt::Display for PreflopClassParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            PreflopClassErrorKind::WrongLength => write!(f, "expected a starting hand like \"AKs\", \"T9o\" or \"77\"")?,
+            PreflopClassErrorKind::InvalidRank(c) => write!(f, "invalid rank '{}'", c)?,
+            PreflopClassErrorKind::InvalidSuffix(c) => write!(f, "expected 's' or 'o', found '{}'", c)?,
+            PreflopClassErrorKind::PairWithSuffix => write!(f, "a pair can't be suited or offsuit")?,
+        }
+        write!(f, " at position {}", self.position)
+    }
+}
+
+impl std::error::Error for PreflopClassParseError {}
+
+/// Parses "AKs", "T9o" or "77".
+impl FromStr for PreflopClass {
+    type Err = PreflopClassParseError;
+
+    fn from_str(s: &str) -> Result<PreflopClass, PreflopClassParseError> {
+        let chars: Vec<char> = s.chars().collect();
+        let error = |position, kind| PreflopClassParseError { position, kind };
+        let rank = |i: usize| {
+            let c = *chars.get(i).ok_or(error(i, PreflopClassErrorKind::WrongLength))?;
+            Rank::from_char(c).ok_or(error(i, PreflopClassErrorKind::InvalidRank(c)))
+        };
+
+        let (a, b) = (rank(0)?, rank(1)?);
+        let suited = match (a == b, chars.get(2)) {
+            (true, Some('s' | 'o')) => return Err(error(2, PreflopClassErrorKind::PairWithSuffix)),
+            (true, _) => false,
+            (false, Some('s')) => true,
+            (false, Some('o')) => false,
+            (false, Some(&c)) => return Err(error(2, PreflopClassErrorKind::InvalidSuffix(c))),
+            (false, None) => return Err(error(2, PreflopClassErrorKind::WrongLength)),
+        };
+        let len = if a == b { 2 } else { 3 };
+        if chars.len() > len {
+            return Err(error(len, PreflopClassErrorKind::WrongLength));
+        }
+        Ok(PreflopClass { high: a.max(b), low: a.min(b), suited })
+    }
+}
+
+/// Classes are stored as their names, like "AKs".
+impl Serialize for PreflopClass {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PreflopClass {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<PreflopClass, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        name.parse().map_err(de::Error::custom)
+    }
+}
+
+/// Hole cards and board with the suits relabelled so that every deal which
+/// only differs by a permutation of suits maps to the same value. Both groups
+/// are treated as sets and sorted high card first.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct CanonicalDeal {
+    pub hole_cards: Vec<Card>,
+    pub board: Vec<Card>,
+}
+
+// Sorts high card first, and lower suits first within a rank.
+fn sort_key(card: &Card) -> (Reverse<Rank>, Suit) {
+    (Reverse(card.rank()), card.suit())
+}
+
+fn relabel(cards: &[Card], suits: &[Suit]) -> Vec<Card> {
+    let mut cards: Vec<Card> = cards.iter().map(|c| Card::new(c.rank(), suits[c.suit().index()])).collect();
+    cards.sort_by_key(sort_key);
+    cards
+}
+
+/// Picks, out of the 24 suit permutations, the one whose relabelled deal
+/// sorts first. Returns `None` if any card repeats.
+pub fn canonicalize(hole_cards: &[Card], board: &[Card]) -> Option<CanonicalDeal> {
+    let all = [hole_cards, board].concat();
+    if all.iter().unique().count() != all.len() {
+        return None;
+    }
+
+    Suit::ALL.into_iter().permutations(4)
+        .map(|suits| CanonicalDeal { hole_cards: relabel(hole_cards, &suits), board: relabel(board, &suits) })
+        .min_by_key(|deal| {
+            let keys = |cards: &[Card]| cards.iter().map(sort_key).collect::<Vec<_>>();
+            (keys(&deal.hole_cards), keys(&deal.board))
+        })
+}
+
+/// The 1,755 strategically distinct flops, each with the number of the
+/// 22,100 concrete flops it stands for.
+pub fn flop_classes() -> Vec<([Card; 3], u32)> {
+    let mut classes: BTreeMap<[Card; 3], u32> = BTreeMap::new();
+    for flop in Card::all().combinations(3) {
+        *classes.entry(flop_class(&flop).unwrap()).or_default() += 1;
+    }
+    classes.into_iter().collect()
+}
+
+/// The canonical form of a three card flop, as listed by `flop_classes`.
+pub fn flop_class(flop: &[Card]) -> Option<[Card; 3]> {
+    if flop.len() != 3 {
+        return None;
+    }
+    let board = canonicalize(&[], flop)?.board;
+    Some([board[0], board[1], board[2]])
+}
+
+/// The name of the hole cards' class, like "AKs".
+#[wasm_bindgen(js_name = preflop_class)]
+pub fn preflop_class_js(hole_cards: Vec<u32>) -> Option<String> {
+    PreflopClass::from_cards(&cards_from_u32(&hole_cards)?).map(|class| class.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::card::parse_cards;
+
+    fn class(text: &str) -> PreflopClass {
+        PreflopClass::from_cards(&parse_cards(text).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn serializes_as_names() {
+        let classes: Vec<PreflopClass> = ["AKs", "T9o", "77"].iter().map(|n| n.parse().unwrap()).collect();
+        let json = serde_json::to_string(&classes).unwrap();
+        assert_eq!(json, r#"["AKs","T9o","77"]"#);
+        assert_eq!(serde_json::from_str::<Vec<PreflopClass>>(&json).unwrap(), classes);
+        assert!(serde_json::from_str::<PreflopClass>(r#""77s""#).is_err());
+    }
+
+    #[test]
+    fn starting_hand_groups() {
+        let group = |name: &str| name.parse::<PreflopClass>().unwrap().group();
+        assert_eq!(group("22"), StartingHandGroup::Pair);
+        assert_eq!(group("KTs"), StartingHandGroup::SuitedBroadway);
+        assert_eq!(group("AJo"), StartingHandGroup::OffsuitBroadway);
+        assert_eq!(group("A5s"), StartingHandGroup::SuitedAce);
+        assert_eq!(group("A9o"), StartingHandGroup::OffsuitAce);
+        assert_eq!(group("65s"), StartingHandGroup::SuitedConnector);
+        assert_eq!(group("T8o"), StartingHandGroup::OffsuitConnector);
+        assert_eq!(group("K2s"), StartingHandGroup::Suited);
+        assert_eq!(group("72o"), StartingHandGroup::Offsuit);
+
+        let counts = StartingHandGroup::ALL.map(|g| PreflopClass::all().filter(|class| class.group() == g).count());
+        assert_eq!(counts.iter().sum::<usize>(), PreflopClass::COUNT);
+        assert!(counts.iter().all(|&n| n > 0));
+    }
+
+    fn deal(hole: &str, board: &str) -> CanonicalDeal {
+        canonicalize(&parse_cards(hole).unwrap(), &parse_cards(board).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn preflop_classes() {
+        assert_eq!(class("AsKs"), class("KhAh"));
+        assert_eq!(class("AsKs").to_string(), "AKs");
+        assert_eq!(class("Ad7c").to_string(), "A7o");
+        assert_eq!(class("7c7d").to_string(), "77");
+        let ace = "As".parse().unwrap();
+        assert_eq!(PreflopClass::from_cards(&[ace, ace]), None);
+        assert_eq!("T9o".parse(), Ok(class("Td9h")));
+    }
+
+    #[test]
+    fn reports_bad_class_names() {
+        let error = |name: &str| name.parse::<PreflopClass>().unwrap_err();
+        let at = |position, kind| PreflopClassParseError { position, kind };
+        assert_eq!(error("AAs"), at(2, PreflopClassErrorKind::PairWithSuffix));
+        assert_eq!(error("AK"), at(2, PreflopClassErrorKind::WrongLength));
+        assert_eq!(error("A"), at(1, PreflopClassErrorKind::WrongLength));
+        assert_eq!(error("AKsx"), at(3, PreflopClassErrorKind::WrongLength));
+        assert_eq!(error("77 "), at(2, PreflopClassErrorKind::WrongLength));
+        assert_eq!(error("AXs"), at(1, PreflopClassErrorKind::InvalidRank('X')));
+        assert_eq!(error("AKx"), at(2, PreflopClassErrorKind::InvalidSuffix('x')));
+        assert_eq!(error("AKx").to_string(), "expected 's' or 'o', found 'x' at position 2");
+    }
+
+    #[test]
+    fn all_169_classes_cover_every_combo() {
+        let classes: Vec<PreflopClass> = PreflopClass::all().collect();
+        assert_eq!(classes.len(), 169);
+        assert_eq!(classes.iter().map(|c| c.combo_count()).sum::<u32>(), 1326);
+
+        for (i, &c) in classes.iter().enumerate() {
+            assert_eq!(c.index(), i);
+            assert_eq!(c.to_string().parse(), Ok(c));
+            let combos = c.combos();
+            assert_eq!(combos.len() as u32, c.combo_count());
+            assert!(combos.iter().all(|combo| PreflopClass::from_cards(combo) == Some(c)));
+        }
+    }
+
+    #[test]
+    fn suit_permutations_share_a_deal() {
+        assert_eq!(deal("AsKs", "2s7h9d"), deal("AhKh", "9c2h7d"));
+        assert_ne!(deal("AsKs", "2s7h9d"), deal("AsKs", "2h7s9d"));
+        assert_eq!(deal("AhKh", "").hole_cards, parse_cards("AsKs").unwrap());
+        assert_eq!(deal("7d7c", "7h").board, parse_cards("7d").unwrap());
+        assert_eq!(canonicalize(&parse_cards("AsKs").unwrap(), &parse_cards("As").unwrap()), None);
+    }
+
+    #[test]
+    fn there_are_1755_flops() {
+        let classes = flop_classes();
+        assert_eq!(classes.len(), 1755);
+        assert_eq!(classes.iter().map(|(_, n)| n).sum::<u32>(), 22100);
+
+        // a rainbow flop of three ranks stands for 24 flops, a monotone one for 4
+        let rainbow = flop_class(&parse_cards("Ah7d2c").unwrap()).unwrap();
+        let monotone = flop_class(&parse_cards("Ah7h2h").unwrap()).unwrap();
+        let count = |flop| classes.iter().find(|(f, _)| *f == flop).unwrap().1;
+        assert_eq!(count(rainbow), 24);
+        assert_eq!(count(monotone), 4);
+        assert_eq!(count(flop_class(&parse_cards("7s7h7d").unwrap()).unwrap()), 4);
+    }
+}
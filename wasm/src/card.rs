@@ -1,129 +1,338 @@
+use std::fmt;
+use std::str::FromStr;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 use wasm_bindgen::prelude::*;
 
-pub type Card = u32;
-
-fn char_to_rank(c: char) -> Option<u32> {
-    match c {
-        '2' => Some(0),
-        '3' => Some(1),
-        '4' => Some(2),
-        '5' => Some(3),
-        '6' => Some(4),
-        '7' => Some(5),
-        '8' => Some(6),
-        '9' => Some(7),
-        'T' => Some(8),
-        'J' => Some(9),
-        'Q' => Some(10),
-        'K' => Some(11),
-        'A' => Some(12),
-        _ => None,
-    }
-}
-
-fn char_to_bit(c: char) -> Option<u32> {
-    let base: u32 = 2;
-    match c {
-        '2' => Some(base.pow(0)),
-        '3' => Some(base.pow(1)),
-        '4' => Some(base.pow(2)),
-        '5' => Some(base.pow(3)),
-        '6' => Some(base.pow(4)),
-        '7' => Some(base.pow(5)),
-        '8' => Some(base.pow(6)),
-        '9' => Some(base.pow(7)),
-        'T' => Some(base.pow(8)),
-        'J' => Some(base.pow(9)),
-        'Q' => Some(base.pow(10)),
-        'K' => Some(base.pow(11)),
-        'A' => Some(base.pow(12)),
-        _ => None,
-    }
-}
-
-fn rank_to_prime(c: char) -> Option<u32> {
-    match c {
-        '2' => Some(2),
-        '3' => Some(3),
-        '4' => Some(5),
-        '5' => Some(7),
-        '6' => Some(11),
-        '7' => Some(13),
-        '8' => Some(17),
-        '9' => Some(19),
-        'T' => Some(23),
-        'J' => Some(29),
-        'Q' => Some(31),
-        'K' => Some(37),
-        'A' => Some(41),
-        _ => None,
-    }
-}
-
-fn suit_to_bit(s: char) -> Option<u32> {
-    match s {
-        's' => Some(1),
-        'h' => Some(2),
-        'd' => Some(4),
-        'c' => Some(8),
-        _ => None,
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Rank {
+    Two,
+    Three,
+    Four,
+    Five,
+    Six,
+    Seven,
+    Eight,
+    Nine,
+    Ten,
+    Jack,
+    Queen,
+    King,
+    Ace,
+}
+
+impl Rank {
+    /// Every rank, deuce first.
+    pub const ALL: [Rank; 13] = [
+        Rank::Two, Rank::Three, Rank::Four, Rank::Five, Rank::Six, Rank::Seven, Rank::Eight,
+        Rank::Nine, Rank::Ten, Rank::Jack, Rank::Queen, Rank::King, Rank::Ace,
+    ];
+
+    /// 0 for a deuce up to 12 for an ace.
+    pub fn index(self) -> usize {
+        self as usize
+    }
+
+    pub fn from_index(index: usize) -> Option<Rank> {
+        Rank::ALL.get(index).copied()
+    }
+
+    pub fn prime(self) -> u32 {
+        const PRIMES: [u32; 13] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41];
+        PRIMES[self.index()]
+    }
+
+    pub fn to_char(self) -> char {
+        b"23456789TJQKA"[self.index()] as char
+    }
+
+    pub fn from_char(c: char) -> Option<Rank> {
+        Rank::ALL.iter().copied().find(|r| r.to_char() == c)
+    }
+}
+
+impl fmt::Display for Rank {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_char())
+    }
+}
+
+/// Suits, with the discriminant being the suit's bit in a card.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Suit {
+    Spades = 1,
+    Hearts = 2,
+    Diamonds = 4,
+    Clubs = 8,
+}
+
+impl Suit {
+    pub const ALL: [Suit; 4] = [Suit::Spades, Suit::Hearts, Suit::Diamonds, Suit::Clubs];
+
+    /// 0 for spades up to 3 for clubs.
+    pub fn index(self) -> usize {
+        (self as u32).trailing_zeros() as usize
+    }
+
+    pub fn to_char(self) -> char {
+        b"shdc"[self.index()] as char
+    }
+
+    pub fn from_char(c: char) -> Option<Suit> {
+        Suit::ALL.iter().copied().find(|s| s.to_char() == c)
+    }
+}
+
+impl fmt::Display for Suit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_char())
+    }
+}
+
+/// A playing card in Cactus Kev's layout:
+///
+/// ```text
+/// xxxbbbbb bbbbbbbb cdhsrrrr xxpppppp
+/// ```
+///
+/// `b` is one bit per rank, `cdhs` the suit bit, `r` the rank index and `p`
+/// the rank's prime. Cards order by rank, then suit.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Card(u32);
+
+impl Card {
+    pub fn new(rank: Rank, suit: Suit) -> Card {
+        let rank_b = 1 << (16 + rank.index());
+        let suit_b = (suit as u32) << 12;
+        let rank_n = (rank.index() as u32) << 8;
+        Card(rank_b | suit_b | rank_n | rank.prime())
+    }
+
+    /// Validates a raw card coming across the wasm boundary.
+    pub fn from_u32(bits: u32) -> Option<Card> {
+        let rank = Rank::from_index(((bits >> 8) & 0xF) as usize)?;
+        let suit = Suit::ALL.iter().copied().find(|&s| (bits >> 12) & 0xF == s as u32)?;
+        let card = Card::new(rank, suit);
+        (card.0 == bits).then_some(card)
+    }
+
+    /// The raw Cactus Kev bits, for the evaluator and for JS.
+    pub fn bits(self) -> u32 {
+        self.0
+    }
+
+    pub fn rank(self) -> Rank {
+        Rank::ALL[((self.0 >> 8) & 0xF) as usize]
+    }
+
+    pub fn suit(self) -> Suit {
+        Suit::ALL[((self.0 >> 12) & 0xF).trailing_zeros() as usize]
+    }
+
+    /// 0 to 51, counting suit by suit in the same order as `Card::all()`.
+    pub fn index(self) -> usize {
+        self.suit().index() * 13 + self.rank().index()
+    }
+
+    pub fn from_index(index: usize) -> Option<Card> {
+        let suit = *Suit::ALL.get(index / 13)?;
+        Some(Card::new(Rank::ALL[index % 13], suit))
+    }
+
+    /// All 52 cards, suit by suit.
+    pub fn all() -> impl Iterator<Item = Card> {
+        Suit::ALL.into_iter().flat_map(|suit| Rank::ALL.into_iter().map(move |rank| Card::new(rank, suit)))
+    }
+}
+
+impl From<Card> for u32 {
+    fn from(card: Card) -> u32 {
+        card.0
+    }
+}
+
+impl TryFrom<u32> for Card {
+    type Error = ();
+
+    fn try_from(bits: u32) -> Result<Card, ()> {
+        Card::from_u32(bits).ok_or(())
+    }
+}
+
+impl fmt::Display for Card {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.rank(), self.suit())
+    }
+}
+
+impl fmt::Debug for Card {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CardErrorKind {
+    /// The input ran out before a suit, or has characters left over.
+    WrongLength,
+    InvalidRank(char),
+    InvalidSuit(char),
+    Duplicate(Card),
+}
+
+/// Error from parsing cards. `position` is the 0-based character offset in
+/// the input where the problem starts.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CardParseError {
+    pub position: usize,
+    pub kind: CardErrorKind,
+}
+
+impl fmt::Display for CardParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            CardErrorKind::WrongLength => write!(f, "expected a rank followed by a suit, like \"As\"")?,
+            CardErrorKind::InvalidRank(c) => write!(f, "invalid rank '{}'", c)?,
+            CardErrorKind::InvalidSuit(c) => write!(f, "invalid suit '{}'", c)?,
+            CardErrorKind::Duplicate(card) => write!(f, "duplicate card {}", card)?,
+        }
+        write!(f, " at position {}", self.position)
+    }
+}
+
+impl std::error::Error for CardParseError {}
+
+fn parse_rank(chars: &[char], pos: usize) -> Result<(Rank, usize), CardParseError> {
+    let error = |kind| CardParseError { position: pos, kind };
+    match chars.get(pos) {
+        None => Err(error(CardErrorKind::WrongLength)),
+        Some('1') if chars.get(pos + 1) == Some(&'0') => Ok((Rank::Ten, pos + 2)),
+        Some(&c) => Rank::from_char(c.to_ascii_uppercase())
+            .map(|rank| (rank, pos + 1))
+            .ok_or(error(CardErrorKind::InvalidRank(c))),
+    }
+}
+
+fn parse_suit(chars: &[char], pos: usize) -> Result<(Suit, usize), CardParseError> {
+    let error = |kind| CardParseError { position: pos, kind };
+    let c = *chars.get(pos).ok_or(error(CardErrorKind::WrongLength))?;
+    let suit = match c {
+        '♠' | '♤' => Some(Suit::Spades),
+        '♥' | '♡' => Some(Suit::Hearts),
+        '♦' | '♢' => Some(Suit::Diamonds),
+        '♣' | '♧' => Some(Suit::Clubs),
+        _ => Suit::from_char(c.to_ascii_lowercase()),
+    };
+    suit.map(|suit| (suit, pos + 1)).ok_or(error(CardErrorKind::InvalidSuit(c)))
+}
+
+// Parses the card starting at `pos`, returning it and where the next one starts.
+pub(crate) fn parse_one(chars: &[char], pos: usize) -> Result<(Card, usize), CardParseError> {
+    let (rank, pos) = parse_rank(chars, pos)?;
+    let (suit, pos) = parse_suit(chars, pos)?;
+    Ok((Card::new(rank, suit), pos))
+}
+
+/// Parses one card. Ranks may be lowercase or "10", and suits may be
+/// letters in either case or symbols like '♠'.
+impl FromStr for Card {
+    type Err = CardParseError;
+
+    fn from_str(s: &str) -> Result<Card, CardParseError> {
+        let chars: Vec<char> = s.chars().collect();
+        let (card, end) = parse_one(&chars, 0)?;
+        if end != chars.len() {
+            return Err(CardParseError { position: end, kind: CardErrorKind::WrongLength });
+        }
+        Ok(card)
+    }
+}
+
+/// Cards are stored as their names, like "As".
+impl Serialize for Card {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
     }
 }
 
+impl<'de> Deserialize<'de> for Card {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Card, D::Error> {
+        String::deserialize(deserializer)?.parse().map_err(de::Error::custom)
+    }
+}
+
+/// Parses any number of cards, written back to back ("AsKh") or separated
+/// by whitespace and commas ("As, Kh Qd7c2s"). Every card must be distinct.
+pub fn parse_cards(text: &str) -> Result<Vec<Card>, CardParseError> {
+    let chars: Vec<char> = text.chars().collect();
+    let mut cards: Vec<Card> = Vec::new();
+
+    let mut pos = 0;
+    while pos < chars.len() {
+        if chars[pos] == ',' || chars[pos].is_whitespace() {
+            pos += 1;
+            continue;
+        }
+        let (card, next) = parse_one(&chars, pos)?;
+        if cards.contains(&card) {
+            return Err(CardParseError { position: pos, kind: CardErrorKind::Duplicate(card) });
+        }
+        cards.push(card);
+        pos = next;
+    }
+
+    Ok(cards)
+}
+
+/// Converts raw cards coming from JS, or `None` if any of them is not a card.
+pub fn cards_from_u32(values: &[u32]) -> Option<Vec<Card>> {
+    values.iter().map(|&v| Card::from_u32(v)).collect()
+}
+
+pub fn cards_to_u32(cards: &[Card]) -> Vec<u32> {
+    cards.iter().map(|&c| c.bits()).collect()
+}
+
 pub fn prime_prod_from_rank(cards: u32) -> u32 {
     let primes: [u32; 13] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41];
     let mut prod: u32 = 1;
 
-    for i in 0..13 {
+    for (i, prime) in primes.iter().enumerate() {
         if cards & (1 << i) != 0 {
-            prod *= primes[i];
+            prod *= prime;
         }
     }
     prod
 }
 
 pub fn prime_prod_from_pbits(cards: Vec<Card>) -> u32 {
-    cards.iter().fold(1, |acc, &x| acc * (x & 0x3F))
+    cards.iter().fold(1, |acc, &x| acc * (x.bits() & 0x3F))
 }
 
-#[wasm_bindgen]
 pub fn new_card(card_str: &str) -> Option<Card> {
-    if card_str.len() != 2 {
-        return None;
-    }
-
-    // "As"
-    let mut chars = card_str.chars();
-    let rank_char = chars.next()?;
-    let suit_char = chars.next()?;
-
-    let rank_b = char_to_bit(rank_char)? << 16;
-    let suit = suit_to_bit(suit_char)? << 12;
-    let rank_n = char_to_rank(rank_char)? << 8;
-    let rank_p = rank_to_prime(rank_char)?;
+    card_str.parse().ok()
+}
 
+#[wasm_bindgen(js_name = new_card)]
+pub fn new_card_js(card_str: &str) -> Option<u32> {
+    new_card(card_str).map(u32::from)
+}
 
-    let new_card: Card = rank_b | suit | rank_n | rank_p;
+/// Like `new_card`, but throws an error explaining what is wrong.
+#[wasm_bindgen(js_name = parse_card)]
+pub fn parse_card_js(card_str: &str) -> Result<u32, JsError> {
+    Ok(card_str.parse::<Card>()?.into())
+}
 
-    Some(new_card)
+/// Parses a string of cards such as "AsKh Qd7c2s", throwing on bad input.
+#[wasm_bindgen(js_name = parse_cards)]
+pub fn parse_cards_js(text: &str) -> Result<Vec<u32>, JsError> {
+    Ok(cards_to_u32(&parse_cards(text)?))
 }
 
+/// Two character name of a raw card, or `undefined` if it is not a card.
 #[wasm_bindgen]
-pub fn card_to_str(card: Card) -> String {
-    let rank = card & 0x3F;
-    let suit = (card >> 12) & 0xF;
-
-    let rank_str = match rank {
-        2 => '2', 3 => '3', 5 => '4', 7 => '5',
-        11 => '6', 13 => '7', 17 => '8', 19 => '9', 
-        23 => 'T', 29 => 'J', 31 => 'Q', 37 => 'K', 41 => 'A',
-        _ => '?'
-    };
-    let suit_str = match suit {
-        1 => 's', 2 => 'h', 4 => 'd', 8 => 'c', _ => '?'
-    };
-
-    format!("{}{}", rank_str, suit_str)
+pub fn card_to_str(card: u32) -> Option<String> {
+    Card::from_u32(card).map(|c| c.to_string())
 }
 
 
@@ -131,16 +340,27 @@ pub fn card_to_str(card: Card) -> String {
 mod tests {
     use super::*;
 
+    #[test]
+    fn serializes_as_names() {
+        let cards = parse_cards("As Td").unwrap();
+        let json = serde_json::to_string(&cards).unwrap();
+        assert_eq!(json, r#"["As","Td"]"#);
+        assert_eq!(serde_json::from_str::<Vec<Card>>(&json).unwrap(), cards);
+        assert_eq!(serde_json::from_str::<Card>(r#""10♥""#).unwrap(), new_card("Th").unwrap());
+        let error = serde_json::from_str::<Card>(r#""Zs""#).unwrap_err();
+        assert!(error.to_string().contains("invalid rank 'Z'"), "{}", error);
+    }
+
     #[test]
     fn test_ace_of_king_diamonds() {
         let card = new_card("Kd").unwrap();
 
-        assert_eq!(card, 134236965);
+        assert_eq!(u32::from(card), 134236965);
     }
 
     #[test]
     fn test_five_of_spades() {
-        let card: u32 = new_card("5s").unwrap();
+        let card: u32 = new_card("5s").unwrap().into();
 
         assert_eq!(card, 529159)
     }
@@ -155,8 +375,8 @@ mod tests {
 
         let cards = &[a, b, c, d, e];
 
-        let combined_cards = cards.iter().copied().fold(0b0, |acc, x| acc | x);
-        
+        let combined_cards = cards.iter().copied().fold(0b0, |acc, x| acc | x.bits());
+
         let clean_cards = combined_cards >> 16;
 
         let predicted = prime_prod_from_rank(clean_cards);
@@ -165,5 +385,100 @@ mod tests {
 
         assert_eq!(predicted, expected)
     }
-}
 
+    #[test]
+    fn card_parts_round_trip() {
+        let card: Card = "Td".parse().unwrap();
+
+        assert_eq!(card.rank(), Rank::Ten);
+        assert_eq!(card.suit(), Suit::Diamonds);
+        assert_eq!(card, Card::new(Rank::Ten, Suit::Diamonds));
+        assert_eq!(card.to_string(), "Td");
+        assert_eq!(format!("{:?}", card), "Td");
+    }
+
+    #[test]
+    fn card_indexes_follow_all() {
+        for (i, card) in Card::all().enumerate() {
+            assert_eq!(card.index(), i);
+            assert_eq!(Card::from_index(i), Some(card));
+        }
+        assert_eq!(Card::from_index(52), None);
+    }
+
+    #[test]
+    fn all_cards_are_distinct_and_valid() {
+        let cards: Vec<Card> = Card::all().collect();
+
+        assert_eq!(cards.len(), 52);
+        for (i, &card) in cards.iter().enumerate() {
+            assert!(!cards[i + 1..].contains(&card));
+            assert_eq!(Card::from_u32(card.bits()), Some(card));
+            assert_eq!(card.to_string().parse::<Card>(), Ok(card));
+        }
+    }
+
+    #[test]
+    fn cards_order_by_rank_then_suit() {
+        let two = new_card("2c").unwrap();
+        let ace_spades = new_card("As").unwrap();
+        let ace_clubs = new_card("Ac").unwrap();
+
+        assert!(two < ace_spades);
+        assert!(ace_spades < ace_clubs);
+        assert_eq!(Card::all().max(), Some(ace_clubs));
+    }
+
+    #[test]
+    fn rejects_garbage() {
+        assert_eq!(Card::from_u32(0), None);
+        assert_eq!(Card::from_u32(12345), None);
+        assert_eq!(Card::from_u32(new_card("As").unwrap().bits() | 0x2000), None);
+        assert_eq!(card_to_str(7), None);
+    }
+
+    fn error(text: &str) -> CardParseError {
+        parse_cards(text).unwrap_err()
+    }
+
+    #[test]
+    fn single_card_errors() {
+        assert_eq!("Xs".parse::<Card>(), Err(CardParseError { position: 0, kind: CardErrorKind::InvalidRank('X') }));
+        assert_eq!("Ax".parse::<Card>(), Err(CardParseError { position: 1, kind: CardErrorKind::InvalidSuit('x') }));
+        assert_eq!("Asd".parse::<Card>(), Err(CardParseError { position: 2, kind: CardErrorKind::WrongLength }));
+        assert_eq!("A".parse::<Card>(), Err(CardParseError { position: 1, kind: CardErrorKind::WrongLength }));
+        assert_eq!("".parse::<Card>(), Err(CardParseError { position: 0, kind: CardErrorKind::WrongLength }));
+    }
+
+    #[test]
+    fn lenient_single_cards() {
+        let ten_hearts = new_card("Th").unwrap();
+        assert_eq!("10h".parse(), Ok(ten_hearts));
+        assert_eq!("th".parse(), Ok(ten_hearts));
+        assert_eq!("TH".parse(), Ok(ten_hearts));
+        assert_eq!("T♥".parse(), Ok(ten_hearts));
+        assert_eq!("a♠".parse(), Ok(new_card("As").unwrap()));
+        assert_eq!("K♢".parse(), Ok(new_card("Kd").unwrap()));
+        assert_eq!("2♣".parse(), Ok(new_card("2c").unwrap()));
+    }
+
+    #[test]
+    fn parses_many_cards() {
+        let expected: Vec<Card> = ["As", "Kh", "Qd", "7c", "2s"].iter().map(|c| new_card(c).unwrap()).collect();
+        assert_eq!(parse_cards("AsKh Qd7c2s"), Ok(expected.clone()));
+        assert_eq!(parse_cards("As,Kh, qd 7C 2♠"), Ok(expected));
+        assert_eq!(parse_cards("10s10h").unwrap().len(), 2);
+        assert_eq!(parse_cards("  "), Ok(vec![]));
+    }
+
+    #[test]
+    fn many_card_errors() {
+        assert_eq!(error("AsKh Qx"), CardParseError { position: 6, kind: CardErrorKind::InvalidSuit('x') });
+        assert_eq!(error("As 1h"), CardParseError { position: 3, kind: CardErrorKind::InvalidRank('1') });
+        assert_eq!(error("AsK"), CardParseError { position: 3, kind: CardErrorKind::WrongLength });
+        let ace = new_card("As").unwrap();
+        assert_eq!(error("As Kd as"), CardParseError { position: 6, kind: CardErrorKind::Duplicate(ace) });
+        assert_eq!(error("As Kd as").to_string(), "duplicate card As at position 6");
+        assert_eq!(error("A♠ Ж").to_string(), "invalid rank 'Ж' at position 3");
+    }
+}
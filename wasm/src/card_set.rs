@@ -0,0 +1,235 @@
+use std::fmt;
+use std::ops::{BitAnd, BitOr, Sub};
+use rand::Rng;
+use crate::card::Card;
+
+/// A set of cards packed into the low 52 bits of a `u64`, one bit per
+/// `Card::index()`. A card is either in the set or not, so a set can never
+/// hold the same card twice, and every operation is a few bit instructions.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct CardSet(u64);
+
+impl CardSet {
+    pub const EMPTY: CardSet = CardSet(0);
+    pub const FULL: CardSet = CardSet((1 << 52) - 1);
+
+    /// Rejects masks with bits above the 52 cards.
+    pub fn from_bits(bits: u64) -> Option<CardSet> {
+        (bits & !CardSet::FULL.0 == 0).then_some(CardSet(bits))
+    }
+
+    pub fn bits(self) -> u64 {
+        self.0
+    }
+
+    /// Adds `card`, returning false if it was already in the set.
+    pub fn insert(&mut self, card: Card) -> bool {
+        let added = !self.contains(card);
+        self.0 |= 1 << card.index();
+        added
+    }
+
+    /// Takes `card` out, returning false if it wasn't in the set.
+    pub fn remove(&mut self, card: Card) -> bool {
+        let removed = self.contains(card);
+        self.0 &= !(1 << card.index());
+        removed
+    }
+
+    pub fn contains(self, card: Card) -> bool {
+        self.0 & (1 << card.index()) != 0
+    }
+
+    pub fn len(self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn union(self, other: CardSet) -> CardSet {
+        CardSet(self.0 | other.0)
+    }
+
+    pub fn intersection(self, other: CardSet) -> CardSet {
+        CardSet(self.0 & other.0)
+    }
+
+    pub fn difference(self, other: CardSet) -> CardSet {
+        CardSet(self.0 & !other.0)
+    }
+
+    /// The cards in `Card::all()` order.
+    pub fn iter(self) -> CardSetIter {
+        CardSetIter(self.0)
+    }
+
+    /// The `n`th card in iteration order.
+    pub fn select(self, n: usize) -> Option<Card> {
+        let mut bits = self.0;
+        for _ in 0..n.min(64) {
+            bits &= bits.wrapping_sub(1);
+        }
+        (bits != 0).then(|| Card::from_index(bits.trailing_zeros() as usize).unwrap())
+    }
+
+    /// Picks `k` distinct cards at random, in the order they were picked.
+    /// Asking for more cards than the set holds returns all of them.
+    pub fn sample<R: Rng + ?Sized>(self, k: usize, rng: &mut R) -> Vec<Card> {
+        let mut left = self;
+        (0..k.min(self.len())).map(|_| {
+            let card = left.select(rng.gen_range(0..left.len())).unwrap();
+            left.remove(card);
+            card
+        }).collect()
+    }
+}
+
+impl BitOr for CardSet {
+    type Output = CardSet;
+
+    fn bitor(self, other: CardSet) -> CardSet {
+        self.union(other)
+    }
+}
+
+impl BitAnd for CardSet {
+    type Output = CardSet;
+
+    fn bitand(self, other: CardSet) -> CardSet {
+        self.intersection(other)
+    }
+}
+
+impl Sub for CardSet {
+    type Output = CardSet;
+
+    fn sub(self, other: CardSet) -> CardSet {
+        self.difference(other)
+    }
+}
+
+impl FromIterator<Card> for CardSet {
+    fn from_iter<I: IntoIterator<Item = Card>>(cards: I) -> CardSet {
+        let mut set = CardSet::EMPTY;
+        set.extend(cards);
+        set
+    }
+}
+
+impl<'a> FromIterator<&'a Card> for CardSet {
+    fn from_iter<I: IntoIterator<Item = &'a Card>>(cards: I) -> CardSet {
+        cards.into_iter().copied().collect()
+    }
+}
+
+impl Extend<Card> for CardSet {
+    fn extend<I: IntoIterator<Item = Card>>(&mut self, cards: I) {
+        for card in cards {
+            self.insert(card);
+        }
+    }
+}
+
+impl IntoIterator for CardSet {
+    type Item = Card;
+    type IntoIter = CardSetIter;
+
+    fn into_iter(self) -> CardSetIter {
+        self.iter()
+    }
+}
+
+impl fmt::Debug for CardSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+pub struct CardSetIter(u64);
+
+impl Iterator for CardSetIter {
+    type Item = Card;
+
+    fn next(&mut self) -> Option<Card> {
+        if self.0 == 0 {
+            return None;
+        }
+        let index = self.0.trailing_zeros() as usize;
+        self.0 &= self.0 - 1;
+        Card::from_index(index)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.0.count_ones() as usize;
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for CardSetIter {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+    use crate::card::parse_cards;
+
+    fn set(text: &str) -> CardSet {
+        parse_cards(text).unwrap().into_iter().collect()
+    }
+
+    #[test]
+    fn insert_and_remove() {
+        let ace = "As".parse().unwrap();
+        let mut cards = CardSet::EMPTY;
+        assert!(cards.insert(ace));
+        assert!(!cards.insert(ace));
+        assert!(cards.contains(ace));
+        assert_eq!(cards.len(), 1);
+
+        assert!(cards.remove(ace));
+        assert!(!cards.remove(ace));
+        assert!(cards.is_empty());
+    }
+
+    #[test]
+    fn set_operations() {
+        let a = set("As Kh Qd");
+        let b = set("Kh Qd 2c");
+        assert_eq!(a | b, set("As Kh Qd 2c"));
+        assert_eq!(a & b, set("Kh Qd"));
+        assert_eq!(a - b, set("As"));
+        assert_eq!(CardSet::FULL.len(), 52);
+        assert_eq!(CardSet::FULL - a, CardSet::FULL.difference(a));
+        assert_eq!((CardSet::FULL - a).len(), 49);
+    }
+
+    #[test]
+    fn iterates_in_deck_order() {
+        assert!(CardSet::FULL.iter().eq(Card::all()));
+        assert_eq!(set("2c Ah 3s").iter().collect::<Vec<_>>(), parse_cards("3s Ah 2c").unwrap());
+        assert_eq!(set("2c Ah 3s").select(1), "Ah".parse().ok());
+        assert_eq!(set("2c Ah 3s").select(3), None);
+    }
+
+    #[test]
+    fn from_bits_rejects_extra_bits() {
+        assert_eq!(CardSet::from_bits(CardSet::FULL.bits()), Some(CardSet::FULL));
+        assert_eq!(CardSet::from_bits(1 << 52), None);
+    }
+
+    #[test]
+    fn samples_distinct_cards() {
+        let mut rng = ChaCha8Rng::seed_from_u64(7);
+        let from = set("As Kh Qd Jc Ts 9h");
+        for _ in 0..100 {
+            let picked = from.sample(4, &mut rng);
+            let picked_set: CardSet = picked.iter().collect();
+            assert_eq!(picked_set.len(), 4);
+            assert_eq!(picked_set - from, CardSet::EMPTY);
+        }
+        assert_eq!(from.sample(10, &mut rng).len(), 6);
+    }
+}
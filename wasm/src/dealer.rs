@@ -0,0 +1,235 @@
+use std::cell::RefCell;
+use rand::{thread_rng, Rng};
+use wasm_bindgen::prelude::*;
+use crate::card::{Card, cards_to_u32};
+use crate::Deck;
+
+/// Deals one hand at a time from a single shuffled deck, so hole cards and
+/// board cards can never collide. Cards are handed out as raw `u32`s, ready
+/// for JS.
+///
+/// Dealing a street is idempotent: asking for the flop twice returns the same
+/// three cards, and asking for a street before the previous one is out
+/// returns nothing.
+///
+/// Each hand is shuffled from a seed, exposed by `seed()`, so a hand can be
+/// dealt again exactly with `deal_hole_cards_with_seed`.
+#[wasm_bindgen]
+pub struct Dealer {
+    deck: Deck,
+    hole_cards: Vec<Card>,
+    board: Vec<Card>,
+}
+
+#[wasm_bindgen]
+impl Dealer {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Dealer {
+        Dealer { deck: Deck::new(), hole_cards: Vec::new(), board: Vec::new() }
+    }
+
+    /// A dealer whose first hand is shuffled from `seed`.
+    pub fn with_seed(seed: u64) -> Dealer {
+        Dealer { deck: Deck::with_seed(seed), hole_cards: Vec::new(), board: Vec::new() }
+    }
+
+    /// The seed the current hand was shuffled with.
+    pub fn seed(&self) -> u64 {
+        self.deck.seed()
+    }
+
+    /// Shuffles a fresh deck and deals two new hole cards.
+    pub fn deal_hole_cards(&mut self) -> Vec<u32> {
+        self.deal_hole_cards_with_seed(thread_rng().gen())
+    }
+
+    /// Starts the hand shuffled from `seed` and deals its hole cards. The
+    /// board that follows is the same every time.
+    pub fn deal_hole_cards_with_seed(&mut self, seed: u64) -> Vec<u32> {
+        *self = Dealer::with_seed(seed);
+        self.hole_cards = self.deck.draw(2);
+        cards_to_u32(&self.hole_cards)
+    }
+
+    pub fn deal_flop(&mut self) -> Vec<u32> {
+        if self.hole_cards.is_empty() {
+            return Vec::new();
+        }
+        if self.board.is_empty() {
+            self.board = self.deck.draw(3);
+        }
+        cards_to_u32(&self.board[..3])
+    }
+
+    pub fn deal_turn(&mut self) -> Option<u32> {
+        self.deal_street(3).map(u32::from)
+    }
+
+    pub fn deal_river(&mut self) -> Option<u32> {
+        self.deal_street(4).map(u32::from)
+    }
+
+    /// Deals whichever single card comes next: the turn, then the river.
+    pub fn deal_card(&mut self) -> Option<u32> {
+        self.deal_street(self.board.len()).map(u32::from)
+    }
+
+    pub fn hole_cards(&self) -> Vec<u32> {
+        cards_to_u32(&self.hole_cards)
+    }
+
+    pub fn board(&self) -> Vec<u32> {
+        cards_to_u32(&self.board)
+    }
+
+    /// Every card dealt so far, hole cards first.
+    pub fn dealt(&self) -> Vec<u32> {
+        let mut cards = self.hole_cards();
+        cards.extend(self.board());
+        cards
+    }
+}
+
+impl Dealer {
+    // `index` is the board slot being dealt: 3 for the turn, 4 for the river
+    fn deal_street(&mut self, index: usize) -> Option<Card> {
+        if !(3..5).contains(&index) || self.board.len() < index {
+            return None;
+        }
+        if self.board.len() == index {
+            self.board.extend(self.deck.draw(1));
+        }
+        self.board.get(index).copied()
+    }
+}
+
+impl Default for Dealer {
+    fn default() -> Self {
+        Dealer::new()
+    }
+}
+
+thread_local! {
+    static DEALER: RefCell<Dealer> = RefCell::new(Dealer::new());
+}
+
+/// Starts a new hand on the shared dealer and returns the hero's hole cards.
+#[wasm_bindgen]
+pub fn draw_starting_hand() -> Vec<u32> {
+    DEALER.with(|dealer| dealer.borrow_mut().deal_hole_cards())
+}
+
+/// Starts the hand shuffled from `seed` on the shared dealer, so a shared
+/// seed replays the same hand.
+#[wasm_bindgen]
+pub fn draw_starting_hand_with_seed(seed: u64) -> Vec<u32> {
+    DEALER.with(|dealer| dealer.borrow_mut().deal_hole_cards_with_seed(seed))
+}
+
+/// The seed of the shared dealer's current hand.
+#[wasm_bindgen]
+pub fn hand_seed() -> u64 {
+    DEALER.with(|dealer| dealer.borrow().seed())
+}
+
+/// Deals the flop of the hand started by `draw_starting_hand`.
+#[wasm_bindgen]
+pub fn draw_flop() -> Vec<u32> {
+    DEALER.with(|dealer| dealer.borrow_mut().deal_flop())
+}
+
+/// Deals the turn, then the river, of the hand started by `draw_starting_hand`.
+#[wasm_bindgen]
+pub fn draw_card() -> Option<u32> {
+    DEALER.with(|dealer| dealer.borrow_mut().deal_card())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn has_duplicates(cards: &[u32]) -> bool {
+        cards.iter().enumerate().any(|(i, c)| cards[i + 1..].contains(c))
+    }
+
+    #[test]
+    fn deals_a_full_hand_without_duplicates() {
+        for _ in 0..200 {
+            let mut dealer = Dealer::new();
+            let hole = dealer.deal_hole_cards();
+            let flop = dealer.deal_flop();
+            let turn = dealer.deal_turn().unwrap();
+            let river = dealer.deal_river().unwrap();
+
+            assert_eq!(hole.len(), 2);
+            assert_eq!(flop.len(), 3);
+            assert_eq!(dealer.board(), [flop.as_slice(), &[turn, river]].concat());
+            assert_eq!(dealer.dealt().len(), 7);
+            assert!(!has_duplicates(&dealer.dealt()));
+        }
+    }
+
+    #[test]
+    fn streets_are_dealt_in_order() {
+        let mut dealer = Dealer::new();
+        assert!(dealer.deal_flop().is_empty());
+
+        dealer.deal_hole_cards();
+        assert_eq!(dealer.deal_river(), None);
+        assert_eq!(dealer.deal_turn(), None);
+
+        let flop = dealer.deal_flop();
+        assert_eq!(dealer.deal_flop(), flop);
+        let turn = dealer.deal_card().unwrap();
+        assert_eq!(dealer.deal_turn(), Some(turn));
+        dealer.deal_card().unwrap();
+        assert_eq!(dealer.deal_card(), None);
+        assert_eq!(dealer.board().len(), 5);
+    }
+
+    #[test]
+    fn new_hand_clears_the_board() {
+        let mut dealer = Dealer::new();
+        dealer.deal_hole_cards();
+        dealer.deal_flop();
+
+        let hole = dealer.deal_hole_cards();
+        assert_eq!(dealer.hole_cards(), hole);
+        assert!(dealer.board().is_empty());
+    }
+
+    #[test]
+    fn seeded_hands_replay() {
+        let mut first = Dealer::new();
+        first.deal_hole_cards();
+        first.deal_flop();
+        first.deal_card();
+        first.deal_card();
+
+        let mut again = Dealer::new();
+        assert_eq!(again.deal_hole_cards_with_seed(first.seed()), first.hole_cards());
+        again.deal_flop();
+        again.deal_card();
+        again.deal_card();
+        assert_eq!(again.dealt(), first.dealt());
+        assert_eq!(again.seed(), first.seed());
+    }
+
+    #[test]
+    fn shared_dealer_exports() {
+        let hole = draw_starting_hand();
+        let flop = draw_flop();
+        let turn = draw_card().unwrap();
+        let river = draw_card().unwrap();
+        assert_eq!(draw_card(), None);
+
+        let mut dealt = hole.clone();
+        dealt.extend(flop);
+        dealt.extend([turn, river]);
+        assert!(!has_duplicates(&dealt));
+
+        let seed = hand_seed();
+        assert_eq!(draw_starting_hand_with_seed(seed), hole);
+        assert_eq!(draw_flop(), dealt[2..5]);
+    }
+}
@@ -1,44 +1,188 @@
 use itertools::Itertools;
 use wasm_bindgen::prelude::*;
-use crate::card::{prime_prod_from_pbits, prime_prod_from_rank, Card};
-use crate::lookup::{FLUSH_LOOKUP, UNSUITED_LOOKUP};
+use crate::card::{Card, cards_from_u32};
+use crate::lookup::{rank_count_index, FLUSH_TABLE, UNSUITED_TABLES};
 
-#[wasm_bindgen]
-pub fn evaluate_hand(cards: Vec<u32>) -> u32 {
-    if check_flush(&cards) {
-        let hand = cards.iter().fold(0u32, |acc, &x| acc | x) >> 16;
-        let prime_val = prime_prod_from_rank(hand);
-        FLUSH_LOOKUP[&prime_val]
-    } else {
-        let key: u32 = prime_prod_from_pbits(cards);
-        UNSUITED_LOOKUP[&key]
-    }
+pub fn evaluate_hand(cards: Vec<Card>) -> u32 {
+    evaluate_cards(&cards)
+}
+
+/// Rank of five to seven raw cards, or `undefined` if any is not a card.
+#[wasm_bindgen(js_name = evaluate_hand)]
+pub fn evaluate_hand_js(cards: Vec<u32>) -> Option<u32> {
+    let cards = cards_from_u32(&cards)?;
+    (5..=7).contains(&cards.len()).then(|| evaluate_cards(&cards))
 }
 
 pub fn check_flush(cards: &[Card]) -> bool {
-    let card_holder = cards.iter().copied().fold(0xF000, |acc, x| acc & x);
+    let card_holder = cards.iter().fold(0xF000, |acc, x| acc & x.bits());
+
+    card_holder & 0xF000 != 0
+}
+
+/// Rank of the best five card hand within `cards`, on the same 1..=7462
+/// scale as `evaluate_hand`.
+pub fn evaluate_board(cards: Vec<Card>) -> u32 {
+    if (5..=7).contains(&cards.len()) {
+        evaluate_cards(&cards)
+    } else {
+        evaluate_board_combinations(cards)
+    }
+}
 
-    if card_holder & 0xF000 != 0 {
-        return true
+/// Table driven evaluation of five to seven cards: one lookup by the rank
+/// mask of a flush suit if there is one, otherwise one lookup by the
+/// perfect hash of the rank counts.
+///
+/// # Panics
+///
+/// Panics if there are fewer than five or more than seven cards; use
+/// `evaluate_board` for any other count.
+pub fn evaluate_cards(cards: &[Card]) -> u32 {
+    debug_assert!((5..=7).contains(&cards.len()), "evaluate_cards takes 5 to 7 cards, not {}", cards.len());
+    let mut suit_masks = [0u32; 4];
+    let mut counts = [0u8; 13];
+    for card in cards {
+        let bits = card.bits();
+        let suit = ((bits >> 12) & 0xF).trailing_zeros() as usize;
+        suit_masks[suit] |= bits >> 16;
+        counts[((bits >> 8) & 0xF) as usize] += 1;
     }
-    else {
-        return false
+
+    // with at most seven cards a flush always beats anything else on offer
+    if let Some(&mask) = suit_masks.iter().find(|mask| mask.count_ones() >= 5) {
+        return FLUSH_TABLE[mask as usize] as u32;
     }
+    UNSUITED_TABLES[cards.len() - 5][rank_count_index(&counts, cards.len())] as u32
 }
 
-pub fn evaluate_board(cards: Vec<u32>) -> u32 {
+/// Best of every 5-card combination scored on its own, for checking the six
+/// and seven card tables against the five card one.
+pub fn evaluate_board_combinations(cards: Vec<Card>) -> u32 {
     cards.iter()
         .copied()
         .combinations(5)
-        .map(|combo| evaluate_hand(combo))
+        .map(evaluate_hand)
         .min()
         .unwrap()
 }
 
+/// The nine hand categories, strongest first. Royal flushes are the best
+/// straight flush rather than a category of their own.
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum HandCategory {
+    StraightFlush,
+    FourOfAKind,
+    FullHouse,
+    Flush,
+    Straight,
+    ThreeOfAKind,
+    TwoPair,
+    OnePair,
+    HighCard,
+}
+
+impl HandCategory {
+    pub fn name(self) -> &'static str {
+        match self {
+            HandCategory::StraightFlush => "Straight flush",
+            HandCategory::FourOfAKind => "Four of a kind",
+            HandCategory::FullHouse => "Full house",
+            HandCategory::Flush => "Flush",
+            HandCategory::Straight => "Straight",
+            HandCategory::ThreeOfAKind => "Three of a kind",
+            HandCategory::TwoPair => "Two pair",
+            HandCategory::OnePair => "One pair",
+            HandCategory::HighCard => "High card",
+        }
+    }
+}
+
+/// Category of a rank on the 1..=7462 scale, or `None` if it is off the scale.
+#[wasm_bindgen]
+pub fn rank_to_category(rank: u32) -> Option<HandCategory> {
+    let category = match rank {
+        1..=10 => HandCategory::StraightFlush,
+        11..=166 => HandCategory::FourOfAKind,
+        167..=322 => HandCategory::FullHouse,
+        323..=1599 => HandCategory::Flush,
+        1600..=1609 => HandCategory::Straight,
+        1610..=2467 => HandCategory::ThreeOfAKind,
+        2468..=3325 => HandCategory::TwoPair,
+        3326..=6185 => HandCategory::OnePair,
+        6186..=7462 => HandCategory::HighCard,
+        _ => return None,
+    };
+    Some(category)
+}
+
+#[wasm_bindgen]
+pub fn category_name(category: HandCategory) -> String {
+    category.name().to_string()
+}
+
+const RANK_NAMES: [&str; 13] = [
+    "Two", "Three", "Four", "Five", "Six", "Seven", "Eight", "Nine", "Ten", "Jack", "Queen", "King", "Ace",
+];
+
+fn plural(rank: usize) -> String {
+    match rank {
+        4 => "Sixes".to_string(),
+        _ => format!("{}s", RANK_NAMES[rank]),
+    }
+}
+
+/// Describes the best five card hand within `cards` (five to seven of them),
+/// e.g. "Two pair, Fives and Twos, Ace kicker". Returns `None` for any other
+/// number of cards.
+pub fn describe_hand(cards: Vec<Card>) -> Option<String> {
+    if !(5..=7).contains(&cards.len()) {
+        return None;
+    }
+
+    let best = cards.iter().copied().combinations(5).min_by_key(|five| evaluate_cards(five))?;
+    let rank = evaluate_cards(&best);
+
+    // rank indices grouped by how often they appear, biggest group first
+    let mut counts = [0usize; 13];
+    for card in &best {
+        counts[card.rank().index()] += 1;
+    }
+    let mut groups: Vec<(usize, usize)> = (0..13).rev().filter(|&r| counts[r] > 0).map(|r| (counts[r], r)).collect();
+    groups.sort_by_key(|&(count, _)| std::cmp::Reverse(count));
+    let ranks: Vec<usize> = groups.iter().map(|&(_, r)| r).collect();
+
+    // the wheel is five high
+    let high = if ranks == [12, 3, 2, 1, 0] { 3 } else { ranks[0] };
+    let kicker = |r: usize| format!("{} kicker", RANK_NAMES[r]);
+
+    let description = match rank_to_category(rank)? {
+        HandCategory::StraightFlush if rank == 1 => "Royal flush".to_string(),
+        HandCategory::StraightFlush => format!("Straight flush, {} high", RANK_NAMES[high]),
+        HandCategory::FourOfAKind => format!("Four of a kind, {}, {}", plural(ranks[0]), kicker(ranks[1])),
+        HandCategory::FullHouse => format!("Full house, {} full of {}", plural(ranks[0]), plural(ranks[1])),
+        HandCategory::Flush => format!("Flush, {} high", RANK_NAMES[high]),
+        HandCategory::Straight => format!("Straight, {} high", RANK_NAMES[high]),
+        HandCategory::ThreeOfAKind => format!("Three of a kind, {}, {}", plural(ranks[0]), kicker(ranks[1])),
+        HandCategory::TwoPair => format!("Two pair, {} and {}, {}", plural(ranks[0]), plural(ranks[1]), kicker(ranks[2])),
+        HandCategory::OnePair => format!("Pair of {}, {}", plural(ranks[0]), kicker(ranks[1])),
+        HandCategory::HighCard => format!("High card, {}, {}", RANK_NAMES[ranks[0]], kicker(ranks[1])),
+    };
+    Some(description)
+}
+
+#[wasm_bindgen(js_name = describe_hand)]
+pub fn describe_hand_js(cards: Vec<u32>) -> Option<String> {
+    describe_hand(cards_from_u32(&cards)?)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::card::{new_card};
+    use crate::card::{new_card, Rank, Suit};
+    use rand::seq::SliceRandom;
+    use rand::thread_rng;
 
     #[test]
     fn check_real_flush() {
@@ -49,7 +193,7 @@ mod tests {
         let e = new_card("Ts").unwrap();
 
         let card_vector: &[Card] = &[a, b, c, d, e];
-        let ret = check_flush(&card_vector);
+        let ret = check_flush(card_vector);
         assert!(ret)
     }
 
@@ -62,7 +206,7 @@ mod tests {
         let e = new_card("Tc").unwrap();
 
         let card_vector: &[Card] = &[a, b, c, d, e];
-        let ret = check_flush(&card_vector);
+        let ret = check_flush(card_vector);
         assert!(!ret)
     }
 
@@ -91,4 +235,166 @@ mod tests {
         let val = evaluate_hand(card_vector);
         assert_eq!(val, 169)
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn seven_card_evaluator_matches_combinations() {
+        let mut rng = thread_rng();
+        let mut deck: Vec<Card> = Card::all().collect();
+        for _ in 0..20_000 {
+            deck.shuffle(&mut rng);
+            for n in 5..=7 {
+                let cards = deck[..n].to_vec();
+                assert_eq!(evaluate_cards(&cards), evaluate_board_combinations(cards.clone()), "{:?}", cards);
+            }
+        }
+    }
+
+    #[test]
+    fn seven_card_evaluator_matches_on_every_rank_pattern() {
+        // a representative of every 7-card rank pattern, with suits dealt
+        // round-robin so no suit gets more than two cards
+        fn walk(counts: &mut [usize; 13], rank: usize, remaining: usize, seen: &mut usize) {
+            if rank == 13 {
+                if remaining == 0 {
+                    let deck: Vec<Card> = Card::all().collect();
+                    let cards: Vec<Card> = (0..13)
+                        .flat_map(|r| std::iter::repeat_n(r, counts[r]))
+                        .enumerate()
+                        .map(|(i, r)| deck[(i % 4) * 13 + r])
+                        .collect();
+                    assert_eq!(evaluate_cards(&cards), evaluate_board_combinations(cards.clone()));
+                    *seen += 1;
+                }
+                return;
+            }
+            for count in 0..=remaining.min(4) {
+                counts[rank] = count;
+                walk(counts, rank + 1, remaining - count, seen);
+            }
+            counts[rank] = 0;
+        }
+
+        let mut seen = 0;
+        walk(&mut [0; 13], 0, 7, &mut seen);
+        assert_eq!(seen, 49205);
+    }
+
+    // An evaluator built from the rules alone, sharing nothing with build.rs:
+    // the category, strongest first, then the ranks that break ties within it.
+    fn reference_key(cards: &[Card]) -> (u8, Vec<usize>) {
+        let mut counts = [0usize; 13];
+        for card in cards {
+            counts[card.rank().index()] += 1;
+        }
+        // bigger groups first, then higher ranks
+        let mut groups: Vec<(usize, usize)> = (0..13).filter(|&r| counts[r] > 0).map(|r| (counts[r], r)).collect();
+        groups.sort_by(|a, b| b.cmp(a));
+        let ranks: Vec<usize> = groups.iter().map(|&(_, rank)| rank).collect();
+
+        let flush = cards.iter().all(|card| card.suit() == cards[0].suit());
+        let straight_high = match ranks[..] {
+            [12, 3, 2, 1, 0] => Some(3),
+            [high, _, _, _, low] if high - low == 4 => Some(high),
+            _ => None,
+        };
+        let category = match (straight_high, flush, groups[0].0, groups[1].0) {
+            (Some(_), true, _, _) => 8,
+            (_, _, 4, _) => 7,
+            (_, _, 3, 2) => 6,
+            (_, true, _, _) => 5,
+            (Some(_), _, _, _) => 4,
+            (_, _, 3, _) => 3,
+            (_, _, 2, 2) => 2,
+            (_, _, 2, _) => 1,
+            _ => 0,
+        };
+        (category, straight_high.map_or(ranks, |high| vec![high]))
+    }
+
+    #[test]
+    fn tables_match_an_independent_ranking_of_every_class() {
+        // one hand for every five-card rank pattern, suited round-robin so it
+        // can't be a flush, and a flush for every five distinct ranks
+        fn walk(counts: &mut [usize; 13], rank: usize, remaining: usize, hands: &mut Vec<Vec<Card>>) {
+            if rank == 13 {
+                if remaining == 0 {
+                    let ranks: Vec<Rank> = (0..13).flat_map(|r| std::iter::repeat_n(Rank::ALL[r], counts[r])).collect();
+                    hands.push(ranks.iter().enumerate().map(|(i, &r)| Card::new(r, Suit::ALL[i % 4])).collect());
+                    if counts.iter().all(|&count| count <= 1) {
+                        hands.push(ranks.iter().map(|&r| Card::new(r, Suit::Spades)).collect());
+                    }
+                }
+                return;
+            }
+            for count in 0..=remaining.min(4) {
+                counts[rank] = count;
+                walk(counts, rank + 1, remaining - count, hands);
+            }
+            counts[rank] = 0;
+        }
+
+        let mut hands = Vec::new();
+        walk(&mut [0; 13], 0, 5, &mut hands);
+        assert_eq!(hands.len(), 7462);
+
+        hands.sort_by_key(|hand| std::cmp::Reverse(reference_key(hand)));
+        for pair in hands.windows(2) {
+            assert_ne!(reference_key(&pair[0]), reference_key(&pair[1]), "{:?} ties {:?}", pair[0], pair[1]);
+        }
+        for (i, hand) in hands.iter().enumerate() {
+            assert_eq!(evaluate_cards(hand), i as u32 + 1, "{:?}", hand);
+        }
+    }
+
+    #[test]
+    fn seven_card_flushes() {
+        let cards: Vec<Card> = ["As", "Ks", "Qs", "Js", "Ts", "Ah", "Ad"].iter().map(|c| new_card(c).unwrap()).collect();
+        assert_eq!(evaluate_board(cards), 1);
+
+        let cards: Vec<Card> = ["2h", "7h", "9h", "Jh", "Kh", "Kd", "Kc"].iter().map(|c| new_card(c).unwrap()).collect();
+        assert_eq!(evaluate_board(cards.clone()), evaluate_board_combinations(cards));
+    }
+
+    fn describe(cards: &[&str]) -> String {
+        describe_hand(cards.iter().map(|c| new_card(c).unwrap()).collect()).unwrap()
+    }
+
+    #[test]
+    fn categories_cover_the_scale() {
+        assert_eq!(rank_to_category(1), Some(HandCategory::StraightFlush));
+        assert_eq!(rank_to_category(169), Some(HandCategory::FullHouse));
+        assert_eq!(rank_to_category(324), Some(HandCategory::Flush));
+        assert_eq!(rank_to_category(1609), Some(HandCategory::Straight));
+        assert_eq!(rank_to_category(6185), Some(HandCategory::OnePair));
+        assert_eq!(rank_to_category(7462), Some(HandCategory::HighCard));
+        assert_eq!(rank_to_category(0), None);
+        assert_eq!(rank_to_category(7463), None);
+    }
+
+    #[test]
+    fn describes_every_category() {
+        assert_eq!(describe(&["As", "Ks", "Qs", "Js", "Ts", "2d", "2c"]), "Royal flush");
+        assert_eq!(describe(&["5h", "4h", "3h", "2h", "Ah"]), "Straight flush, Five high");
+        assert_eq!(describe(&["9c", "9d", "9h", "9s", "Kd", "Ac"]), "Four of a kind, Nines, Ace kicker");
+        assert_eq!(describe(&["Kc", "Kd", "Kh", "2s", "2d", "2c"]), "Full house, Kings full of Twos");
+        assert_eq!(describe(&["Ad", "9d", "6d", "4d", "2d", "Ks"]), "Flush, Ace high");
+        assert_eq!(describe(&["Tc", "9d", "8h", "7s", "6d", "6c"]), "Straight, Ten high");
+        assert_eq!(describe(&["7c", "7d", "7h", "Ks", "2d"]), "Three of a kind, Sevens, King kicker");
+        assert_eq!(describe(&["5c", "5d", "2h", "2s", "Ad", "3c"]), "Two pair, Fives and Twos, Ace kicker");
+        assert_eq!(describe(&["6c", "6d", "Ah", "Js", "3d"]), "Pair of Sixes, Ace kicker");
+        assert_eq!(describe(&["Ac", "Jd", "8h", "5s", "3d", "2c", "9h"]), "High card, Ace, Jack kicker");
+    }
+
+    #[test]
+    fn describe_needs_five_to_seven_cards() {
+        assert_eq!(describe_hand(vec![new_card("As").unwrap()]), None);
+    }
+
+    #[test]
+    fn js_bridge_rejects_garbage() {
+        let royal: Vec<u32> = ["As", "Ks", "Qs", "Js", "Ts"].iter().map(|c| new_card(c).unwrap().into()).collect();
+        assert_eq!(evaluate_hand_js(royal.clone()), Some(1));
+        assert_eq!(describe_hand_js(royal), Some("Royal flush".to_string()));
+        assert_eq!(evaluate_hand_js(vec![1, 2, 3, 4, 5]), None);
+    }
+}
@@ -0,0 +1,621 @@
+use std::fmt;
+use serde::{Deserialize, Serialize};
+use crate::card::{Card, CardParseError, parse_cards};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum Street {
+    Preflop,
+    Flop,
+    Turn,
+    River,
+}
+
+impl Street {
+    /// How many board cards are out on this street.
+    pub fn board_len(self) -> usize {
+        match self {
+            Street::Preflop => 0,
+            Street::Flop => 3,
+            Street::Turn => 4,
+            Street::River => 5,
+        }
+    }
+}
+
+/// What a player did. Amounts are in the hand's currency or chips, and
+/// `Raise::to` is the player's total for the street after raising.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ActionKind {
+    Ante(f64),
+    SmallBlind(f64),
+    BigBlind(f64),
+    Fold,
+    Check,
+    Call(f64),
+    Bet(f64),
+    Raise { by: f64, to: f64 },
+    /// The part of a bet nobody called, given back to the bettor.
+    Uncalled(f64),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Action {
+    pub street: Street,
+    pub player: String,
+    pub kind: ActionKind,
+    pub all_in: bool,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Seat {
+    pub number: u32,
+    pub player: String,
+    /// Chips at the start of the hand.
+    pub stack: f64,
+    /// Known when dealt to the hero, shown down or mucked face up.
+    pub hole_cards: Option<[Card; 2]>,
+    pub sitting_out: bool,
+}
+
+/// What a hand's amounts are counted in. Totals only make sense within one.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Currency {
+    /// Cash-game money, by its code such as "USD"; play money has none.
+    Cash(Option<String>),
+    /// Chips in the tournament with this id, worth nothing outside it.
+    Chips(String),
+}
+
+/// One hand, as written by PokerStars.
+#[derive(Clone, Debug, PartialEq)]
+pub struct HandHistory {
+    pub hand_id: String,
+    pub currency: Currency,
+    pub table: String,
+    pub button_seat: u32,
+    pub small_blind: f64,
+    pub big_blind: f64,
+    pub seats: Vec<Seat>,
+    /// Whoever the hole cards were dealt to.
+    pub hero: Option<String>,
+    pub actions: Vec<Action>,
+    pub board: Vec<Card>,
+    pub total_pot: f64,
+    /// The main pot followed by any side pots; just the total if there
+    /// were none.
+    pub pots: Vec<f64>,
+    pub rake: f64,
+    /// What each winner took from the pots, in the order it was announced.
+    pub collected: Vec<(String, f64)>,
+    /// Lines that were not understood and skipped, such as chat.
+    pub unparsed: Vec<String>,
+}
+
+impl HandHistory {
+    pub fn seat(&self, player: &str) -> Option<&Seat> {
+        self.seats.iter().find(|s| s.player == player)
+    }
+
+    /// Actions on one street, in order.
+    pub fn street_actions(&self, street: Street) -> impl Iterator<Item = &Action> {
+        self.actions.iter().filter(move |a| a.street == street)
+    }
+
+    /// Chips each seat put in over the whole hand, after any uncalled bet
+    /// came back, in seat order.
+    pub fn contributions(&self) -> Vec<f64> {
+        let mut total = vec![0.0; self.seats.len()];
+        let mut street = vec![0.0; self.seats.len()];
+        let mut current = Street::Preflop;
+
+        for action in &self.actions {
+            if action.street != current {
+                current = action.street;
+                street.iter_mut().for_each(|s| *s = 0.0);
+            }
+            let Some(i) = self.seats.iter().position(|s| s.player == action.player) else {
+                continue;
+            };
+            let (added, counts_for_street) = match action.kind {
+                // antes are dead money and don't count towards calling a bet
+                ActionKind::Ante(amount) => (amount, false),
+                ActionKind::SmallBlind(amount) | ActionKind::BigBlind(amount)
+                    | ActionKind::Call(amount) | ActionKind::Bet(amount) => (amount, true),
+                ActionKind::Raise { to, .. } => (to - street[i], true),
+                ActionKind::Uncalled(amount) => (-amount, true),
+                ActionKind::Fold | ActionKind::Check => (0.0, true),
+            };
+            total[i] += added;
+            if counts_for_street {
+                street[i] += added;
+            }
+        }
+        total
+    }
+
+    /// Whether the player folded at some point in the hand.
+    pub fn folded(&self, player: &str) -> bool {
+        self.actions.iter().any(|a| a.player == player && a.kind == ActionKind::Fold)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum HandHistoryErrorKind {
+    /// The text does not start with a "PokerStars Hand #" line.
+    MissingHeader,
+    InvalidHeader,
+    InvalidSeat,
+    InvalidAmount(String),
+    InvalidCards(CardParseError),
+    /// Cards in brackets were expected but missing, or the wrong number.
+    MissingCards,
+    /// A street dealt other than three cards for the flop or one more for
+    /// the turn or river.
+    WrongBoard(Street),
+    /// A second hand started; use `parse_hand_histories` for whole files.
+    UnexpectedHeader,
+}
+
+/// Error from parsing a hand history. `line` is 1-based and counts from the
+/// start of the text that was passed in.
+#[derive(Clone, Debug, PartialEq)]
+pub struct HandHistoryError {
+    pub line: usize,
+    pub kind: HandHistoryErrorKind,
+}
+
+impl fmt::Display for HandHistoryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            HandHistoryErrorKind::MissingHeader => write!(f, "expected a \"PokerStars Hand #\" line")?,
+            HandHistoryErrorKind::InvalidHeader => write!(f, "unrecognised hand header")?,
+            HandHistoryErrorKind::InvalidSeat => write!(f, "unrecognised seat")?,
+            HandHistoryErrorKind::InvalidAmount(text) => write!(f, "invalid amount '{}'", text)?,
+            HandHistoryErrorKind::InvalidCards(e) => write!(f, "{}", e)?,
+            HandHistoryErrorKind::MissingCards => write!(f, "expected cards in brackets")?,
+            HandHistoryErrorKind::WrongBoard(street) => write!(f, "wrong number of board cards for the {}", format!("{:?}", street).to_lowercase())?,
+            HandHistoryErrorKind::UnexpectedHeader => write!(f, "a second hand starts")?,
+        }
+        write!(f, " on line {}", self.line)
+    }
+}
+
+impl std::error::Error for HandHistoryError {}
+
+fn is_header(line: &str) -> bool {
+    line.starts_with("PokerStars Hand #") || line.starts_with("PokerStars Game #")
+}
+
+/// Splits a file of hands on their header lines and parses each one.
+pub fn parse_hand_histories(text: &str) -> Result<Vec<HandHistory>, HandHistoryError> {
+    let lines: Vec<&str> = text.lines().collect();
+    let starts: Vec<usize> = (0..lines.len()).filter(|&i| is_header(lines[i].trim())).collect();
+    if starts.is_empty() {
+        let first = lines.iter().position(|l| !l.trim().is_empty()).unwrap_or(0);
+        return Err(HandHistoryError { line: first + 1, kind: HandHistoryErrorKind::MissingHeader });
+    }
+
+    starts.iter().enumerate().map(|(n, &start)| {
+        let end = starts.get(n + 1).copied().unwrap_or(lines.len());
+        parse_lines(&lines[start..end], start)
+    }).collect()
+}
+
+/// Parses the text of a single hand. Lines that are not understood, like
+/// chat or players joining, are kept in `unparsed` rather than failing.
+pub fn parse_hand_history(text: &str) -> Result<HandHistory, HandHistoryError> {
+    let lines: Vec<&str> = text.lines().collect();
+    parse_lines(&lines, 0)
+}
+
+// `first_line` is how many lines of the original text come before `lines`
+fn parse_lines(lines: &[&str], first_line: usize) -> Result<HandHistory, HandHistoryError> {
+    let mut parser: Option<Parser> = None;
+    for (i, raw) in lines.iter().enumerate() {
+        let line = raw.trim();
+        if line.is_empty() {
+            continue;
+        }
+        let error = |kind| HandHistoryError { line: first_line + i + 1, kind };
+        if is_header(line) {
+            if parser.is_some() {
+                return Err(error(HandHistoryErrorKind::UnexpectedHeader));
+            }
+            parser = Some(Parser::new(line).ok_or(error(HandHistoryErrorKind::InvalidHeader))?);
+        } else {
+            let parser = parser.as_mut().ok_or(error(HandHistoryErrorKind::MissingHeader))?;
+            parser.line(line).map_err(error)?;
+        }
+    }
+
+    let error = HandHistoryError { line: first_line + 1, kind: HandHistoryErrorKind::MissingHeader };
+    Ok(parser.ok_or(error)?.hand)
+}
+
+fn parse_amount(text: &str) -> Result<f64, HandHistoryErrorKind> {
+    let digits: String = text.trim()
+        .trim_start_matches(['$', '€', '£'])
+        .chars().filter(|&c| c != ',')
+        .collect();
+    digits.parse().map_err(|_| HandHistoryErrorKind::InvalidAmount(text.trim().to_string()))
+}
+
+/// The cards inside the last `[...]` of a line.
+fn bracketed_cards(line: &str) -> Result<Vec<Card>, HandHistoryErrorKind> {
+    let start = line.rfind('[').ok_or(HandHistoryErrorKind::MissingCards)?;
+    let inside = &line[start + 1..];
+    let inside = &inside[..inside.find(']').unwrap_or(inside.len())];
+    parse_cards(inside).map_err(HandHistoryErrorKind::InvalidCards)
+}
+
+fn hole_cards(line: &str) -> Result<[Card; 2], HandHistoryErrorKind> {
+    match *bracketed_cards(line)? {
+        [a, b] => Ok([a, b]),
+        _ => Err(HandHistoryErrorKind::MissingCards),
+    }
+}
+
+struct Parser {
+    hand: HandHistory,
+    street: Street,
+    in_summary: bool,
+}
+
+impl Parser {
+    // "PokerStars Hand #243328593920:  Hold'em No Limit ($0.01/$0.02 USD) - ..."
+    fn new(header: &str) -> Option<Parser> {
+        let id_start = header.find('#')? + 1;
+        let id_len = header[id_start..].find(':')?;
+        let hand_id = header[id_start..id_start + id_len].to_string();
+
+        // the blinds are the first parenthesised pair, like "(25/50)", and
+        // a cash game's currency follows them, like "($0.01/$0.02 USD)"
+        let (small_blind, big_blind, code) = header.split('(').skip(1)
+            .filter_map(|part| part.split(')').next())
+            .find_map(|stakes| {
+                let (small, big) = stakes.split_once('/')?;
+                let mut big = big.split_whitespace();
+                let (big, code) = (big.next()?, big.next());
+                Some((parse_amount(small).ok()?, parse_amount(big).ok()?, code))
+            })?;
+
+        // "... Tournament #3456789012, $1.00+$0.10 USD Hold'em ..."
+        let currency = match header.split_once("Tournament #") {
+            Some((_, rest)) => {
+                let id = rest.split(|c: char| !c.is_ascii_digit()).next()?;
+                Currency::Chips(id.to_string())
+            }
+            None => Currency::Cash(code.map(str::to_string)),
+        };
+
+        let hand = HandHistory {
+            hand_id,
+            currency,
+            table: String::new(),
+            button_seat: 0,
+            small_blind,
+            big_blind,
+            seats: Vec::new(),
+            hero: None,
+            actions: Vec::new(),
+            board: Vec::new(),
+            total_pot: 0.0,
+            pots: Vec::new(),
+            rake: 0.0,
+            collected: Vec::new(),
+            unparsed: Vec::new(),
+        };
+        Some(Parser { hand, street: Street::Preflop, in_summary: false })
+    }
+
+    fn line(&mut self, line: &str) -> Result<(), HandHistoryErrorKind> {
+        if let Some(marker) = line.strip_prefix("*** ") {
+            self.street_marker(marker, line)
+        } else if self.in_summary {
+            self.summary_line(line)
+        } else if let Some(rest) = line.strip_prefix("Table '") {
+            self.table_line(rest);
+            Ok(())
+        } else if line.starts_with("Seat ") && self.hand.actions.is_empty() && self.street == Street::Preflop {
+            self.seat_line(line)
+        } else if let Some(rest) = line.strip_prefix("Dealt to ") {
+            self.dealt_line(rest, line)
+        } else if let Some(rest) = line.strip_prefix("Uncalled bet (") {
+            self.uncalled_line(rest, line)
+        } else if let Some((player, rest)) = self.split_player(line, " collected ") {
+            let amount = rest.split(" from ").next().unwrap_or(rest);
+            self.hand.collected.push((player, parse_amount(amount)?));
+            Ok(())
+        } else if let Some((player, rest)) = self.split_player(line, ": ") {
+            self.player_line(player, rest, line)
+        } else {
+            self.hand.unparsed.push(line.to_string());
+            Ok(())
+        }
+    }
+
+    fn street_marker(&mut self, marker: &str, line: &str) -> Result<(), HandHistoryErrorKind> {
+        let street = if marker.starts_with("FLOP") {
+            Street::Flop
+        } else if marker.starts_with("TURN") {
+            Street::Turn
+        } else if marker.starts_with("RIVER") {
+            Street::River
+        } else {
+            self.in_summary = marker.starts_with("SUMMARY");
+            return Ok(());
+        };
+
+        // the flop line shows three cards; turn and river repeat the board
+        // before the new card in its own brackets
+        let cards = bracketed_cards(line)?;
+        let dealt = if street == Street::Flop { 3 } else { 1 };
+        if cards.len() != dealt || self.hand.board.len() + dealt != street.board_len() {
+            return Err(HandHistoryErrorKind::WrongBoard(street));
+        }
+        self.hand.board.extend(cards);
+        self.street = street;
+        Ok(())
+    }
+
+    // "Alcyone IV' 6-max Seat #3 is the button"
+    fn table_line(&mut self, rest: &str) {
+        if let Some((name, rest)) = rest.split_once('\'') {
+            self.hand.table = name.to_string();
+            if let Some(seat) = rest.split('#').nth(1).and_then(|s| s.split_whitespace().next()) {
+                self.hand.button_seat = seat.parse().unwrap_or(0);
+            }
+        }
+    }
+
+    // "Seat 5: fish 99 ($3.12 in chips) is sitting out"
+    fn seat_line(&mut self, line: &str) -> Result<(), HandHistoryErrorKind> {
+        let (number, rest) = line["Seat ".len()..].split_once(": ").ok_or(HandHistoryErrorKind::InvalidSeat)?;
+        let number = number.parse().map_err(|_| HandHistoryErrorKind::InvalidSeat)?;
+        let chips_end = rest.find(" in chips").ok_or(HandHistoryErrorKind::InvalidSeat)?;
+        let open = rest[..chips_end].rfind(" (").ok_or(HandHistoryErrorKind::InvalidSeat)?;
+        let chips = &rest[open + 2..chips_end];
+
+        self.hand.seats.push(Seat {
+            number,
+            player: rest[..open].to_string(),
+            stack: parse_amount(chips)?,
+            hole_cards: None,
+            sitting_out: rest.ends_with("is sitting out"),
+        });
+        Ok(())
+    }
+
+    // "Hero [Ah Kd]"
+    fn dealt_line(&mut self, rest: &str, line: &str) -> Result<(), HandHistoryErrorKind> {
+        let Some((player, _)) = rest.rsplit_once(" [") else {
+            // some sites announce cards dealt to others without showing them
+            return Ok(());
+        };
+        let cards = hole_cards(line)?;
+        self.hand.hero = Some(player.to_string());
+        self.set_hole_cards(player, cards);
+        Ok(())
+    }
+
+    // "$0.20) returned to Hero"
+    fn uncalled_line(&mut self, rest: &str, line: &str) -> Result<(), HandHistoryErrorKind> {
+        let Some((amount, player)) = rest.split_once(") returned to ") else {
+            self.hand.unparsed.push(line.to_string());
+            return Ok(());
+        };
+        self.push_action(player.to_string(), ActionKind::Uncalled(parse_amount(amount)?), false);
+        Ok(())
+    }
+
+    fn player_line(&mut self, player: String, rest: &str, line: &str) -> Result<(), HandHistoryErrorKind> {
+        let (rest, all_in) = match rest.strip_suffix(" and is all-in") {
+            Some(rest) => (rest, true),
+            None => (rest, false),
+        };
+
+        let kind = if rest == "folds" {
+            ActionKind::Fold
+        } else if rest == "checks" {
+            ActionKind::Check
+        } else if let Some(amount) = rest.strip_prefix("calls ") {
+            ActionKind::Call(parse_amount(amount)?)
+        } else if let Some(amount) = rest.strip_prefix("bets ") {
+            ActionKind::Bet(parse_amount(amount)?)
+        } else if let Some((by, to)) = rest.strip_prefix("raises ").and_then(|r| r.split_once(" to ")) {
+            ActionKind::Raise { by: parse_amount(by)?, to: parse_amount(to)? }
+        } else if let Some(amount) = rest.strip_prefix("posts the ante ") {
+            ActionKind::Ante(parse_amount(amount)?)
+        } else if let Some(amount) = rest.strip_prefix("posts small blind ") {
+            ActionKind::SmallBlind(parse_amount(amount)?)
+        } else if let Some(amount) = rest.strip_prefix("posts big blind ") {
+            ActionKind::BigBlind(parse_amount(amount)?)
+        } else if rest.starts_with("shows [") {
+            let cards = hole_cards(line)?;
+            self.set_hole_cards(&player, cards);
+            return Ok(());
+        } else {
+            if rest != "mucks hand" && rest != "doesn't show hand" {
+                self.hand.unparsed.push(line.to_string());
+            }
+            return Ok(());
+        };
+
+        self.push_action(player, kind, all_in);
+        Ok(())
+    }
+
+    fn summary_line(&mut self, line: &str) -> Result<(), HandHistoryErrorKind> {
+        if let Some(rest) = line.strip_prefix("Total pot ") {
+            // "Total pot 3405 Main pot 1805. Side pot 1600. | Rake 0"
+            let (pots, rake) = rest.split_once(" | ").unwrap_or((rest, ""));
+            let words: Vec<&str> = pots.split_whitespace().collect();
+            self.hand.total_pot = parse_amount(words.first().copied().unwrap_or(""))?;
+            for pair in words.windows(2) {
+                if pair[0] == "pot" || pair[0].starts_with("pot-") {
+                    self.hand.pots.push(parse_amount(pair[1].trim_end_matches('.'))?);
+                }
+            }
+            if self.hand.pots.is_empty() {
+                self.hand.pots.push(self.hand.total_pot);
+            }
+            if let Some(rake) = rake.split(" | ").find_map(|part| part.strip_prefix("Rake ")) {
+                self.hand.rake = parse_amount(rake)?;
+            }
+        } else if line.starts_with("Board [") {
+            self.hand.board = bracketed_cards(line)?;
+        } else if line.starts_with("Seat ") && (line.contains(" showed [") || line.contains(" mucked [")) {
+            // "Seat 2: Hero mucked [Ah Kd]" reveals cards the showdown did not
+            let number: Option<u32> = line["Seat ".len()..].split(':').next().and_then(|n| n.parse().ok());
+            let cards = hole_cards(line)?;
+            if let Some(seat) = self.hand.seats.iter_mut().find(|s| Some(s.number) == number) {
+                seat.hole_cards.get_or_insert(cards);
+            }
+        }
+        Ok(())
+    }
+
+    /// Finds the seated player whose name starts `line` and is followed by
+    /// `separator`. Names may contain spaces, so the longest match wins.
+    fn split_player<'a>(&self, line: &'a str, separator: &str) -> Option<(String, &'a str)> {
+        self.hand.seats.iter()
+            .filter(|s| line.starts_with(&s.player) && line[s.player.len()..].starts_with(separator))
+            .max_by_key(|s| s.player.len())
+            .map(|s| (s.player.clone(), &line[s.player.len() + separator.len()..]))
+    }
+
+    fn set_hole_cards(&mut self, player: &str, cards: [Card; 2]) {
+        if let Some(seat) = self.hand.seats.iter_mut().find(|s| s.player == player) {
+            seat.hole_cards = Some(cards);
+        }
+    }
+
+    fn push_action(&mut self, player: String, kind: ActionKind, all_in: bool) {
+        self.hand.actions.push(Action { street: self.street, player, kind, all_in });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CASH_SHOWDOWN: &str = include_str!("../testdata/cash_showdown.txt");
+    const TOURNAMENT_SIDE_POT: &str = include_str!("../testdata/tournament_side_pot.txt");
+    const SESSION: &str = include_str!("../testdata/session.txt");
+
+    fn cards(text: &str) -> Vec<Card> {
+        parse_cards(text).unwrap()
+    }
+
+    fn pair(text: &str) -> Option<[Card; 2]> {
+        let cards = cards(text);
+        Some([cards[0], cards[1]])
+    }
+
+    #[test]
+    fn cash_hand_with_showdown() {
+        let hand = parse_hand_history(CASH_SHOWDOWN).unwrap();
+        assert_eq!(hand.hand_id, "243328593920");
+        assert_eq!(hand.table, "Alcyone IV");
+        assert_eq!(hand.button_seat, 3);
+        assert_eq!((hand.small_blind, hand.big_blind), (0.01, 0.02));
+        assert_eq!(hand.currency, Currency::Cash(Some("USD".to_string())));
+
+        assert_eq!(hand.seats.len(), 5);
+        let fish = hand.seat("fish 99").unwrap();
+        assert_eq!((fish.number, fish.stack), (5, 3.12));
+        assert!(hand.seat("sleepy").unwrap().sitting_out);
+
+        assert_eq!(hand.hero.as_deref(), Some("Hero"));
+        assert_eq!(hand.seat("Hero").unwrap().hole_cards, pair("Ah Kd"));
+        assert_eq!(hand.seat("nit_lord").unwrap().hole_cards, pair("Kh 7d"));
+        assert_eq!(hand.seat("BigStack99").unwrap().hole_cards, None);
+        assert_eq!(hand.board, cards("7h 2c Kc Qs 4d"));
+
+        let flop: Vec<&Action> = hand.street_actions(Street::Flop).collect();
+        assert_eq!(flop.len(), 3);
+        assert_eq!(flop[1].player, "nit_lord");
+        assert_eq!(flop[1].kind, ActionKind::Raise { by: 0.20, to: 0.28 });
+        assert_eq!(hand.actions[0].kind, ActionKind::SmallBlind(0.01));
+        assert_eq!(hand.street_actions(Street::River).last().unwrap().kind, ActionKind::Call(0.40));
+
+        assert_eq!((hand.total_pot, hand.rake), (1.51, 0.06));
+        assert_eq!(hand.pots, vec![1.51]);
+        assert_eq!(hand.collected, vec![("nit_lord".to_string(), 1.45)]);
+        assert_eq!(hand.unparsed, vec!["Hero said, \"nh\""]);
+    }
+
+    #[test]
+    fn tournament_all_in_with_side_pot() {
+        let hand = parse_hand_history(TOURNAMENT_SIDE_POT).unwrap();
+        assert_eq!((hand.small_blind, hand.big_blind), (25.0, 50.0));
+        assert_eq!(hand.currency, Currency::Chips("3456789012".to_string()));
+        assert_eq!(hand.table, "3456789012 7");
+        assert_eq!(hand.actions.iter().filter(|a| matches!(a.kind, ActionKind::Ante(5.0))).count(), 4);
+
+        let all_ins: Vec<&str> = hand.actions.iter().filter(|a| a.all_in).map(|a| a.player.as_str()).collect();
+        assert_eq!(all_ins, ["ShortyMcShort", "Hero", "Villain_B"]);
+        let uncalled = hand.actions.iter().find(|a| matches!(a.kind, ActionKind::Uncalled(_))).unwrap();
+        assert_eq!((uncalled.player.as_str(), uncalled.kind), ("Hero", ActionKind::Uncalled(750.0)));
+
+        assert_eq!(hand.total_pot, 3405.0);
+        assert_eq!(hand.pots, vec![1805.0, 1600.0]);
+        assert_eq!(hand.collected, vec![("Hero".to_string(), 1600.0), ("ShortyMcShort".to_string(), 1805.0)]);
+        assert!(hand.seats.iter().filter(|s| s.player != "folder").all(|s| s.hole_cards.is_some()));
+        assert!(hand.unparsed.is_empty());
+    }
+
+    #[test]
+    fn file_of_hands_with_noise() {
+        let hands = parse_hand_histories(SESSION).unwrap();
+        assert_eq!(hands.len(), 2);
+
+        assert_eq!(hands[0].hand_id, "243328600001");
+        assert!(hands[0].board.is_empty());
+        assert_eq!(hands[0].collected, vec![("Hero".to_string(), 0.25)]);
+        assert_eq!(hands[0].seat("alpha").unwrap().stack, 10.0);
+
+        assert_eq!(hands[1].board, cards("Jc 8d 2c Qh"));
+        assert_eq!(hands[1].street_actions(Street::Turn).count(), 4);
+        assert_eq!(hands[1].unparsed, vec!["delta joins the table at seat #5", "gamma has timed out", "alpha said, \"nice hand\""]);
+    }
+
+    #[test]
+    fn contributions_add_up_to_the_pot() {
+        for text in [CASH_SHOWDOWN, TOURNAMENT_SIDE_POT] {
+            let hand = parse_hand_history(text).unwrap();
+            let total: f64 = hand.contributions().iter().sum();
+            assert!((total - hand.total_pot).abs() < 1e-9, "{} vs {}", total, hand.total_pot);
+        }
+
+        let hand = parse_hand_history(TOURNAMENT_SIDE_POT).unwrap();
+        assert_eq!(hand.contributions(), vec![600.0, 1400.0, 1400.0, 5.0]);
+        assert!(hand.folded("folder") && !hand.folded("Hero"));
+    }
+
+    #[test]
+    fn reports_bad_lines() {
+        let missing = parse_hand_history("\nSeat 1: alpha ($10 in chips)").unwrap_err();
+        assert_eq!(missing, HandHistoryError { line: 2, kind: HandHistoryErrorKind::MissingHeader });
+
+        let bad_card = CASH_SHOWDOWN.replace("[Ah Kd]", "[Ah Kx]");
+        let error = parse_hand_history(&bad_card).unwrap_err();
+        assert_eq!(error.line, 11);
+        assert_eq!(error.to_string(), "invalid suit 'x' at position 4 on line 11");
+
+        let bad_amount = CASH_SHOWDOWN.replace("bets $0.40", "bets $0.4O");
+        assert_eq!(parse_hand_history(&bad_amount).unwrap_err().kind, HandHistoryErrorKind::InvalidAmount("$0.4O".to_string()));
+
+        let short_flop = CASH_SHOWDOWN.replace("*** FLOP *** [7h 2c Kc]", "*** FLOP *** [7h 2c]");
+        let error = parse_hand_history(&short_flop).unwrap_err();
+        assert_eq!(error, HandHistoryError { line: 16, kind: HandHistoryErrorKind::WrongBoard(Street::Flop) });
+        assert_eq!(error.to_string(), "wrong number of board cards for the flop on line 16");
+        let double_turn = CASH_SHOWDOWN.replace("[Qs]", "[Qs 3d]");
+        assert_eq!(parse_hand_history(&double_turn).unwrap_err().kind, HandHistoryErrorKind::WrongBoard(Street::Turn));
+        let no_turn = CASH_SHOWDOWN.replace("*** TURN *** [7h 2c Kc] [Qs]", "");
+        assert_eq!(parse_hand_history(&no_turn).unwrap_err().kind, HandHistoryErrorKind::WrongBoard(Street::River));
+
+        assert_eq!(parse_hand_history(SESSION).unwrap_err().kind, HandHistoryErrorKind::UnexpectedHeader);
+        assert_eq!(parse_hand_histories("").unwrap_err().kind, HandHistoryErrorKind::MissingHeader);
+    }
+}
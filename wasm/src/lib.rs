@@ -1,67 +1,148 @@
 use wasm_bindgen::prelude::*;
 use rand::seq::SliceRandom;
-use rand::thread_rng;
-use crate::card::{Card, new_card};
+use rand::{thread_rng, Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use crate::card::{Card, cards_from_u32, cards_to_u32};
+use crate::card_set::CardSet;
 use crate::evaluate::{evaluate_board};
+pub mod all_in;
+pub mod canonical;
 pub mod card;
+pub mod card_set;
+pub mod dealer;
+pub mod equity;
 pub mod evaluate;
+pub mod hand_history;
 pub mod lookup;
+pub mod preflop;
+mod preflop_tables;
+pub mod quiz;
+pub mod range;
+pub mod replay;
+pub mod scheduler;
+pub mod scoring;
+pub mod state;
+pub mod stats;
+mod rank_hash;
 
-fn new_deck() -> Vec<Card> {
-    let suits = ['s', 'c', 'h', 'd'];
-    let ranks = ['2', '3', '4', '5', '6', '7', '8', '9', 'T', 'J', 'Q', 'K', 'A'];
-
-    let mut deck = Vec::with_capacity(52);
-
-    for &s in &suits {
-        for &r in &ranks {
-            let card = new_card(&format!("{r}{s}")).unwrap();
-            deck.push(card);
-        }
-    }
-    deck
-}
-
+/// A shuffled deck. The cards left are a `CardSet`, so removing dead cards
+/// is O(1) and no card can be dealt twice. Cards are drawn from the shuffled
+/// order, after any cards stacked on top by `new_from_cards`.
+///
+/// Every shuffle comes from a seed, so any deck can be dealt again, natively
+/// or in the browser, with `Deck::with_seed`.
 #[wasm_bindgen]
 pub struct Deck {
-    cards: Vec<Card>,
+    live: CardSet,
+    // drawn first, last element on top
+    stacked: Vec<Card>,
+    // may still hold cards that were removed or dealt
+    order: Vec<Card>,
+    seed: u64,
 }
 
 #[wasm_bindgen]
 impl Deck {
+    /// Shuffles with a random seed.
     #[wasm_bindgen(constructor)]
     pub fn new() -> Deck {
-        let mut cards = new_deck();
-        cards.shuffle(&mut thread_rng());
-        Deck { cards }
+        Deck::with_seed(thread_rng().gen())
+    }
+
+    /// Shuffles with ChaCha8, which yields the same order on every platform.
+    pub fn with_seed(seed: u64) -> Deck {
+        let mut order: Vec<Card> = Card::all().collect();
+        order.shuffle(&mut ChaCha8Rng::seed_from_u64(seed));
+        Deck { live: CardSet::FULL, stacked: Vec::new(), order, seed }
+    }
+
+    /// The seed this deck was shuffled with. Decks from `new_from_cards`
+    /// report 0.
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// How many cards are left to draw.
+    pub fn len(&self) -> usize {
+        self.live.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.live.is_empty()
     }
 
+    #[wasm_bindgen(js_name = new_from_cards)]
+    pub fn new_from_cards_js(cards: Vec<u32>) -> Option<Deck> {
+        Some(Deck::new_from_cards(cards_from_u32(&cards)?))
+    }
+
+    #[wasm_bindgen(js_name = draw)]
+    pub fn draw_js(&mut self, n: usize) -> Vec<u32> {
+        cards_to_u32(&self.draw(n))
+    }
+
+    #[wasm_bindgen(js_name = remove)]
+    pub fn remove_js(&mut self, card: u32) -> bool {
+        Card::from_u32(card).is_some_and(|card| self.remove(card))
+    }
+}
+
+impl Deck {
+    /// A full deck that deals `cards` first, in order, and the rest at
+    /// random from seed 0. Repeated cards are only dealt once.
     pub fn new_from_cards(cards: Vec<Card>) -> Deck {
-        let mut deck = new_deck();
-        for &card in &cards {
-            // remove card from deck
-            if let Some(idx) = deck.iter().position(|&c| c == card) {
-                deck.remove(idx);
-            }
-        }
-        // add cards back to deck to be in front
-        deck.extend(cards.iter().copied());
-        Deck { cards: deck }
+        Deck::new_from_cards_seeded(cards, 0)
     }
 
-    pub fn draw(&mut self, n: usize) -> Vec<Card> {
-        let take = n.min(self.cards.len());
-        self.cards.split_off(self.cards.len() - take)
+    /// Like `new_from_cards`, with the rest of the deck shuffled from `seed`.
+    pub fn new_from_cards_seeded(cards: Vec<Card>, seed: u64) -> Deck {
+        let mut deck = Deck::with_seed(seed);
+        deck.stacked = cards.into_iter().rev().collect();
+        deck
     }
 
-    pub fn remove(&mut self, card: Card) -> bool {
-        if let Some(idx) = self.cards.iter().position(|&c| c == card) {
-            self.cards.remove(idx);
-            true
+    /// Deals `n` cards, or as many as are left. Past the stacked cards, a
+    /// draw takes the last `n` cards of the shuffled order as a block, in
+    /// order, which keeps every seed dealing what it always has.
+    pub fn draw(&mut self, n: usize) -> Vec<Card> {
+        let mut drawn = Vec::with_capacity(n);
+        // cards that were removed or already dealt are skipped
+        while drawn.len() < n {
+            match self.stacked.pop() {
+                Some(card) if self.live.contains(card) => {
+                    self.live.remove(card);
+                    drawn.push(card);
+                }
+                Some(_) => continue,
+                None => break,
+            }
         }
-        else {
-            false
+
+        let mut block = Vec::with_capacity(n - drawn.len());
+        while drawn.len() + block.len() < n {
+            match self.order.pop() {
+                Some(card) if self.live.remove(card) => block.push(card),
+                Some(_) => continue,
+                None => break,
+            }
         }
+        drawn.extend(block.into_iter().rev());
+        drawn
+    }
+
+    pub fn remove(&mut self, card: Card) -> bool {
+        self.live.remove(card)
+    }
+
+    /// The cards still in the deck.
+    pub fn remaining(&self) -> CardSet {
+        self.live
+    }
+}
+
+impl Default for Deck {
+    fn default() -> Self {
+        Deck::new()
     }
 }
 
@@ -93,19 +174,55 @@ pub fn simulate_hand_with_deck(starting_hand: Vec<Card>, deck: &mut Deck) -> i32
     let hero_score = evaluate_board(hero_board);
     let villain_score = evaluate_board(villain_board);
 
-    if hero_score > villain_score {
+    // lower scores are stronger hands (1 is a royal flush)
+    if hero_score < villain_score {
         1
-    } else if villain_score > hero_score {
+    } else if villain_score < hero_score {
         -1
     } else {
         0
     }
 }
 
+/// Splits one pot between `hands` on a complete five card `board`, returning
+/// each hand's share in seat order. Tied winners share equally.
+pub fn showdown_shares(hands: &[Vec<Card>], board: &[Card]) -> Vec<f64> {
+    let scores: Vec<u32> = hands.iter().map(|hand| {
+        let mut cards = hand.clone();
+        cards.extend_from_slice(board);
+        evaluate_board(cards)
+    }).collect();
+
+    // lower scores are stronger hands
+    let best = scores.iter().copied().min().unwrap_or(0);
+    let winners = scores.iter().filter(|&&s| s == best).count() as f64;
+
+    scores.iter().map(|&s| if s == best { 1.0 / winners } else { 0.0 }).collect()
+}
+
+/// Plays out one multi-way hand. Each entry of `hands` is a player's two hole
+/// cards, or empty for a player who is dealt two random cards from `deck`.
+/// `board` holds the community cards already known; only the missing streets
+/// are drawn.
+pub fn simulate_multiway_with_deck(hands: &[Vec<Card>], board: &[Card], deck: &mut Deck) -> Vec<f64> {
+    for &card in hands.iter().flatten().chain(board) {
+        deck.remove(card);
+    }
+
+    let dealt: Vec<Vec<Card>> = hands.iter().map(|hand| {
+        if hand.is_empty() { deck.draw(2) } else { hand.clone() }
+    }).collect();
+
+    let mut full_board = board.to_vec();
+    full_board.extend(deck.draw(5 - board.len()));
+
+    showdown_shares(&dealt, &full_board)
+}
+
 #[cfg(test)]
 mod tests {
     use crate::card::{new_card};
-    use crate::{Deck, simulate_hand_with_deck};
+    use crate::{Deck, simulate_hand_with_deck, simulate_multiway_with_deck};
     fn rigged_deck() -> Deck {
         let order = vec![
             new_card("As").unwrap(), new_card("Ah").unwrap(), // hero
@@ -115,8 +232,7 @@ mod tests {
             new_card("6s").unwrap(), // river
             // remaining 45 cards don't matter for this test
         ];
-
-        Deck {cards: order}
+        Deck::new_from_cards(order)
     }
 
     #[test]
@@ -126,4 +242,100 @@ mod tests {
         let result = simulate_hand_with_deck(starting_hand, &mut deck);
         assert_eq!(result, 1);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn check_flush_beats_aces() {
+        let starting_hand = vec![new_card("7c").unwrap(), new_card("7d").unwrap()];
+        let order = vec![
+            new_card("As").unwrap(), new_card("Ah").unwrap(), // villain
+            new_card("2s").unwrap(), new_card("3s").unwrap(), new_card("4s").unwrap(), // flop
+            new_card("9s").unwrap(), // turn
+            new_card("6s").unwrap(), // river
+        ];
+        let mut deck = Deck::new_from_cards(order);
+        let result = simulate_hand_with_deck(starting_hand, &mut deck);
+        assert_eq!(result, -1);
+    }
+
+    #[test]
+    fn check_board_splits_three_ways() {
+        let hands = vec![
+            vec![new_card("2c").unwrap(), new_card("3d").unwrap()],
+            vec![new_card("4c").unwrap(), new_card("5d").unwrap()],
+            vec![], // random player
+        ];
+        let order = vec![
+            new_card("6c").unwrap(), new_card("7d").unwrap(), // random player
+            new_card("As").unwrap(), new_card("Ks").unwrap(), new_card("Qs").unwrap(), // flop
+            new_card("Js").unwrap(), // turn
+            new_card("Ts").unwrap(), // river
+        ];
+        let mut deck = Deck::new_from_cards(order);
+        let shares = simulate_multiway_with_deck(&hands, &[], &mut deck);
+        assert_eq!(shares, vec![1.0 / 3.0; 3]);
+    }
+
+    #[test]
+    fn check_known_board_is_kept() {
+        let hands = vec![
+            vec![new_card("As").unwrap(), new_card("Ah").unwrap()],
+            vec![new_card("Kc").unwrap(), new_card("Kd").unwrap()],
+        ];
+        let board = vec![
+            new_card("Ks").unwrap(), new_card("7h").unwrap(), new_card("2c").unwrap(), new_card("9d").unwrap(),
+        ];
+        let order = vec![
+            new_card("4s").unwrap(), // river
+            new_card("Kh").unwrap(), new_card("3s").unwrap(), // rest of the deck
+        ];
+        let mut deck = Deck::new_from_cards(order);
+        let shares = simulate_multiway_with_deck(&hands, &board, &mut deck);
+        // the known king on the board gives villain a set
+        assert_eq!(shares, vec![0.0, 1.0]);
+    }
+
+    #[test]
+    fn stacked_cards_come_first_and_only_once() {
+        let ace = new_card("As").unwrap();
+        let king = new_card("Kd").unwrap();
+        let mut deck = Deck::new_from_cards(vec![ace, king, ace]);
+        assert!(deck.remove(king));
+        assert!(!deck.remove(king));
+
+        assert_eq!(deck.draw(1), vec![ace]);
+        assert_eq!(deck.len(), 50);
+        let rest = deck.draw(60);
+        assert_eq!(rest.len(), 50);
+        assert!(!rest.contains(&ace) && !rest.contains(&king));
+        assert!(deck.is_empty());
+    }
+
+    #[test]
+    fn same_seed_same_deck() {
+        let mut a = Deck::with_seed(8842);
+        let mut b = Deck::with_seed(8842);
+        assert_eq!(a.seed(), 8842);
+        assert_eq!(a.draw(52), b.draw(52));
+
+        let mut c = Deck::with_seed(8843);
+        assert_ne!(Deck::with_seed(8842).draw(52), c.draw(52));
+    }
+
+    #[test]
+    fn seeded_order_is_stable() {
+        // ChaCha8 is portable, so this must hold in wasm builds too
+        let mut deck = Deck::with_seed(8842);
+        let dealt: Vec<String> = deck.draw(5).iter().map(|c| c.to_string()).collect();
+        assert_eq!(dealt, ["2h", "6h", "7s", "3c", "8s"]);
+    }
+
+    #[test]
+    fn removed_cards_close_up_the_seeded_order() {
+        // as if the 7s had been taken out of the shuffled deck
+        let mut deck = Deck::with_seed(8842);
+        assert!(deck.remove(new_card("7s").unwrap()));
+        let dealt: Vec<String> = deck.draw(4).iter().map(|c| c.to_string()).collect();
+        assert_eq!(dealt[1..], ["6h", "3c", "8s"]);
+        assert_eq!(deck.len(), 47);
+    }
+}
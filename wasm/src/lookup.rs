@@ -1,230 +1,61 @@
-use once_cell::sync::Lazy;
-use std::collections::HashMap;
-use itertools::Itertools;
-use crate::card::{ prime_prod_from_rank};
+pub use crate::rank_hash::{rank_count_index, RANK_COUNTS};
 
+// FLUSH_TABLE, UNSUITED_5, UNSUITED_6 and UNSUITED_7, generated by build.rs
+include!(concat!(env!("OUT_DIR"), "/lookup_tables.rs"));
 
+/// Best non-flush rank for five, six and seven cards, indexed by
+/// `[n - 5][rank_count_index(counts, n)]`.
+pub static UNSUITED_TABLES: [&[u16]; 3] = [&UNSUITED_5, &UNSUITED_6, &UNSUITED_7];
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::card::{new_card, Card};
 
-pub fn next_bit_permutation(v: u32) -> Option<u32> {
-    let t = (v | (v-1)).wrapping_add(1);
-
-    let nt = (!t).wrapping_add(1);
-    let nv = (!v).wrapping_add(1);
-
-    let w = t | ((((t & nt) / (v & nv)) >> 1) - 1);
-
-    return Some(w)
-}
-
-struct BitSubset {
-    current: Option<u32>,
-    limit: u32
-}
-
-impl BitSubset {
-    pub fn new(n_bits: u8, k: u8) -> Self {
-        let start = (1u32 << k) - 1;
-        let limit = 1u32 << n_bits;
-        Self { current: Some(start), limit}
-    }
-}
-
-impl Iterator for BitSubset {
-    type Item = u32;
-
-    fn next(&mut self) -> Option<Self::Item> {
-        let x = self.current?;
-
-        if x >= self.limit {
-            self.current = None;
-            return None;
-        }
-        else {
-            self.current = next_bit_permutation(x);
-            return Some(x);
-        }
-    }
-}
-
-fn build_flush_table() -> HashMap<u32, u32> {
-
-    // 1287 = binom(13, 5)
-    // Equates to the total number of distinct flushes disregarding suit
-    let mut map: HashMap<u32, u32> = HashMap::with_capacity(1287);
-
-    const STRAIGHT_FLUSHES: [u32; 10] = [
-        0b1_1111_0000_0000, // A-K-Q-J-T
-        0b1111_1000_0000,
-        0b111_1100_0000,
-        0b11_1110_0000,
-        0b1_1111_0000, // T-9-8-7-6
-        0b1111_1000,
-        0b111_1100,
-        0b11_1110,
-        0b1_1111, // 6-5-4-3-2
-        0b1000_0000_01111 // A-5-4-3-2
-    ];
-
-    let mut rank = 1;
-    for sf in STRAIGHT_FLUSHES {
-        let key = prime_prod_from_rank(sf);
-        map.insert(key, rank);
-        rank += 1;
-    }
-
-    let input = 0b1_1111_0000_0000;
-    let prime_input = prime_prod_from_rank(input);
-    let val = map.get(&prime_input).copied().expect("Royal flush missing early");
-    assert_eq!(val, 1);
-
-    rank = 323;
-
-    let all_patterns: Vec<u32> = BitSubset::new(13, 5).collect();
-
-    for bits in all_patterns.into_iter().rev() {
-        if STRAIGHT_FLUSHES.contains(&bits) {
-            continue;
-        } else {
-            let key = prime_prod_from_rank(bits);
-            map.insert(key, rank);
-            rank += 1;
-        }
-    }
-    
-    return map;
-}
-
-pub static FLUSH_LOOKUP: Lazy<HashMap<u32, u32>> = Lazy::new(build_flush_table);
-
-fn build_unsuited_table() -> HashMap<u32, u32> {
-    let mut map: HashMap<u32, u32> = HashMap::with_capacity(6175);
-
-    const PRIMES: [u32; 13] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41];
-
-
-    let mut rank = 11;
-    for r in (0..13).rev() {
-        for s in (0..13).rev().filter(|&k| k != r) {
-            let val = PRIMES[r].pow(4) * PRIMES[s];
-            map.insert(val, rank);
-            rank += 1;
-        }
-    }
-
-    for r in (0..13).rev() {
-        for s in (0..13).rev().filter(|&k| k != r) {
-            let val = PRIMES[r].pow(3) * PRIMES[s].pow(2);
-            map.insert(val, rank);
-            rank += 1;
-        }
-    }
-    
-    rank = 1600;
-
-    const STRAIGHTS: [u32; 10] = [
-        0b1_1111_0000_0000, // A-K-Q-J-T
-        0b1111_1000_0000,
-        0b111_1100_0000,
-        0b11_1110_0000,
-        0b1_1111_0000, // T-9-8-7-6
-        0b1111_1000,
-        0b111_1100,
-        0b11_1110,
-        0b1_1111, // 6-5-4-3-2
-        0b1000_0000_01111 // A-5-4-3-2
-    ];
-
-    for straight in STRAIGHTS {
-        let val = prime_prod_from_rank(straight);
-        map.insert(val, rank);
-        rank += 1;
-    }
-
-    for trips in (0..13).rev() {
-        let kickers: Vec<usize> = (0..13).rev().filter(|&k| k != trips).collect();
-
-        for (k1, k2) in kickers.iter().copied().tuple_combinations() {
-            let key = PRIMES[trips].pow(3) * PRIMES[k1] * PRIMES[k2];
-            map.insert(key, rank);
-            rank += 1;
+    fn unsuited_rank(hand: &[Card]) -> u16 {
+        let mut counts = [0u8; 13];
+        for &card in hand {
+            counts[card.rank().index()] += 1;
         }
+        UNSUITED_TABLES[0][rank_count_index(&counts, 5)]
     }
 
-    for (p1, p2) in (0..13).rev().tuple_combinations() {
-        let kickers: Vec<usize> = (0..13).rev().filter(|&k| k != p1 && k != p2).collect();
-        for kick in kickers {
-            let key = PRIMES[p1].pow(2) * PRIMES[p2].pow(2) * PRIMES[kick];
-            map.insert(key, rank);
-            rank += 1;
-        }
-    }
+    #[test]
+    fn rank_count_index_is_dense() {
+        assert_eq!(RANK_COUNTS[13][5], 6175);
+        assert_eq!(RANK_COUNTS[13][7], 49205);
 
-    for pair in (0..13).rev() {
-        let kickers: Vec<usize> = (0..13).rev().filter(|&k| k != pair).collect();
-        for (k1, k2, k3) in kickers.iter().copied().tuple_combinations() {
-            let key = PRIMES[pair].pow(2) * PRIMES[k1] * PRIMES[k2] * PRIMES[k3];
-            map.insert(key, rank);
-            rank += 1;
-        }
+        let mut lowest = [0u8; 13];
+        lowest[0] = 4;
+        lowest[1] = 1;
+        let mut highest = [0u8; 13];
+        highest[12] = 4;
+        highest[11] = 1;
+        assert_eq!(rank_count_index(&lowest, 5), 6174);
+        assert_eq!(rank_count_index(&highest, 5), 0);
     }
 
-    let straights = [
-        [12, 11, 10, 9, 8],
-        [11, 10, 9, 8, 7],
-        [10, 9, 8, 7, 6],
-        [9, 8, 7, 6, 5],
-        [8, 7, 6, 5, 4],
-        [7, 6, 5, 4, 3],
-        [6, 5, 4, 3, 2],
-        [5, 4, 3, 2, 1],
-        [4, 3, 2, 1, 0],
-        [12, 3, 2, 1, 0]
-    ];
-
-    let straight_keys: Vec<u32> = straights.iter().map(|row| {
-        row.iter().fold(1u32, |acc, &r| acc * PRIMES[r])
-    }).collect();
-
-    let mut i = 1;
-    for high in (0..13).rev() {
-        let kickers: Vec<usize> = (0..13).rev().filter(|&k| k < high).collect();
-        for (k1, k2, k3, k4) in kickers.iter().copied().tuple_combinations() {
-            let key = PRIMES[high] * PRIMES[k1] * PRIMES[k2] * PRIMES[k3] * PRIMES[k4];
-            if straight_keys.contains(&key) {
-                println!("{}", i);
-                i += 1;
-                continue
-            } else {
-                map.insert(key, rank);
-                rank += 1;
-            }
+    #[test]
+    fn tables_cover_every_pattern() {
+        assert_eq!(FLUSH_TABLE[0b1_1111_0000_0011], 1);
+        assert_eq!(FLUSH_TABLE[0b1111], 0);
+        for (n, table) in (5..=7).zip(UNSUITED_TABLES) {
+            assert_eq!(table.len(), RANK_COUNTS[13][n] as usize);
+            assert!(table.iter().all(|&rank| (11..=7462).contains(&rank)));
         }
     }
 
-    return map;
-}
-
-pub static UNSUITED_LOOKUP: Lazy<HashMap<u32, u32>> = Lazy::new(build_unsuited_table);
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::card::{new_card, prime_prod_from_pbits};
-
     #[test]
     fn check_ryl_flush_rank() {
         let ryl_flush = 0b1_1111_0000_0000;
-        let prime_val = prime_prod_from_rank(ryl_flush);
-        let val = FLUSH_LOOKUP.get(&prime_val).copied().expect("Royal flush missing");
-        let expected: u32 = 1;
+        let val = FLUSH_TABLE[ryl_flush];
+        let expected = 1;
         assert_eq!(val, expected);
     }
     #[test]
     fn check_random_flush() {
-        let kng_flsh = 0b111101000000;
-        let prime_val = prime_prod_from_rank(kng_flsh);
-        let val = FLUSH_LOOKUP.get(&prime_val).copied().expect("king flush missing");
+        let kng_flsh = 0b1111_0100_0000;
+        let val = FLUSH_TABLE[kng_flsh];
         let expected = 816;
         assert_eq!(val, expected);
     }
@@ -239,9 +70,7 @@ mod tests {
 
         let hand = vec![a, b, c, d, e];
 
-        let key = prime_prod_from_pbits(hand);
-
-        let predicted = UNSUITED_LOOKUP.get(&key).copied().expect("Quad aces missing");
+        let predicted = unsuited_rank(&hand);
         let expected = 16;
         assert_eq!(predicted, expected);
     }
@@ -256,9 +85,7 @@ mod tests {
 
         let hand = vec![a, b, c, d, e];
 
-        let key = prime_prod_from_pbits(hand);
-
-        let predicted = UNSUITED_LOOKUP.get(&key).copied().expect("Quad deuces missing");
+        let predicted = unsuited_rank(&hand);
         let expected = 161;
         assert_eq!(predicted, expected);
     }
@@ -273,9 +100,7 @@ mod tests {
 
         let hand = vec![a, b, c, d, e];
 
-        let key = prime_prod_from_pbits(hand);
-
-        let predicted = UNSUITED_LOOKUP.get(&key).copied().expect("FH missing");
+        let predicted = unsuited_rank(&hand);
         let expected = 297;
         assert_eq!(predicted, expected);
     }
@@ -290,9 +115,7 @@ mod tests {
 
         let hand = vec![a, b, c, d, e];
 
-        let key = prime_prod_from_pbits(hand);
-
-        let predicted = UNSUITED_LOOKUP.get(&key).copied().expect("FH missing");
+        let predicted = unsuited_rank(&hand);
         let expected = 1609;
         assert_eq!(predicted, expected);
     }
@@ -307,9 +130,7 @@ mod tests {
 
         let hand = vec![a, b, c, d, e];
 
-        let key = prime_prod_from_pbits(hand);
-
-        let predicted = UNSUITED_LOOKUP.get(&key).copied().expect("FH missing");
+        let predicted = unsuited_rank(&hand);
         let expected = 1873;
         assert_eq!(predicted, expected);
     }
@@ -324,9 +145,7 @@ mod tests {
 
         let hand = vec![a, b, c, d, e];
 
-        let key = prime_prod_from_pbits(hand);
-
-        let predicted = UNSUITED_LOOKUP.get(&key).copied().expect("FH missing");
+        let predicted = unsuited_rank(&hand);
         let expected = 3282;
         assert_eq!(predicted, expected);
     }
@@ -341,9 +160,7 @@ mod tests {
 
         let hand = vec![a, b, c, d, e];
 
-        let key = prime_prod_from_pbits(hand);
-
-        let predicted = UNSUITED_LOOKUP.get(&key).copied().expect("FH missing");
+        let predicted = unsuited_rank(&hand);
         let expected = 6185;
         assert_eq!(predicted, expected);
     }
@@ -358,9 +175,7 @@ mod tests {
 
         let hand = vec![a, b, c, d, e];
 
-        let key = prime_prod_from_pbits(hand);
-
-        let predicted = UNSUITED_LOOKUP.get(&key).copied().expect("FH missing");
+        let predicted = unsuited_rank(&hand);
         let expected = 7462;
         assert_eq!(predicted, expected);
     }
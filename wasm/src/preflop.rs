@@ -0,0 +1,227 @@
+use std::cmp::Ordering;
+use wasm_bindgen::prelude::*;
+use crate::canonical::PreflopClass;
+use crate::card::{Card, cards_from_u32};
+use crate::preflop_tables::{HEADS_UP, VS_RANDOM};
+
+/// Most random opponents `preflop_equity_vs_random` has figures for.
+pub const MAX_OPPONENTS: u32 = 9;
+
+/// Random deals behind each figure in `class_equity_vs_random` against two
+/// or more opponents.
+pub const MULTIWAY_TRIALS: u32 = 200_000;
+
+/// Equity, in percent, of a starting-hand class all in preflop against
+/// 1 to `MAX_OPPONENTS` random hands, precomputed by
+/// `examples/preflop_tables.rs`. Against one hand the figure is exact to the
+/// nearest hundredth of a percent; against more it is a Monte Carlo
+/// estimate, with the standard error `class_std_error_vs_random` gives.
+pub fn class_equity_vs_random(class: PreflopClass, opponents: u32) -> Option<f64> {
+    if !(1..=MAX_OPPONENTS).contains(&opponents) {
+        return None;
+    }
+    Some(VS_RANDOM[class.index()][opponents as usize - 1] as f64 / 100.0)
+}
+
+/// Standard error, in percent, of `class_equity_vs_random`: 0 heads-up,
+/// where the figure is exact. Ties make the spread of each deal's pot share
+/// smaller than a plain win/loss, so for estimates this is an upper bound.
+pub fn class_std_error_vs_random(class: PreflopClass, opponents: u32) -> Option<f64> {
+    let p = class_equity_vs_random(class, opponents)? / 100.0;
+    if opponents == 1 {
+        return Some(0.0);
+    }
+    Some(100.0 * (p * (1.0 - p) / MULTIWAY_TRIALS as f64).sqrt())
+}
+
+/// Table lookup of `hole_cards`' preflop equity against random hands,
+/// answering instantly where `estimate_multiway_equity` would simulate.
+/// Exact heads-up and estimated against more, as `class_equity_vs_random`.
+pub fn preflop_equity_vs_random(hole_cards: &[Card], opponents: u32) -> Option<f64> {
+    class_equity_vs_random(PreflopClass::from_cards(hole_cards)?, opponents)
+}
+
+#[wasm_bindgen(js_name = preflop_equity_vs_random)]
+pub fn preflop_equity_vs_random_js(hole_cards: Vec<u32>, opponents: u32) -> Option<f64> {
+    preflop_equity_vs_random(&cards_from_u32(&hole_cards)?, opponents)
+}
+
+/// Heads-up equity, in percent, of `hero` all in preflop against `villain`,
+/// averaged over every combo of both classes that doesn't share a card.
+/// Exact to the nearest hundredth of a percent, from every board being
+/// enumerated by `examples/preflop_tables.rs`.
+pub fn class_vs_class_equity(hero: PreflopClass, villain: PreflopClass) -> f64 {
+    let (i, j) = (hero.index(), villain.index());
+    // only i < j is stored, row by row
+    let stored = |i: usize, j: usize| HEADS_UP[i * (2 * PreflopClass::COUNT - i - 1) / 2 + (j - i - 1)] as f64 / 100.0;
+    match i.cmp(&j) {
+        Ordering::Less => stored(i, j),
+        Ordering::Equal => 50.0,
+        Ordering::Greater => 100.0 - stored(j, i),
+    }
+}
+
+/// Heads-up equity of one class against another, named like "AKo" and "22".
+#[wasm_bindgen]
+pub fn preflop_matchup(hero: &str, villain: &str) -> Result<f64, JsError> {
+    Ok(class_vs_class_equity(hero.parse()?, villain.parse()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use rand::{Rng, SeedableRng};
+    use rand_chacha::ChaCha8Rng;
+    use crate::canonical::{canonicalize, CanonicalDeal};
+    use crate::card::parse_cards;
+    use crate::equity::{enumerate_equity, estimate_multiway_equity};
+    use crate::{Deck, simulate_hand_with_deck};
+
+    fn equity(class: &str, opponents: u32) -> f64 {
+        class_equity_vs_random(class.parse().unwrap(), opponents).unwrap()
+    }
+
+    #[test]
+    fn known_heads_up_values() {
+        assert_eq!(equity("AA", 1), 85.2);
+        assert_eq!(equity("KK", 1), 82.4);
+        assert_eq!(equity("AKs", 1), 67.04);
+        assert_eq!(equity("72o", 1), 34.58);
+        assert_eq!(equity("22", 1), 50.33);
+    }
+
+    #[test]
+    fn heads_up_column_agrees_with_matrix() {
+        // against a random hand is against each class in proportion to the
+        // combos of it that can still be dealt
+        for hero in PreflopClass::all() {
+            let hero_cards = hero.combos()[0];
+            let (mut total, mut combos) = (0.0, 0);
+            for villain in PreflopClass::all() {
+                let live = villain.combos().iter().filter(|v| !v.iter().any(|c| hero_cards.contains(c))).count();
+                total += live as f64 * class_vs_class_equity(hero, villain);
+                combos += live;
+            }
+            let from_matrix = total / combos as f64;
+            let table = class_equity_vs_random(hero, 1).unwrap();
+            assert!((from_matrix - table).abs() <= 0.01, "{}: matrix gives {} but table says {}", hero, from_matrix, table);
+        }
+    }
+
+    #[test]
+    fn equity_falls_with_more_opponents() {
+        for class in PreflopClass::all() {
+            let row: Vec<f64> = (1..=MAX_OPPONENTS).map(|n| class_equity_vs_random(class, n).unwrap()).collect();
+            assert!(row.windows(2).all(|w| w[0] > w[1]), "{} was {:?}", class, row);
+        }
+        assert_eq!(class_equity_vs_random("AA".parse().unwrap(), 0), None);
+        assert_eq!(class_equity_vs_random("AA".parse().unwrap(), 10), None);
+    }
+
+    #[test]
+    fn std_error_reflects_trials() {
+        let aces = "AA".parse().unwrap();
+        assert_eq!(class_std_error_vs_random(aces, 1), Some(0.0));
+        let two = class_std_error_vs_random(aces, 2).unwrap();
+        assert!((two - 0.1).abs() < 0.01, "AA against two was {}", two);
+        assert_eq!(class_std_error_vs_random(aces, 0), None);
+    }
+
+    #[test]
+    fn any_combo_of_a_class_looks_up_the_same() {
+        let spades = preflop_equity_vs_random(&parse_cards("AsKs").unwrap(), 3);
+        let hearts = preflop_equity_vs_random(&parse_cards("KhAh").unwrap(), 3);
+        assert_eq!(spades, hearts);
+        assert_eq!(preflop_equity_vs_random(&parse_cards("As").unwrap(), 1), None);
+    }
+
+    #[test]
+    fn table_agrees_with_simulation() {
+        let mut rng = ChaCha8Rng::seed_from_u64(11);
+        for (hand, opponents) in [("QcJc", 1), ("9h9d", 4), ("Ah5s", 8)] {
+            let hole_cards = parse_cards(hand).unwrap();
+            let simulated = estimate_multiway_equity(&hole_cards, &[], opponents + 1, 20000, &mut rng).unwrap()[0];
+            let table = preflop_equity_vs_random(&hole_cards, opponents).unwrap();
+            assert!((simulated.equity - table).abs() < 4.0 * simulated.std_error + 0.2,
+                "{} vs {}: simulated {} but table says {}", hand, opponents, simulated.equity, table);
+        }
+    }
+
+    fn matchup(hero: &str, villain: &str) -> f64 {
+        class_vs_class_equity(hero.parse().unwrap(), villain.parse().unwrap())
+    }
+
+    #[test]
+    fn known_matchups() {
+        assert_eq!(matchup("AA", "KK"), 81.95);
+        assert_eq!(matchup("AKo", "22"), 47.35);
+        assert_eq!(matchup("AKs", "QQ"), 46.05);
+        assert_eq!(matchup("22", "AKo"), 100.0 - 47.35);
+        assert_eq!(matchup("T9s", "T9s"), 50.0);
+    }
+
+    #[test]
+    fn matchups_are_complementary() {
+        for hero in PreflopClass::all() {
+            for villain in PreflopClass::all() {
+                let sum = class_vs_class_equity(hero, villain) + class_vs_class_equity(villain, hero);
+                assert!((sum - 100.0).abs() < 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn matrix_matches_enumeration() {
+        // one hero combo stands for them all; villain combos that relate to
+        // it the same way up to suits do equally well
+        let (hero, villain): (PreflopClass, PreflopClass) = ("AKs".parse().unwrap(), "QQ".parse().unwrap());
+        let hero_cards = hero.combos()[0];
+        let mut groups: HashMap<CanonicalDeal, ([Card; 2], u32)> = HashMap::new();
+        for villain_cards in villain.combos() {
+            groups.entry(canonicalize(&hero_cards, &villain_cards).unwrap()).or_insert((villain_cards, 0)).1 += 1;
+        }
+
+        let combos: u32 = groups.values().map(|&(_, count)| count).sum();
+        let exact = groups.values()
+            .map(|(villain_cards, count)| enumerate_equity(&hero_cards, villain_cards, &[]).unwrap().equity * *count as f64)
+            .sum::<f64>() / combos as f64;
+        // stored to the nearest hundredth
+        assert!((exact - class_vs_class_equity(hero, villain)).abs() <= 0.005, "enumerated {}", exact);
+    }
+
+    #[test]
+    fn matrix_matches_simulation() {
+        // every combo of one class is dealt against every compatible combo
+        // of the other the same number of times, which is how the matrix
+        // weighs them
+        let mut rng = ChaCha8Rng::seed_from_u64(17);
+        for (hero, villain) in [("AKs", "QJs"), ("KK", "QQ"), ("AKo", "22")] {
+            let (hero, villain): (PreflopClass, PreflopClass) = (hero.parse().unwrap(), villain.parse().unwrap());
+            let (mut trials, mut points, mut squares) = (0.0, 0.0, 0.0);
+            for hero_cards in hero.combos() {
+                for villain_cards in villain.combos() {
+                    if villain_cards.iter().any(|card| hero_cards.contains(card)) {
+                        continue;
+                    }
+                    for _ in 0..400 {
+                        let mut deck = Deck::new_from_cards_seeded(villain_cards.to_vec(), rng.gen());
+                        let share = 0.5 * (1 + simulate_hand_with_deck(hero_cards.to_vec(), &mut deck)) as f64;
+                        trials += 1.0;
+                        points += share;
+                        squares += share * share;
+                    }
+                }
+            }
+
+            let mean = points / trials;
+            let std_error = 100.0 * ((squares / trials - mean * mean) / trials).sqrt();
+            let simulated = 100.0 * mean;
+            // four standard errors, plus rounding to the nearest hundredth
+            let bound = 4.0 * std_error + 0.005;
+            let table = class_vs_class_equity(hero, villain);
+            assert!((simulated - table).abs() <= bound,
+                "{} vs {}: simulated {} ± {} but matrix says {}", hero, villain, simulated, std_error, table);
+        }
+    }
+}
@@ -0,0 +1,1317 @@
+// Generated by `cargo run --release --example preflop_tables`. Do not edit.
+
+/// Equity of each preflop class, in `PreflopClass::index` order, against
+/// 1 to 9 random hands, in hundredths of a percent. Exact against one
+/// hand and estimated from 200000 deals against more.
+pub static VS_RANDOM: [[u16; 9]; 169] = [
+    [5033, 3072, 2205, 1765, 1545, 1421, 1318, 1248, 1194], // 22
+    [3230, 1992, 1392, 1073, 901, 764, 683, 613, 562], // 32o
+    [3320, 2063, 1471, 1148, 952, 827, 726, 661, 607], // 42o
+    [3428, 2145, 1547, 1213, 993, 861, 767, 684, 631], // 52o
+    [3408, 2060, 1459, 1120, 912, 771, 679, 612, 537], // 62o
+    [3458, 2052, 1423, 1085, 865, 717, 620, 538, 477], // 72o
+    [3683, 2184, 1514, 1144, 925, 752, 648, 561, 498], // 82o
+    [3910, 2315, 1599, 1214, 970, 808, 687, 595, 524], // 92o
+    [4167, 2483, 1732, 1315, 1065, 879, 754, 661, 573], // T2o
+    [4435, 2635, 1856, 1432, 1138, 947, 817, 710, 619], // J2o
+    [4730, 2855, 2000, 1548, 1259, 1038, 888, 773, 673], // Q2o
+    [5051, 3113, 2214, 1694, 1386, 1160, 1006, 863, 765], // K2o
+    [5493, 3538, 2538, 1977, 1631, 1363, 1197, 1030, 918], // A2o
+    [3598, 2395, 1812, 1493, 1309, 1180, 1063, 993, 915], // 32s
+    [5369, 3366, 2407, 1896, 1622, 1455, 1343, 1266, 1188], // 33
+    [3515, 2235, 1634, 1299, 1076, 924, 836, 751, 694], // 43o
+    [3626, 2350, 1708, 1353, 1133, 990, 874, 793, 731], // 53o
+    [3608, 2275, 1634, 1296, 1055, 910, 784, 704, 642], // 63o
+    [3660, 2249, 1612, 1231, 996, 843, 726, 642, 564], // 73o
+    [3748, 2229, 1576, 1189, 945, 794, 668, 584, 512], // 83o
+    [4002, 2387, 1687, 1272, 1017, 840, 704, 610, 543], // 93o
+    [4259, 2552, 1809, 1380, 1099, 909, 788, 675, 589], // T3o
+    [4528, 2725, 1920, 1473, 1179, 982, 835, 715, 635], // J3o
+    [4822, 2952, 2088, 1596, 1297, 1076, 915, 797, 702], // Q3o
+    [5143, 3204, 2274, 1760, 1445, 1190, 1023, 894, 798], // K3o
+    [5584, 3626, 2628, 2054, 1685, 1426, 1228, 1084, 951], // A3o
+    [3683, 2457, 1885, 1560, 1359, 1211, 1114, 1046, 961], // 42s
+    [3864, 2638, 2036, 1695, 1474, 1331, 1207, 1118, 1034], // 43s
+    [5702, 3666, 2634, 2056, 1730, 1523, 1395, 1287, 1214], // 44
+    [3816, 2542, 1902, 1504, 1268, 1094, 991, 905, 825], // 54o
+    [3801, 2467, 1816, 1440, 1201, 1040, 915, 822, 754], // 64o
+    [3855, 2433, 1769, 1392, 1141, 957, 836, 762, 682], // 74o
+    [3945, 2441, 1745, 1335, 1072, 908, 770, 686, 605], // 84o
+    [4067, 2460, 1730, 1316, 1049, 858, 723, 640, 563], // 94o
+    [4350, 2640, 1866, 1432, 1147, 954, 805, 698, 612], // T4o
+    [4619, 2835, 1998, 1528, 1218, 1009, 871, 742, 646], // J4o
+    [4913, 3027, 2153, 1656, 1336, 1108, 940, 818, 717], // Q4o
+    [5233, 3292, 2352, 1826, 1466, 1231, 1041, 910, 809], // K4o
+    [5673, 3718, 2714, 2133, 1753, 1482, 1277, 1109, 988], // A4o
+    [3785, 2552, 1974, 1592, 1407, 1258, 1148, 1054, 983], // 52s
+    [3969, 2733, 2126, 1763, 1532, 1366, 1251, 1164, 1086], // 53s
+    [4145, 2901, 2275, 1880, 1651, 1489, 1351, 1248, 1167], // 54s
+    [6032, 4007, 2883, 2248, 1849, 1597, 1434, 1321, 1236], // 55
+    [3994, 2658, 1985, 1574, 1339, 1149, 1012, 921, 834], // 65o
+    [4051, 2639, 1969, 1567, 1278, 1101, 969, 876, 801], // 75o
+    [4143, 2639, 1915, 1513, 1241, 1043, 909, 797, 721], // 85o
+    [4267, 2652, 1913, 1488, 1183, 986, 851, 731, 659], // 95o
+    [4425, 2714, 1927, 1486, 1194, 990, 836, 725, 643], // T5o
+    [4718, 2915, 2069, 1599, 1284, 1045, 899, 780, 673], // J5o
+    [5012, 3130, 2237, 1715, 1377, 1142, 972, 842, 743], // Q5o
+    [5331, 3392, 2437, 1878, 1509, 1267, 1086, 944, 829], // K5o
+    [5770, 3811, 2812, 2206, 1803, 1530, 1312, 1140, 1017], // A5o
+    [3767, 2484, 1873, 1539, 1328, 1175, 1061, 979, 916], // 62s
+    [3953, 2643, 2050, 1700, 1476, 1295, 1166, 1093, 1000], // 63s
+    [4133, 2845, 2187, 1825, 1575, 1412, 1286, 1185, 1110], // 64s
+    [4313, 3029, 2361, 1979, 1712, 1528, 1381, 1277, 1197], // 65s
+    [6328, 4295, 3149, 2442, 2004, 1721, 1532, 1394, 1303], // 66
+    [4232, 2853, 2133, 1712, 1414, 1211, 1065, 966, 887], // 76o
+    [4324, 2842, 2117, 1683, 1381, 1191, 1026, 911, 829], // 86o
+    [4449, 2846, 2105, 1666, 1335, 1132, 985, 844, 770], // 96o
+    [4609, 2900, 2112, 1652, 1331, 1115, 957, 832, 731], // T6o
+    [4784, 2989, 2146, 1638, 1324, 1105, 928, 803, 704], // J6o
+    [5102, 3230, 2326, 1798, 1453, 1206, 1006, 860, 777], // Q6o
+    [5422, 3501, 2519, 1948, 1593, 1322, 1127, 982, 857], // K6o
+    [5768, 3796, 2755, 2140, 1731, 1459, 1255, 1092, 959], // A6o
+    [3816, 2457, 1853, 1507, 1283, 1130, 1026, 932, 851], // 72s
+    [4004, 2641, 2002, 1647, 1397, 1236, 1117, 1019, 931], // 73s
+    [4185, 2838, 2184, 1788, 1535, 1371, 1231, 1120, 1034], // 74s
+    [4368, 3014, 2339, 1956, 1666, 1472, 1333, 1243, 1158], // 75s
+    [4537, 3184, 2505, 2076, 1799, 1584, 1441, 1320, 1227], // 76s
+    [6624, 4661, 3432, 2665, 2183, 1856, 1634, 1473, 1349], // 77
+    [4505, 3032, 2299, 1848, 1523, 1293, 1139, 1028, 921], // 87o
+    [4630, 3069, 2291, 1827, 1513, 1292, 1098, 986, 878], // 97o
+    [4791, 3129, 2321, 1850, 1494, 1279, 1104, 959, 856], // T7o
+    [4968, 3183, 2339, 1834, 1489, 1243, 1066, 913, 804], // J7o
+    [5177, 3306, 2397, 1860, 1507, 1242, 1053, 911, 804], // Q7o
+    [5519, 3603, 2636, 2038, 1655, 1386, 1171, 1025, 883], // K7o
+    [5884, 3917, 2871, 2252, 1819, 1530, 1305, 1140, 989], // A7o
+    [4027, 2589, 1951, 1559, 1337, 1169, 1050, 952, 871], // 82s
+    [4087, 2637, 1973, 1610, 1366, 1202, 1073, 961, 877], // 83s
+    [4270, 2815, 2155, 1749, 1481, 1306, 1172, 1076, 985], // 84s
+    [4454, 3008, 2306, 1915, 1626, 1429, 1294, 1174, 1086], // 85s
+    [4624, 3188, 2473, 2062, 1746, 1548, 1412, 1275, 1198], // 86s
+    [4794, 3373, 2658, 2209, 1889, 1677, 1500, 1372, 1277], // 87s
+    [6916, 4976, 3765, 2956, 2399, 2031, 1777, 1589, 1463], // 88
+    [4810, 3261, 2501, 2006, 1653, 1416, 1237, 1085, 982], // 98o
+    [4972, 3332, 2518, 2029, 1689, 1438, 1249, 1106, 978], // T8o
+    [5149, 3433, 2542, 2038, 1672, 1418, 1202, 1060, 948], // J8o
+    [5360, 3538, 2587, 2058, 1691, 1407, 1205, 1061, 924], // Q8o
+    [5602, 3705, 2700, 2133, 1737, 1461, 1241, 1071, 944], // K8o
+    [5987, 4062, 2990, 2363, 1912, 1607, 1373, 1211, 1055], // A8o
+    [4242, 2701, 2038, 1635, 1401, 1213, 1105, 992, 904], // 92s
+    [4326, 2786, 2090, 1683, 1426, 1246, 1118, 998, 923], // 93s
+    [4386, 2826, 2124, 1716, 1452, 1287, 1141, 1030, 944], // 94s
+    [4572, 3026, 2295, 1876, 1597, 1374, 1244, 1129, 1041], // 95s
+    [4743, 3218, 2477, 2044, 1737, 1525, 1358, 1251, 1140], // 96s
+    [4912, 3401, 2663, 2207, 1882, 1669, 1489, 1353, 1251], // 97s
+    [5080, 3588, 2847, 2360, 2021, 1785, 1590, 1449, 1342], // 98s
+    [7206, 5363, 4123, 3261, 2663, 2256, 1943, 1728, 1571], // 99
+    [5153, 3568, 2760, 2247, 1878, 1619, 1419, 1269, 1137], // T9o
+    [5325, 3632, 2779, 2250, 1882, 1606, 1381, 1215, 1084], // J9o
+    [5536, 3750, 2848, 2271, 1892, 1606, 1388, 1213, 1059], // Q9o
+    [5781, 3920, 2945, 2355, 1945, 1649, 1418, 1225, 1061], // K9o
+    [6077, 4158, 3110, 2460, 2015, 1701, 1465, 1276, 1118], // A9o
+    [4484, 2879, 2154, 1745, 1497, 1301, 1171, 1062, 958], // T2s
+    [4569, 2936, 2225, 1798, 1529, 1340, 1202, 1076, 998], // T3s
+    [4653, 3025, 2283, 1849, 1553, 1372, 1218, 1108, 1002], // T4s
+    [4722, 3069, 2346, 1875, 1611, 1399, 1231, 1122, 1020], // T5s
+    [4894, 3266, 2501, 2044, 1749, 1507, 1340, 1224, 1122], // T6s
+    [5064, 3458, 2681, 2211, 1896, 1664, 1472, 1346, 1228], // T7s
+    [5233, 3672, 2887, 2394, 2056, 1811, 1620, 1465, 1361], // T8s
+    [5403, 3870, 3103, 2593, 2243, 1988, 1777, 1611, 1494], // T9s
+    [7501, 5766, 4521, 3639, 3011, 2534, 2151, 1903, 1717], // TT
+    [5525, 3923, 3077, 2534, 2148, 1856, 1625, 1453, 1321], // JTo
+    [5729, 3987, 3107, 2579, 2168, 1861, 1641, 1443, 1306], // QTo
+    [5974, 4199, 3246, 2651, 2222, 1897, 1656, 1480, 1317], // KTo
+    [6272, 4434, 3394, 2743, 2295, 1973, 1724, 1506, 1326], // ATo
+    [4738, 3050, 2257, 1841, 1563, 1365, 1233, 1116, 1032], // J2s
+    [4823, 3100, 2329, 1883, 1601, 1397, 1265, 1136, 1036], // J3s
+    [4907, 3183, 2385, 1938, 1635, 1442, 1277, 1161, 1055], // J4s
+    [4999, 3287, 2473, 1988, 1703, 1463, 1307, 1178, 1067], // J5s
+    [5061, 3328, 2516, 2037, 1724, 1503, 1339, 1203, 1101], // J6s
+    [5232, 3521, 2703, 2219, 1866, 1640, 1463, 1321, 1199], // J7s
+    [5402, 3748, 2915, 2415, 2057, 1793, 1591, 1436, 1324], // J8s
+    [5566, 3945, 3108, 2600, 2233, 1968, 1757, 1591, 1454], // J9s
+    [5753, 4185, 3396, 2853, 2488, 2209, 1963, 1809, 1660], // JTs
+    [7747, 6115, 4896, 4020, 3355, 2847, 2466, 2166, 1941], // JJ
+    [5813, 4114, 3242, 2680, 2293, 1979, 1727, 1544, 1360], // QJo
+    [6057, 4322, 3369, 2766, 2334, 2029, 1771, 1554, 1406], // KJo
+    [6356, 4553, 3546, 2888, 2436, 2111, 1834, 1606, 1436], // AJo
+    [5017, 3251, 2423, 1986, 1681, 1476, 1315, 1184, 1099], // Q2s
+    [5102, 3301, 2482, 2009, 1726, 1508, 1354, 1209, 1103], // Q3s
+    [5186, 3407, 2568, 2077, 1752, 1536, 1359, 1245, 1140], // Q4s
+    [5277, 3495, 2615, 2116, 1797, 1570, 1397, 1269, 1156], // Q5s
+    [5361, 3570, 2691, 2190, 1855, 1614, 1427, 1292, 1168], // Q6s
+    [5430, 3646, 2772, 2268, 1916, 1667, 1470, 1322, 1216], // Q7s
+    [5602, 3845, 2969, 2426, 2073, 1809, 1623, 1455, 1324], // Q8s
+    [5766, 4047, 3189, 2649, 2262, 1988, 1768, 1580, 1451], // Q9s
+    [5947, 4327, 3446, 2890, 2531, 2229, 1982, 1806, 1638], // QTs
+    [6026, 4421, 3570, 3015, 2627, 2320, 2072, 1887, 1724], // QJs
+    [7993, 6487, 5352, 4487, 3772, 3228, 2832, 2502, 2225], // QQ
+    [6146, 4429, 3523, 2930, 2503, 2175, 1904, 1677, 1507], // KQo
+    [6443, 4680, 3692, 3051, 2595, 2225, 1959, 1743, 1566], // AQo
+    [5321, 3492, 2615, 2136, 1809, 1607, 1441, 1291, 1194], // K2s
+    [5405, 3590, 2687, 2177, 1880, 1629, 1466, 1325, 1199], // K3s
+    [5488, 3644, 2750, 2251, 1905, 1682, 1479, 1325, 1227], // K4s
+    [5579, 3739, 2830, 2298, 1946, 1699, 1512, 1366, 1251], // K5s
+    [5664, 3835, 2918, 2363, 1999, 1751, 1562, 1400, 1293], // K6s
+    [5754, 3917, 2976, 2443, 2067, 1809, 1597, 1436, 1306], // K7s
+    [5831, 4008, 3089, 2516, 2130, 1854, 1663, 1494, 1349], // K8s
+    [5999, 4213, 3313, 2722, 2334, 2034, 1815, 1646, 1483], // K9s
+    [6179, 4483, 3567, 2984, 2585, 2282, 2048, 1853, 1690], // KTs
+    [6257, 4590, 3675, 3088, 2686, 2389, 2127, 1938, 1755], // KJs
+    [6340, 4690, 3843, 3256, 2832, 2510, 2250, 2057, 1870], // KQs
+    [8240, 6883, 5832, 4990, 4305, 3736, 3291, 2935, 2602], // KK
+    [6532, 4814, 3844, 3228, 2806, 2435, 2163, 1918, 1724], // AKo
+    [5738, 3899, 2938, 2417, 2066, 1820, 1622, 1482, 1359], // A2s
+    [5822, 3985, 3024, 2466, 2123, 1852, 1673, 1515, 1401], // A3s
+    [5903, 4071, 3103, 2535, 2168, 1893, 1706, 1536, 1420], // A4s
+    [5992, 4142, 3160, 2594, 2213, 1931, 1737, 1564, 1440], // A5s
+    [5991, 4109, 3136, 2553, 2154, 1894, 1680, 1533, 1396], // A6s
+    [6098, 4245, 3224, 2631, 2245, 1963, 1734, 1559, 1427], // A7s
+    [6194, 4341, 3358, 2740, 2319, 2037, 1810, 1617, 1476], // A8s
+    [6278, 4447, 3471, 2837, 2404, 2107, 1870, 1695, 1540], // A9s
+    [6460, 4697, 3738, 3109, 2674, 2363, 2101, 1902, 1727], // ATs
+    [6539, 4815, 3841, 3219, 2784, 2469, 2198, 1981, 1823], // AJs
+    [6621, 4916, 3978, 3369, 2930, 2614, 2327, 2122, 1911], // AQs
+    [6704, 5066, 4150, 3549, 3111, 2763, 2504, 2257, 2076], // AKs
+    [8520, 7324, 6375, 5559, 4923, 4366, 3862, 3465, 3115], // AA
+];
+
+/// Heads-up equity of class `i` against class `j`, for every `i < j` in
+/// `PreflopClass::index` order, row by row, in hundredths of a percent.
+pub static HEADS_UP: [u16; 14196] = [
+    // 22
+    6658, 6596, 6536, 6649, 6741, 6703, 6682, 6656, 6676, 6695, 6715, 6582, 6279, 1957, 5283, 5230,
+    5275, 5272, 5393, 5341, 5318, 5337, 5357, 5376, 5315, 6223, 5027, 1894, 5108, 5154, 5151, 5272,
+    5340, 5283, 5302, 5321, 5340, 5279, 6166, 4977, 4865, 1843, 5032, 5030, 5151, 5220, 5282, 5266,
+    5285, 5305, 5244, 6269, 5021, 4910, 4798, 1844, 4894, 5020, 5089, 5152, 5256, 5240, 5259, 5307,
+    6353, 5019, 4908, 4797, 4670, 1813, 4887, 4956, 5020, 5124, 5228, 5213, 5240, 6317, 5131, 5020,
+    4909, 4787, 4663, 1835, 4880, 4944, 5049, 5153, 5258, 5250, 6298, 5083, 5083, 4972, 4851, 4727,
+    4657, 1822, 4858, 4963, 5068, 5173, 5285, 6274, 5062, 5030, 5030, 4909, 4786, 4716, 4636, 1813,
+    4837, 4942, 5047, 5160, 6291, 5079, 5047, 5015, 5004, 4881, 4812, 4733, 4616, 1813, 4977, 5082,
+    5195, 6308, 5096, 5064, 5032, 4989, 4977, 4908, 4829, 4713, 4745, 1815, 5118, 5230, 6326, 5114,
+    5081, 5049, 5007, 4963, 5004, 4926, 4810, 4842, 4874, 1819, 5265, 6204, 5055, 5022, 4990, 5051,
+    4988, 4998, 5030, 4914, 4946, 4979, 5011, 1778,
+    // 32o
+    4682, 4015, 3713, 3481, 3234, 3085, 2948, 2859, 2796, 2748, 2532, 4748, 1080, 4523, 3916, 3586,
+    3227, 3156, 2981, 2840, 2748, 2682, 2629, 2411, 4438, 4311, 1258, 3945, 3752, 3585, 3546, 3504,
+    3437, 3414, 3403, 3399, 3251, 3825, 3753, 3747, 1210, 3665, 3529, 3495, 3454, 3437, 3405, 3393,
+    3389, 3241, 3539, 3441, 3572, 3492, 1347, 3441, 3431, 3429, 3413, 3431, 3410, 3406, 3406, 3317,
+    3102, 3418, 3367, 3279, 1398, 3253, 3363, 3349, 3367, 3397, 3383, 3355, 3085, 3033, 3383, 3337,
+    3271, 3100, 1412, 3337, 3331, 3351, 3381, 3417, 3380, 2944, 2867, 3345, 3299, 3270, 3204, 3180,
+    1442, 3319, 3344, 3375, 3412, 3424, 2814, 2733, 3283, 3283, 3254, 3190, 3174, 3163, 1434, 3282,
+    3318, 3356, 3375, 2729, 2645, 3261, 3253, 3270, 3207, 3192, 3186, 3128, 1431, 3328, 3365, 3384,
+    2668, 2581, 3250, 3242, 3251, 3234, 3219, 3214, 3162, 3170, 1430, 3375, 3393, 2621, 2530, 3246,
+    3238, 3247, 3221, 3252, 3247, 3196, 3205, 3213, 1431, 3402, 2424, 2331, 3107, 3099, 3253, 3199,
+    3223, 3264, 3218, 3226, 3235, 3243, 1279,
+    // 42o
+    4026, 3728, 3531, 3284, 3091, 2993, 2905, 2842, 2793, 2577, 5054, 2984, 4633, 4423, 4168, 4023,
+    3984, 3849, 3827, 3804, 3793, 3788, 3661, 4749, 4394, 1060, 3841, 3557, 3213, 3143, 2981, 2857,
+    2765, 2699, 2646, 2428, 3837, 4188, 3685, 1225, 3634, 3518, 3485, 3459, 3454, 3421, 3410, 3406,
+    3258, 3554, 3955, 3415, 3465, 1367, 3466, 3456, 3434, 3435, 3453, 3432, 3428, 3427, 3365, 3822,
+    3090, 3359, 3305, 1455, 3377, 3368, 3402, 3420, 3449, 3436, 3408, 3133, 3787, 3022, 3329, 3296,
+    3219, 1469, 3342, 3383, 3403, 3433, 3470, 3432, 2950, 3661, 2868, 3304, 3275, 3209, 3185, 1443,
+    3324, 3350, 3380, 3417, 3429, 2856, 3640, 2750, 3300, 3276, 3241, 3224, 3168, 1478, 3322, 3359,
+    3397, 3415, 2771, 3618, 2661, 3270, 3293, 3257, 3242, 3191, 3166, 1475, 3368, 3406, 3424, 2711,
+    3607, 2597, 3259, 3273, 3284, 3269, 3219, 3200, 3208, 1474, 3415, 3434, 2663, 3603, 2546, 3255,
+    3269, 3271, 3303, 3253, 3234, 3242, 3251, 1475, 3443, 2466, 3484, 2347, 3116, 3275, 3250, 3273,
+    3269, 3256, 3264, 3273, 3281, 1323,
+    // 52o
+    3740, 3546, 3299, 3140, 2999, 2949, 2887, 2838, 2622, 5667, 3042, 4921, 4633, 4182, 4040, 4001,
+    3899, 3832, 3844, 3833, 3829, 3702, 5658, 4662, 2948, 4525, 4055, 3912, 3874, 3893, 3795, 3808,
+    3796, 3792, 3665, 4749, 4394, 4296, 1044, 3497, 3186, 3128, 2968, 2857, 2782, 2716, 2663, 2445,
+    3566, 3969, 3851, 3361, 1382, 3439, 3424, 3429, 3440, 3469, 3448, 3444, 3443, 3380, 3839, 3721,
+    3066, 3281, 1476, 3375, 3392, 3406, 3442, 3471, 3458, 3429, 3148, 3804, 3686, 3009, 3268, 3218,
+    1489, 3367, 3388, 3425, 3455, 3492, 3454, 2997, 3708, 3704, 2857, 3272, 3234, 3210, 1499, 3329,
+    3401, 3432, 3469, 3481, 2861, 3645, 3613, 2750, 3281, 3245, 3229, 3173, 1478, 3327, 3364, 3401,
+    3420, 2813, 3656, 3624, 2678, 3309, 3279, 3264, 3241, 3171, 1519, 3408, 3446, 3464, 2752, 3645,
+    3613, 2614, 3290, 3305, 3291, 3268, 3204, 3245, 1519, 3455, 3474, 2705, 3641, 3608, 2562, 3285,
+    3293, 3324, 3302, 3239, 3280, 3288, 1519, 3483, 2507, 3522, 3490, 2364, 3291, 3271, 3294, 3318,
+    3260, 3301, 3310, 3318, 1367,
+    // 62o
+    3377, 3128, 2975, 2868, 2775, 2751, 2703, 2652, 5942, 3032, 5200, 5186, 4619, 3896, 3856, 3759,
+    3723, 3691, 3715, 3711, 3611, 5929, 4915, 2934, 5035, 4511, 3768, 3728, 3752, 3686, 3654, 3678,
+    3674, 3570, 5918, 4902, 4760, 2841, 4221, 3641, 3601, 3625, 3681, 3618, 3642, 3637, 3533, 4749,
+    4376, 4276, 4008, 821, 2959, 2925, 2774, 2665, 2603, 2554, 2501, 2447, 3224, 3701, 3582, 3464,
+    2855, 1269, 3168, 3186, 3226, 3272, 3313, 3299, 3297, 2990, 3665, 3547, 3428, 2821, 3025, 1282,
+    3160, 3208, 3255, 3297, 3333, 3321, 2844, 3574, 3569, 3451, 2677, 3042, 3018, 1298, 3179, 3232,
+    3279, 3316, 3354, 2740, 3539, 3507, 3503, 2572, 3079, 3062, 3034, 1313, 3163, 3247, 3285, 3323,
+    2650, 3508, 3476, 3443, 2511, 3120, 3105, 3082, 3017, 1297, 3244, 3282, 3320, 2626, 3530, 3497,
+    3465, 2463, 3158, 3144, 3126, 3095, 3091, 1341, 3326, 3365, 2578, 3525, 3493, 3461, 2411, 3146,
+    3177, 3160, 3130, 3126, 3167, 1341, 3374, 2530, 3433, 3397, 3365, 2359, 3143, 3166, 3195, 3165,
+    3161, 3202, 3210, 1342,
+    // 72o
+    2977, 2822, 2720, 2662, 2594, 2585, 2500, 6154, 3038, 5367, 5349, 5471, 4607, 3745, 3651, 3620,
+    3619, 3599, 3630, 3522, 6109, 5065, 2937, 5198, 5320, 4498, 3617, 3640, 3579, 3578, 3558, 3589,
+    3481, 6096, 5049, 4907, 2841, 5169, 4207, 3489, 3512, 3572, 3541, 3521, 3552, 3444, 6252, 5162,
+    5021, 4880, 2776, 3819, 3402, 3425, 3485, 3576, 3525, 3555, 3543, 4749, 4361, 4259, 3990, 3629,
+    648, 2747, 2614, 2511, 2451, 2415, 2379, 2285, 2851, 3559, 3440, 3321, 3238, 2656, 1122, 2983,
+    3032, 3107, 3164, 3212, 3167, 2702, 3470, 3462, 3343, 3260, 2528, 2854, 1138, 3009, 3089, 3146,
+    3195, 3200, 2603, 3440, 3404, 3399, 3317, 2429, 2899, 2876, 1158, 3050, 3114, 3169, 3174, 2545,
+    3437, 3401, 3369, 3401, 2370, 2967, 2949, 2912, 1179, 3112, 3196, 3202, 2480, 3417, 3381, 3349,
+    3352, 2333, 3019, 3001, 2970, 2966, 1165, 3194, 3199, 2469, 3446, 3409, 3377, 3380, 2298, 3064,
+    3047, 3022, 3046, 3042, 1210, 3243, 2388, 3346, 3310, 3277, 3370, 2209, 3024, 3053, 3028, 3052,
+    3048, 3089, 1180,
+    // 82o
+    2809, 2705, 2652, 2619, 2566, 2520, 6386, 3127, 5576, 5526, 5640, 5705, 4706, 3691, 3659, 3663,
+    3673, 3660, 3588, 6340, 5259, 3022, 5375, 5488, 5553, 4596, 3679, 3618, 3622, 3632, 3619, 3547,
+    6327, 5215, 5073, 2923, 5337, 5402, 4240, 3552, 3611, 3585, 3596, 3583, 3510, 6486, 5320, 5178,
+    5037, 2850, 5251, 3878, 3458, 3518, 3613, 3593, 3580, 3608, 6626, 5380, 5238, 5097, 4954, 2750,
+    3479, 3332, 3391, 3487, 3589, 3544, 3544, 4749, 4452, 4351, 4021, 3684, 3312, 666, 2603, 2515,
+    2458, 2424, 2402, 2324, 2692, 3508, 3500, 3381, 3292, 3173, 2519, 1152, 2966, 3048, 3133, 3197,
+    3214, 2591, 3477, 3441, 3436, 3348, 3229, 2433, 2838, 1173, 3015, 3106, 3171, 3188, 2538, 3480,
+    3443, 3411, 3437, 3318, 2377, 2913, 2882, 1199, 3134, 3199, 3221, 2504, 3487, 3451, 3419, 3417,
+    3412, 2342, 2991, 2965, 2989, 1222, 3196, 3249, 2451, 3474, 3438, 3405, 3403, 3370, 2318, 3049,
+    3024, 3048, 3044, 1210, 3246, 2407, 3407, 3371, 3338, 3429, 3371, 2246, 3067, 3042, 3071, 3095,
+    3091, 1224,
+    // 92o
+    2674, 2619, 2592, 2573, 2483, 6527, 3138, 5645, 5596, 5709, 5775, 5932, 4683, 3626, 3629, 3645,
+    3662, 3546, 6521, 5323, 3087, 5494, 5577, 5638, 5795, 4670, 3626, 3629, 3645, 3662, 3545, 6476,
+    5278, 5185, 2984, 5427, 5487, 5644, 4314, 3615, 3588, 3604, 3621, 3504, 6630, 5384, 5262, 5121,
+    2909, 5336, 5497, 3904, 3521, 3616, 3601, 3619, 3602, 6772, 5445, 5318, 5177, 5033, 2807, 5348,
+    3494, 3394, 3489, 3595, 3583, 3538, 6784, 5592, 5465, 5324, 5184, 5044, 2766, 3182, 3312, 3406,
+    3513, 3622, 3546, 4749, 4432, 4419, 4090, 3708, 3325, 3035, 644, 2468, 2425, 2392, 2371, 2307,
+    2564, 3449, 3449, 3440, 3351, 3232, 3155, 2390, 1144, 2936, 3030, 3122, 3155, 2508, 3450, 3450,
+    3414, 3439, 3319, 3242, 2347, 2809, 1170, 3063, 3155, 3188, 2480, 3463, 3463, 3426, 3424, 3419,
+    3342, 2313, 2896, 2926, 1198, 3183, 3215, 2459, 3477, 3477, 3441, 3439, 3405, 3443, 2291, 2981,
+    3010, 3034, 1222, 3213, 2373, 3368, 3368, 3332, 3423, 3364, 3373, 2230, 3011, 3041, 3065, 3061,
+    1180,
+    // T2o
+    2588, 2558, 2545, 2495, 6657, 3160, 5668, 5662, 5746, 5806, 5964, 5952, 4683, 3605, 3620, 3643,
+    3562, 6615, 5345, 3100, 5527, 5610, 5671, 5829, 5947, 4648, 3595, 3610, 3633, 3552, 6610, 5339,
+    5214, 3051, 5510, 5540, 5692, 5810, 4388, 3595, 3610, 3633, 3552, 6731, 5417, 5292, 5199, 2973,
+    5389, 5545, 5663, 3964, 3618, 3603, 3626, 3646, 6869, 5473, 5348, 5227, 5084, 2869, 5396, 5514,
+    3518, 3491, 3597, 3590, 3582, 6883, 5620, 5496, 5370, 5230, 5090, 2827, 5410, 3197, 3408, 3514,
+    3628, 3590, 6912, 5611, 5605, 5479, 5340, 5200, 5102, 2751, 2912, 3291, 3397, 3511, 3593, 4749,
+    4432, 4399, 4158, 3763, 3348, 3048, 2781, 630, 2373, 2356, 2337, 2275, 2481, 3429, 3420, 3420,
+    3442, 3322, 3245, 3135, 2300, 1144, 2960, 3055, 3119, 2450, 3441, 3432, 3432, 3426, 3421, 3343,
+    3233, 2281, 2831, 1172, 3088, 3152, 2434, 3460, 3452, 3452, 3446, 3413, 3449, 3339, 2260, 2919,
+    2948, 1202, 3185, 2385, 3385, 3376, 3376, 3463, 3405, 3413, 3417, 2201, 2980, 3009, 3038, 1201,
+    // J2o
+    2520, 2507, 2457, 6742, 3137, 5691, 5650, 5777, 5807, 5960, 5949, 5973, 4683, 3586, 3609, 3528,
+    6700, 5366, 3073, 5515, 5642, 5672, 5825, 5944, 5933, 4648, 3576, 3599, 3519, 6658, 5328, 5203,
+    3016, 5508, 5538, 5690, 5809, 5928, 4366, 3567, 3590, 3509, 6819, 5445, 5320, 5196, 2992, 5437,
+    5563, 5676, 5795, 4035, 3601, 3624, 3643, 6925, 5473, 5348, 5224, 5129, 2886, 5414, 5527, 5646,
+    3565, 3590, 3583, 3575, 6934, 5616, 5491, 5367, 5248, 5107, 2842, 5423, 5542, 3219, 3507, 3621,
+    3583, 6965, 5607, 5602, 5478, 5353, 5213, 5115, 2764, 5429, 2926, 3389, 3503, 3586, 6995, 5630,
+    5593, 5588, 5464, 5323, 5225, 5119, 2686, 2684, 3237, 3351, 3433, 4749, 4432, 4399, 4137, 3828,
+    3391, 3068, 2794, 2567, 595, 2339, 2320, 2257, 2415, 3409, 3401, 3393, 3423, 3413, 3336, 3225,
+    3083, 2265, 1129, 3053, 3117, 2399, 3429, 3421, 3412, 3442, 3405, 3442, 3331, 3189, 2244, 2916,
+    1158, 3150, 2350, 3353, 3345, 3336, 3460, 3397, 3406, 3410, 3268, 2185, 2976, 3005, 1158,
+    // Q2o
+    2468, 2419, 6802, 3113, 5702, 5661, 5753, 5828, 5950, 5933, 5959, 5992, 4683, 3574, 3494, 6760,
+    5376, 3046, 5526, 5618, 5693, 5815, 5928, 5918, 5952, 4648, 3565, 3484, 6718, 5339, 5214, 2986,
+    5484, 5558, 5680, 5794, 5913, 5912, 4366, 3555, 3474, 6843, 5423, 5298, 5174, 2954, 5423, 5550,
+    5663, 5782, 5911, 4013, 3580, 3599, 6988, 5491, 5366, 5242, 5115, 2902, 5451, 5534, 5648, 5776,
+    3631, 3580, 3572, 6966, 5606, 5481, 5357, 5234, 5142, 2856, 5430, 5544, 5672, 3257, 3614, 3575,
+    6992, 5591, 5586, 5462, 5340, 5220, 5122, 2778, 5431, 5559, 2946, 3496, 3578, 7023, 5615, 5578,
+    5574, 5452, 5326, 5228, 5121, 2699, 5409, 2698, 3342, 3425, 7058, 5646, 5609, 5572, 5570, 5444,
+    5346, 5240, 5099, 2690, 2576, 3352, 3434, 4749, 4432, 4399, 4137, 3808, 3451, 3102, 2812, 2580,
+    2465, 564, 2303, 2240, 2363, 3397, 3388, 3380, 3402, 3401, 3433, 3323, 3180, 3189, 2228, 1115,
+    3115, 2314, 3321, 3313, 3304, 3419, 3393, 3398, 3401, 3259, 3267, 2169, 2973, 1114,
+    // K2o
+    2379, 6849, 3089, 5706, 5666, 5757, 5797, 5963, 5916, 5936, 5970, 6004, 4683, 3459, 6807, 5380,
+    3019, 5531, 5622, 5662, 5828, 5911, 5895, 5929, 5963, 4648, 3449, 6765, 5342, 5218, 2956, 5488,
+    5527, 5693, 5776, 5890, 5889, 5923, 4366, 3439, 6890, 5427, 5302, 5178, 2921, 5392, 5563, 5646,
+    5759, 5888, 5887, 4013, 3564, 6999, 5462, 5338, 5213, 5087, 2862, 5430, 5513, 5627, 5756, 5884,
+    3609, 3527, 7016, 5617, 5492, 5368, 5245, 5122, 2871, 5459, 5537, 5666, 5794, 3314, 3572, 7010,
+    5575, 5570, 5445, 5323, 5200, 5149, 2791, 5424, 5553, 5681, 2978, 3570, 7037, 5594, 5556, 5552,
+    5430, 5306, 5223, 5116, 2711, 5402, 5531, 2716, 3417, 7072, 5624, 5587, 5550, 5548, 5424, 5341,
+    5235, 5094, 2702, 5566, 2590, 3426, 7107, 5656, 5619, 5582, 5547, 5543, 5460, 5354, 5213, 5244,
+    2699, 2495, 3435, 4749, 4432, 4399, 4137, 3808, 3431, 3155, 2841, 2596, 2477, 2386, 537, 2223,
+    2278, 3288, 3280, 3272, 3387, 3352, 3393, 3393, 3250, 3258, 3267, 2153, 1070,
+    // A2o
+    7055, 3116, 5811, 5771, 5834, 5923, 6054, 6051, 6035, 6069, 6103, 6138, 4695, 7014, 5481, 3042,
+    5636, 5700, 5789, 5919, 6046, 5995, 6029, 6063, 6098, 4661, 6972, 5443, 5318, 2976, 5565, 5654,
+    5785, 5912, 5990, 5989, 6023, 6058, 4380, 6938, 5501, 5377, 5253, 2992, 5395, 5553, 5680, 5758,
+    5886, 5886, 5921, 4134, 7081, 5584, 5460, 5336, 5089, 2975, 5454, 5581, 5659, 5788, 5916, 5916,
+    3746, 7062, 5706, 5582, 5458, 5238, 5146, 2977, 5494, 5572, 5700, 5829, 5958, 3429, 7095, 5704,
+    5699, 5575, 5355, 5264, 5182, 2953, 5473, 5601, 5730, 5860, 3161, 7085, 5690, 5653, 5648, 5428,
+    5337, 5256, 5165, 2872, 5451, 5580, 5709, 2881, 7120, 5720, 5684, 5647, 5547, 5455, 5374, 5283,
+    5142, 2862, 5614, 5744, 2743, 7156, 5752, 5715, 5679, 5546, 5574, 5493, 5402, 5261, 5292, 2860,
+    5779, 2646, 7192, 5784, 5747, 5711, 5578, 5574, 5613, 5522, 5380, 5412, 5444, 2865, 2592, 4749,
+    4447, 4415, 4155, 3920, 3559, 3263, 3012, 2750, 2620, 2527, 2474, 678,
+    // 32s
+    1587, 4818, 4259, 3959, 3629, 3560, 3395, 3260, 3172, 3108, 3057, 2849, 4714, 4595, 1681, 4273,
+    4103, 3953, 3922, 3884, 3821, 3800, 3790, 3786, 3644, 4153, 4083, 4004, 1636, 4020, 3899, 3873,
+    3835, 3821, 3791, 3780, 3777, 3635, 3893, 3800, 3844, 3768, 1763, 3816, 3811, 3810, 3796, 3814,
+    3795, 3791, 3794, 3691, 3489, 3703, 3653, 3571, 1811, 3645, 3746, 3735, 3754, 3783, 3770, 3744,
+    3474, 3422, 3674, 3629, 3567, 3405, 1824, 3722, 3717, 3737, 3767, 3803, 3768, 3339, 3265, 3639,
+    3594, 3566, 3503, 3481, 1852, 3704, 3729, 3759, 3795, 3809, 3215, 3137, 3580, 3580, 3552, 3492,
+    3476, 3464, 1844, 3669, 3705, 3742, 3761, 3134, 3053, 3559, 3551, 3569, 3509, 3495, 3487, 3431,
+    1841, 3714, 3751, 3770, 3076, 2991, 3549, 3541, 3551, 3536, 3522, 3515, 3464, 3473, 1841, 3760,
+    3779, 3030, 2941, 3545, 3537, 3547, 3524, 3555, 3548, 3498, 3506, 3515, 1841, 3788, 2843, 2752,
+    3410, 3402, 3553, 3502, 3525, 3564, 3519, 3527, 3535, 3543, 1699,
+    // 33
+    6502, 6445, 6563, 6661, 6758, 6724, 6702, 6725, 6748, 6771, 6641, 6660, 6139, 1899, 5232, 5286,
+    5313, 5314, 5438, 5384, 5406, 5429, 5452, 5394, 6606, 6086, 4980, 1848, 5164, 5192, 5193, 5318,
+    5384, 5371, 5393, 5416, 5358, 6616, 6193, 5031, 4919, 1855, 5061, 5062, 5187, 5253, 5361, 5348,
+    5371, 5426, 6611, 6282, 5059, 4947, 4825, 1861, 4929, 5059, 5126, 5233, 5341, 5329, 5359, 6528,
+    6370, 5060, 4949, 4827, 4703, 1838, 4926, 4993, 5101, 5209, 5317, 5312, 6517, 6339, 5176, 5065,
+    4943, 4824, 4700, 1866, 4917, 5026, 5134, 5242, 5357, 6497, 6318, 5126, 5126, 5005, 4886, 4762,
+    4692, 1858, 4899, 5008, 5117, 5232, 6518, 6338, 5146, 5114, 5103, 4985, 4862, 4792, 4675, 1858,
+    5043, 5152, 5267, 6539, 6359, 5166, 5134, 5091, 5084, 4961, 4892, 4775, 4808, 1860, 5187, 5302,
+    6560, 6381, 5187, 5155, 5112, 5072, 5060, 4991, 4875, 4908, 4940, 1863, 5338, 6535, 6260, 5131,
+    5099, 5163, 5101, 5057, 5098, 4983, 5015, 5047, 5079, 1823,
+    // 43o
+    4038, 3741, 3543, 3406, 3230, 3132, 3043, 2981, 2932, 2716, 5083, 4749, 1245, 3897, 3638, 3417,
+    3154, 3153, 3030, 2938, 2872, 2820, 2601, 4800, 3853, 3743, 1278, 3749, 3653, 3583, 3608, 3602,
+    3570, 3559, 3554, 3407, 4543, 3571, 3496, 3576, 1425, 3604, 3555, 3583, 3583, 3601, 3581, 3577,
+    3579, 4390, 3382, 3284, 3489, 3438, 1539, 3476, 3520, 3553, 3572, 3601, 3588, 3559, 4190, 3247,
+    3033, 3425, 3392, 3314, 1599, 3348, 3488, 3508, 3538, 3575, 3537, 4126, 3080, 3029, 3448, 3419,
+    3356, 3191, 1619, 3466, 3491, 3522, 3559, 3571, 4105, 2986, 2912, 3443, 3420, 3387, 3322, 3302,
+    1654, 3464, 3501, 3538, 3557, 4083, 2901, 2824, 3413, 3436, 3404, 3340, 3325, 3300, 1651, 3510,
+    3548, 3566, 4072, 2841, 2760, 3403, 3417, 3431, 3367, 3352, 3333, 3342, 1650, 3557, 3575, 4068,
+    2794, 2709, 3398, 3412, 3418, 3401, 3386, 3368, 3376, 3384, 1651, 3585, 3970, 2596, 2510, 3259,
+    3421, 3396, 3371, 3402, 3389, 3398, 3406, 3414, 1499,
+    // 53o
+    3752, 3557, 3455, 3279, 3137, 3088, 3026, 2977, 2761, 5274, 5651, 3029, 4641, 4188, 4047, 3999,
+    4023, 3925, 3937, 3926, 3922, 3795, 5084, 4749, 4408, 1230, 3578, 3390, 3139, 3140, 3030, 2955,
+    2889, 2837, 2619, 4558, 3583, 3979, 3442, 1440, 3577, 3549, 3577, 3588, 3617, 3597, 3593, 3595,
+    4408, 3396, 3850, 3261, 3414, 1560, 3500, 3544, 3558, 3593, 3623, 3609, 3581, 4237, 3293, 3807,
+    3020, 3389, 3339, 1655, 3472, 3493, 3560, 3590, 3627, 3589, 4173, 3127, 3830, 3018, 3416, 3380,
+    3308, 1675, 3470, 3543, 3573, 3611, 3623, 4110, 2991, 3738, 2912, 3424, 3392, 3327, 3306, 1654,
+    3469, 3505, 3543, 3561, 4121, 2943, 3749, 2840, 3452, 3425, 3390, 3374, 3304, 1695, 3550, 3587,
+    3606, 4110, 2883, 3738, 2776, 3433, 3452, 3417, 3402, 3338, 3379, 1694, 3597, 3615, 4106, 2835,
+    3734, 2726, 3429, 3439, 3450, 3435, 3372, 3413, 3421, 1695, 3624, 4008, 2638, 3615, 2526, 3438,
+    3418, 3420, 3452, 3394, 3435, 3443, 3452, 1543,
+    // 63o
+    3389, 3290, 3114, 3006, 2913, 2890, 2841, 2791, 5503, 5922, 3016, 5181, 4627, 3902, 3858, 3882,
+    3816, 3784, 3808, 3804, 3699, 5491, 5912, 4901, 2922, 4273, 3775, 3730, 3755, 3810, 3748, 3771,
+    3767, 3663, 5091, 4749, 4389, 4062, 1007, 3163, 2936, 2946, 2837, 2776, 2727, 2675, 2622, 4291,
+    3241, 3712, 3593, 3050, 1353, 3297, 3338, 3378, 3424, 3465, 3451, 3448, 4128, 3141, 3672, 3554,
+    2832, 3151, 1454, 3295, 3343, 3390, 3437, 3474, 3462, 4064, 2974, 3695, 3577, 2838, 3188, 3144,
+    1474, 3321, 3373, 3421, 3458, 3496, 4029, 2870, 3632, 3628, 2734, 3225, 3188, 3168, 1489, 3305,
+    3389, 3426, 3465, 3998, 2780, 3601, 3569, 2673, 3266, 3231, 3215, 3150, 1473, 3386, 3423, 3462,
+    4020, 2756, 3623, 3591, 2625, 3305, 3275, 3260, 3229, 3225, 1517, 3468, 3506, 4015, 2709, 3619,
+    3586, 2575, 3292, 3308, 3294, 3263, 3259, 3300, 1517, 3516, 3944, 2660, 3522, 3490, 2523, 3289,
+    3297, 3328, 3298, 3294, 3335, 3344, 1518,
+    // 73o
+    3139, 2960, 2859, 2800, 2733, 2724, 2638, 5632, 6102, 3019, 5344, 5466, 4615, 3746, 3770, 3709,
+    3708, 3688, 3719, 3610, 5617, 6090, 5048, 2923, 5316, 4260, 3618, 3642, 3702, 3671, 3651, 3682,
+    3574, 5752, 6246, 5162, 5021, 2858, 3899, 3531, 3555, 3615, 3706, 3654, 3685, 3676, 5098, 4749,
+    4373, 4045, 3709, 835, 2758, 2786, 2684, 2624, 2589, 2554, 2460, 4065, 3001, 3565, 3446, 3364,
+    2667, 1294, 3124, 3173, 3247, 3305, 3353, 3307, 4000, 2833, 3587, 3469, 3386, 2690, 2985, 1313,
+    3151, 3230, 3288, 3336, 3341, 3970, 2734, 3530, 3525, 3442, 2591, 3030, 3010, 1334, 3192, 3256,
+    3310, 3316, 3967, 2676, 3527, 3494, 3526, 2532, 3098, 3083, 3046, 1354, 3253, 3338, 3343, 3947,
+    2610, 3507, 3474, 3477, 2496, 3150, 3135, 3104, 3100, 1341, 3335, 3341, 3975, 2599, 3535, 3503,
+    3506, 2461, 3195, 3181, 3155, 3179, 3175, 1386, 3385, 3857, 2518, 3435, 3402, 3498, 2373, 3155,
+    3186, 3161, 3185, 3181, 3222, 1356,
+    // 83o
+    2809, 2705, 2652, 2619, 2566, 2520, 5664, 6227, 3022, 5413, 5532, 5624, 4603, 3658, 3600, 3605,
+    3615, 3602, 3529, 5648, 6183, 5108, 2922, 5381, 5473, 4247, 3530, 3589, 3564, 3574, 3561, 3488,
+    5784, 6336, 5219, 5077, 2855, 5326, 3885, 3442, 3501, 3597, 3577, 3564, 3590, 5888, 6476, 5305,
+    5164, 5025, 2792, 3488, 3352, 3411, 3506, 3608, 3564, 3564, 5008, 4749, 4358, 4029, 3692, 3321,
+    667, 2615, 2527, 2471, 2437, 2415, 2338, 3855, 2693, 3480, 3361, 3278, 3192, 2530, 1153, 2972,
+    3054, 3139, 3203, 3219, 3824, 2592, 3425, 3417, 3333, 3247, 2445, 2843, 1174, 3021, 3112, 3177,
+    3194, 3827, 2539, 3428, 3391, 3422, 3336, 2389, 2919, 2887, 1200, 3139, 3204, 3227, 3834, 2505,
+    3435, 3399, 3402, 3431, 2355, 2997, 2971, 2995, 1222, 3202, 3254, 3821, 2452, 3422, 3386, 3389,
+    3388, 2331, 3055, 3030, 3054, 3050, 1210, 3252, 3736, 2408, 3354, 3318, 3414, 3389, 2259, 3073,
+    3048, 3077, 3101, 3097, 1224,
+    // 93o
+    2691, 2636, 2609, 2590, 2500, 5788, 6394, 3106, 5558, 5646, 5729, 5795, 4701, 3639, 3643, 3658,
+    3676, 3558, 5744, 6349, 5245, 3004, 5495, 5579, 5644, 4328, 3628, 3602, 3617, 3635, 3517, 5874,
+    6502, 5326, 5185, 2934, 5431, 5497, 3922, 3541, 3635, 3621, 3638, 3620, 5975, 6645, 5405, 5264,
+    5124, 2864, 5349, 3546, 3444, 3538, 3645, 3632, 3588, 5938, 6785, 5466, 5324, 5185, 5045, 2767,
+    3191, 3317, 3412, 3519, 3628, 3552, 5028, 4749, 4449, 4104, 3726, 3375, 3043, 688, 2519, 2477,
+    2444, 2424, 2360, 3836, 2581, 3463, 3454, 3371, 3279, 3160, 2438, 1188, 2976, 3071, 3163, 3196,
+    3838, 2525, 3464, 3428, 3458, 3367, 3248, 2396, 2847, 1214, 3104, 3196, 3229, 3851, 2497, 3477,
+    3441, 3444, 3466, 3347, 2362, 2935, 2964, 1243, 3223, 3256, 3865, 2476, 3491, 3455, 3458, 3453,
+    3448, 2340, 3019, 3048, 3072, 1267, 3254, 3738, 2390, 3382, 3345, 3441, 3412, 3379, 2280, 3050,
+    3079, 3103, 3099, 1224,
+    // T3o
+    2605, 2575, 2562, 2512, 5810, 6488, 3119, 5591, 5679, 5762, 5829, 5987, 4679, 3609, 3624, 3647,
+    3566, 5804, 6482, 5275, 3071, 5578, 5632, 5693, 5850, 4402, 3609, 3624, 3646, 3565, 5907, 6604,
+    5356, 5263, 2999, 5484, 5546, 5703, 3981, 3638, 3623, 3646, 3663, 6003, 6741, 5435, 5314, 5175,
+    2926, 5397, 5559, 3571, 3541, 3647, 3640, 3631, 5967, 6884, 5496, 5370, 5231, 5091, 2827, 5411,
+    3205, 3414, 3520, 3634, 3595, 5999, 6897, 5644, 5518, 5379, 5242, 5102, 2791, 2960, 3332, 3437,
+    3551, 3633, 5028, 4749, 4429, 4172, 3780, 3398, 3056, 2827, 674, 2425, 2409, 2390, 2328, 3817,
+    2498, 3435, 3435, 3461, 3370, 3250, 3173, 2348, 1188, 3001, 3096, 3160, 3829, 2467, 3447, 3447,
+    3446, 3468, 3349, 3272, 2330, 2869, 1217, 3129, 3193, 3848, 2451, 3466, 3466, 3465, 3460, 3455,
+    3378, 2310, 2957, 2986, 1246, 3226, 3755, 2402, 3390, 3390, 3482, 3452, 3419, 3455, 2250, 3018,
+    3047, 3076, 1246,
+    // J3o
+    2537, 2524, 2474, 5831, 6572, 3093, 5579, 5710, 5764, 5825, 5984, 5973, 4679, 3590, 3613, 3532,
+    5793, 6531, 5264, 3036, 5576, 5630, 5691, 5849, 5968, 4380, 3580, 3603, 3522, 5935, 6692, 5385,
+    5260, 3017, 5532, 5564, 5716, 5835, 4052, 3620, 3643, 3661, 6004, 6797, 5435, 5311, 5220, 2943,
+    5415, 5572, 5690, 3618, 3640, 3633, 3624, 5963, 6935, 5492, 5368, 5249, 5108, 2842, 5424, 5542,
+    3228, 3513, 3627, 3588, 5995, 6950, 5640, 5516, 5392, 5255, 5115, 2804, 5439, 2975, 3430, 3544,
+    3626, 6018, 6980, 5631, 5627, 5502, 5366, 5226, 5129, 2726, 2733, 3277, 3391, 3474, 5028, 4749,
+    4429, 4152, 3846, 3441, 3077, 2840, 2613, 639, 2391, 2373, 2311, 3797, 2432, 3415, 3407, 3442,
+    3461, 3341, 3263, 3121, 2314, 1173, 3094, 3158, 3817, 2416, 3435, 3426, 3462, 3452, 3447, 3369,
+    3227, 2294, 2954, 1203, 3191, 3723, 2367, 3358, 3350, 3478, 3444, 3411, 3448, 3306, 2235, 3014,
+    3043, 1202,
+    // Q3o
+    2486, 2436, 5841, 6632, 3066, 5590, 5686, 5784, 5815, 5968, 5958, 5992, 4679, 3578, 3497, 5804,
+    6591, 5274, 3006, 5552, 5650, 5681, 5834, 5953, 5952, 4380, 3569, 3487, 5914, 6716, 5363, 5239,
+    2979, 5519, 5550, 5703, 5823, 5951, 4030, 3599, 3617, 6022, 6860, 5453, 5329, 5206, 2959, 5451,
+    5578, 5692, 5821, 3683, 3630, 3621, 5952, 6966, 5482, 5357, 5235, 5143, 2857, 5430, 5544, 5672,
+    3266, 3619, 3581, 5979, 6977, 5625, 5501, 5378, 5262, 5122, 2818, 5441, 5569, 2995, 3536, 3619,
+    6003, 7009, 5617, 5612, 5490, 5369, 5229, 5131, 2739, 5419, 2747, 3383, 3466, 6034, 7043, 5648,
+    5611, 5608, 5487, 5347, 5250, 5109, 2730, 2625, 3393, 3475, 5028, 4749, 4429, 4152, 3826, 3501,
+    3111, 2858, 2626, 2511, 608, 2355, 2293, 3785, 2380, 3403, 3394, 3421, 3448, 3439, 3361, 3219,
+    3227, 2278, 1159, 3156, 3691, 2331, 3326, 3318, 3438, 3440, 3403, 3440, 3297, 3305, 2219, 3011,
+    1158,
+    // K3o
+    2396, 5845, 6680, 3039, 5594, 5691, 5753, 5828, 5951, 5935, 5969, 6003, 4679, 3462, 5808, 6638,
+    5278, 2976, 5556, 5619, 5694, 5816, 5930, 5929, 5964, 4380, 3452, 5917, 6763, 5367, 5242, 2947,
+    5488, 5563, 5686, 5800, 5928, 5927, 4030, 3582, 5993, 6871, 5424, 5300, 5178, 2918, 5431, 5558,
+    5672, 5800, 5929, 3661, 3577, 5963, 7017, 5493, 5368, 5246, 5123, 2871, 5460, 5538, 5666, 5795,
+    3323, 3577, 5963, 6995, 5608, 5484, 5362, 5242, 5150, 2831, 5434, 5563, 5691, 3026, 3611, 5982,
+    7022, 5595, 5590, 5468, 5349, 5224, 5126, 2751, 5412, 5541, 2765, 3458, 6012, 7057, 5626, 5589,
+    5586, 5467, 5342, 5245, 5103, 2742, 5575, 2640, 3467, 6044, 7092, 5657, 5620, 5586, 5586, 5461,
+    5364, 5222, 5254, 2740, 2545, 3476, 5028, 4749, 4429, 4152, 3825, 3481, 3164, 2887, 2642, 2524,
+    2433, 581, 2276, 3658, 2295, 3294, 3285, 3405, 3399, 3398, 3431, 3288, 3297, 3305, 2203, 1114,
+    // A3o
+    5967, 6886, 3062, 5699, 5791, 5880, 5920, 6086, 6035, 6069, 6103, 6138, 4691, 5929, 6845, 5379,
+    2996, 5657, 5746, 5786, 5952, 6030, 6029, 6063, 6099, 4392, 6014, 6811, 5463, 5339, 3049, 5514,
+    5554, 5725, 5803, 5931, 5930, 5965, 4186, 6097, 6954, 5547, 5423, 5203, 3032, 5455, 5626, 5704,
+    5832, 5961, 5961, 3799, 6035, 7063, 5583, 5459, 5239, 5147, 2978, 5494, 5572, 5701, 5829, 5959,
+    3438, 6074, 7081, 5738, 5613, 5398, 5306, 5183, 2993, 5483, 5611, 5740, 5870, 3210, 6060, 7070,
+    5691, 5687, 5471, 5380, 5257, 5174, 2912, 5461, 5590, 5719, 2930, 6090, 7105, 5722, 5686, 5589,
+    5498, 5375, 5293, 5151, 2902, 5624, 5754, 2793, 6122, 7141, 5754, 5717, 5589, 5617, 5494, 5412,
+    5270, 5302, 2900, 5789, 2696, 6154, 7177, 5786, 5749, 5621, 5617, 5614, 5531, 5390, 5422, 5454,
+    2905, 2642, 5021, 4749, 4445, 4168, 3969, 3609, 3272, 3057, 2796, 2666, 2574, 2521, 722,
+    // 42s
+    4675, 1568, 4189, 3931, 3615, 3547, 3394, 3276, 3188, 3124, 3073, 2865, 4163, 4431, 4020, 1649,
+    3988, 3886, 3860, 3840, 3836, 3806, 3795, 3792, 3650, 3907, 4216, 3775, 3739, 1782, 3835, 3831,
+    3815, 3816, 3834, 3815, 3811, 3813, 3733, 4094, 3477, 3643, 3592, 1863, 3756, 3751, 3783, 3801,
+    3831, 3818, 3792, 3516, 4065, 3411, 3619, 3588, 3515, 1876, 3727, 3765, 3785, 3815, 3850, 3816,
+    3345, 3946, 3265, 3599, 3571, 3509, 3486, 1852, 3709, 3735, 3765, 3801, 3814, 3254, 3926, 3152,
+    3595, 3573, 3539, 3522, 3469, 1885, 3707, 3743, 3780, 3799, 3173, 3906, 3068, 3567, 3589, 3556,
+    3541, 3492, 3467, 1882, 3752, 3788, 3808, 3115, 3896, 3006, 3556, 3571, 3583, 3568, 3520, 3500,
+    3508, 1881, 3797, 3816, 3069, 3892, 2956, 3552, 3567, 3570, 3601, 3553, 3534, 3542, 3550, 1882,
+    3825, 2881, 3776, 2767, 3418, 3572, 3549, 3571, 3569, 3555, 3563, 3571, 3579, 1740,
+    // 43s
+    1738, 4237, 4002, 3800, 3558, 3554, 3436, 3348, 3285, 3235, 3025, 5023, 4174, 4070, 1699, 4096,
+    4013, 3952, 3979, 3975, 3945, 3934, 3930, 3789, 4785, 3918, 3846, 3844, 1836, 3965, 3923, 3954,
+    3955, 3973, 3954, 3950, 3955, 4643, 3745, 3653, 3766, 3718, 1942, 3848, 3894, 3925, 3944, 3973,
+    3960, 3934, 4456, 3621, 3421, 3708, 3678, 3604, 1997, 3733, 3862, 3883, 3912, 3948, 3913, 4395,
+    3464, 3414, 3734, 3706, 3647, 3491, 2017, 3841, 3866, 3896, 3932, 3946, 4375, 3373, 3301, 3730,
+    3708, 3677, 3616, 3596, 2050, 3838, 3874, 3911, 3930, 4355, 3292, 3217, 3702, 3725, 3694, 3634,
+    3619, 3593, 2047, 3883, 3920, 3939, 4345, 3234, 3155, 3692, 3706, 3721, 3661, 3646, 3626, 3635,
+    2046, 3929, 3948, 4341, 3188, 3106, 3688, 3702, 3709, 3694, 3680, 3660, 3669, 3677, 2046, 3957,
+    4246, 3000, 2916, 3553, 3710, 3687, 3664, 3695, 3681, 3689, 3697, 3705, 1904,
+    // 44
+    6349, 6472, 6574, 6676, 6777, 6741, 6767, 6794, 6821, 6692, 6696, 6619, 6000, 1852, 5288, 5323,
+    5354, 5358, 5480, 5470, 5495, 5521, 5466, 6709, 6632, 6111, 5034, 1860, 5192, 5223, 5228, 5350,
+    5460, 5450, 5476, 5534, 6706, 6630, 6205, 5068, 4946, 1872, 5095, 5099, 5222, 5332, 5443, 5433,
+    5467, 6627, 6628, 6298, 5099, 4977, 4857, 1886, 4967, 5094, 5205, 5315, 5426, 5424, 6566, 6548,
+    6389, 5103, 4982, 4863, 4739, 1869, 4961, 5072, 5183, 5295, 5413, 6554, 6536, 6356, 5216, 5095,
+    4976, 4857, 4733, 1902, 4956, 5068, 5179, 5298, 6578, 6560, 6380, 5207, 5197, 5078, 4959, 4836,
+    4729, 1902, 5103, 5214, 5333, 6602, 6584, 6404, 5230, 5188, 5180, 5061, 4938, 4832, 4864, 1904,
+    5249, 5368, 6627, 6609, 6428, 5254, 5211, 5171, 5163, 5041, 4935, 4967, 4999, 1908, 5403, 6605,
+    6587, 6310, 5200, 5265, 5202, 5163, 5151, 5045, 5077, 5109, 5141, 1867,
+    // 54o
+    3764, 3570, 3468, 3401, 3276, 3227, 3164, 3116, 2899, 5184, 5081, 4750, 1415, 3637, 3471, 3343,
+    3151, 3202, 3128, 3062, 3011, 2792, 4704, 4567, 3599, 3502, 1493, 3692, 3683, 3675, 3736, 3765,
+    3745, 3740, 3743, 4554, 4417, 3413, 3341, 3525, 1617, 3638, 3642, 3706, 3741, 3771, 3757, 3729,
+    4384, 4354, 3310, 3215, 3518, 3471, 1739, 3570, 3644, 3711, 3741, 3778, 3740, 4268, 4212, 3241,
+    3029, 3510, 3475, 3403, 1805, 3475, 3647, 3678, 3715, 3727, 4237, 4182, 3122, 3073, 3567, 3534,
+    3472, 3311, 1830, 3609, 3646, 3684, 3702, 4248, 4193, 3073, 3001, 3595, 3568, 3535, 3471, 3437,
+    1871, 3691, 3728, 3747, 4237, 4182, 3013, 2938, 3576, 3595, 3563, 3499, 3470, 3511, 1870, 3737,
+    3756, 4233, 4178, 2965, 2888, 3571, 3582, 3596, 3533, 3505, 3546, 3554, 1871, 3765, 4135, 4080,
+    2768, 2688, 3580, 3561, 3566, 3549, 3526, 3567, 3576, 3584, 1719,
+    // 64o
+    3401, 3302, 3270, 3145, 3052, 3029, 2980, 2930, 5614, 5489, 5906, 3004, 4370, 3907, 3864, 3879,
+    3939, 3877, 3901, 3896, 3792, 5193, 5089, 4750, 4158, 1193, 3244, 3140, 2957, 3010, 2948, 2900,
+    2848, 2795, 4437, 4300, 3257, 3721, 3130, 1410, 3435, 3462, 3526, 3572, 3612, 3599, 3596, 4275,
+    4240, 3157, 3682, 3026, 3283, 1538, 3420, 3494, 3541, 3588, 3625, 3613, 4187, 4127, 3120, 3697,
+    2849, 3309, 3264, 1640, 3425, 3477, 3555, 3592, 3630, 4156, 4097, 3001, 3753, 2895, 3368, 3334,
+    3265, 1665, 3445, 3529, 3567, 3606, 4125, 4066, 2910, 3694, 2835, 3409, 3376, 3312, 3283, 1649,
+    3527, 3564, 3603, 4147, 4088, 2887, 3715, 2787, 3448, 3421, 3385, 3361, 3357, 1692, 3609, 3647,
+    4142, 4083, 2839, 3711, 2737, 3435, 3454, 3419, 3396, 3392, 3433, 1693, 3657, 4071, 3991, 2790,
+    3614, 2685, 3432, 3443, 3453, 3431, 3427, 3468, 3476, 1694,
+    // 74o
+    3151, 3123, 2998, 2939, 2872, 2862, 2777, 5740, 5615, 6083, 3004, 5462, 4359, 3752, 3770, 3831,
+    3800, 3780, 3811, 3703, 5876, 5750, 6239, 5162, 2939, 3955, 3665, 3683, 3744, 3835, 3784, 3814,
+    3805, 5201, 5097, 4750, 4142, 3766, 1021, 2962, 2797, 2856, 2797, 2762, 2727, 2633, 4211, 4151,
+    3018, 3575, 3492, 2861, 1378, 3252, 3324, 3398, 3456, 3504, 3458, 4127, 4047, 2983, 3593, 3510,
+    2700, 3109, 1485, 3285, 3364, 3422, 3476, 3481, 4097, 4016, 2864, 3650, 3567, 2752, 3176, 3135,
+    1510, 3332, 3397, 3451, 3457, 4094, 4013, 2806, 3619, 3651, 2694, 3244, 3208, 3179, 1530, 3394,
+    3479, 3484, 4074, 3993, 2740, 3599, 3602, 2658, 3296, 3260, 3236, 3233, 1517, 3476, 3481, 4102,
+    4022, 2729, 3627, 3630, 2623, 3341, 3311, 3288, 3312, 3308, 1562, 3526, 3984, 3903, 2649, 3527,
+    3623, 2536, 3301, 3317, 3294, 3318, 3314, 3355, 1532,
+    // 84o
+    2971, 2844, 2791, 2758, 2705, 2659, 5771, 5654, 6176, 3004, 5527, 5620, 4346, 3658, 3718, 3693,
+    3703, 3690, 3617, 5907, 5787, 6330, 5218, 2937, 5473, 3942, 3571, 3631, 3726, 3706, 3693, 3719,
+    6012, 5891, 6470, 5305, 5166, 2874, 3568, 3480, 3540, 3635, 3738, 3693, 3693, 5111, 5104, 4750,
+    4126, 3750, 3400, 853, 2626, 2699, 2643, 2610, 2588, 2512, 3983, 3983, 2843, 3486, 3402, 3316,
+    2541, 1324, 3112, 3194, 3278, 3343, 3359, 3951, 3951, 2722, 3541, 3458, 3372, 2606, 2974, 1349,
+    3162, 3253, 3318, 3335, 3954, 3953, 2669, 3516, 3547, 3461, 2551, 3049, 3020, 1375, 3280, 3345,
+    3368, 3961, 3961, 2635, 3524, 3527, 3556, 2517, 3127, 3104, 3128, 1398, 3343, 3395, 3948, 3948,
+    2582, 3510, 3513, 3513, 2494, 3185, 3162, 3186, 3182, 1386, 3392, 3862, 3862, 2538, 3442, 3539,
+    3514, 2422, 3203, 3180, 3210, 3234, 3230, 1400,
+    // 94o
+    2692, 2636, 2609, 2590, 2500, 5749, 5628, 6236, 3003, 5532, 5622, 5715, 4335, 3606, 3583, 3599,
+    3616, 3499, 5880, 5759, 6358, 5220, 2934, 5475, 5568, 3929, 3518, 3612, 3598, 3615, 3597, 5986,
+    5865, 6495, 5304, 5164, 2869, 5424, 3554, 3427, 3521, 3628, 3615, 3571, 5949, 5970, 6635, 5391,
+    5252, 5116, 2809, 3199, 3336, 3431, 3538, 3647, 3571, 5041, 5013, 4750, 4111, 3733, 3383, 3052,
+    689, 2531, 2488, 2456, 2436, 2373, 3842, 3806, 2581, 3434, 3350, 3264, 3178, 2449, 1189, 2981,
+    3076, 3168, 3200, 3843, 3808, 2526, 3411, 3438, 3351, 3266, 2407, 2852, 1215, 3109, 3201, 3234,
+    3856, 3821, 2497, 3424, 3423, 3451, 3365, 2374, 2939, 2968, 1243, 3228, 3261, 3870, 3835, 2476,
+    3438, 3437, 3437, 3466, 2353, 3024, 3053, 3077, 1267, 3258, 3743, 3707, 2390, 3329, 3421, 3396,
+    3396, 2292, 3054, 3083, 3107, 3103, 1225,
+    // T4o
+    2622, 2593, 2579, 2530, 5840, 5719, 6355, 3090, 5642, 5700, 5784, 5851, 4430, 3622, 3636, 3659,
+    3578, 5942, 5821, 6476, 5323, 3018, 5552, 5637, 5703, 3994, 3651, 3636, 3658, 3676, 6043, 5922,
+    6613, 5378, 5239, 2951, 5492, 5559, 3588, 3560, 3665, 3658, 3650, 6007, 6024, 6757, 5457, 5318,
+    5181, 2884, 5411, 3258, 3463, 3569, 3683, 3644, 5999, 5987, 6898, 5518, 5379, 5243, 5103, 2791,
+    2969, 3336, 3442, 3556, 3638, 5062, 5034, 4750, 4200, 3794, 3415, 3106, 2835, 718, 2476, 2460,
+    2442, 2380, 3855, 3819, 2514, 3448, 3475, 3388, 3297, 3178, 2396, 1232, 3041, 3136, 3200, 3867,
+    3831, 2483, 3460, 3459, 3487, 3395, 3276, 2378, 2907, 1261, 3169, 3233, 3886, 3851, 2468, 3480,
+    3479, 3479, 3501, 3382, 2358, 2995, 3024, 1291, 3266, 3792, 3756, 2419, 3403, 3495, 3471, 3465,
+    3460, 2300, 3055, 3084, 3113, 1290,
+    // J4o
+    2555, 2541, 2492, 5828, 5707, 6403, 3055, 5640, 5698, 5782, 5850, 6008, 4408, 3593, 3616, 3535,
+    5971, 5850, 6564, 5320, 3037, 5600, 5655, 5717, 5875, 4066, 3633, 3656, 3674, 6043, 5922, 6669,
+    5375, 5284, 2968, 5510, 5572, 5730, 3635, 3658, 3652, 3643, 6002, 6019, 6807, 5454, 5335, 5199,
+    2899, 5424, 5587, 3280, 3562, 3676, 3637, 5995, 5984, 6951, 5517, 5392, 5256, 5116, 2805, 5439,
+    2984, 3435, 3549, 3631, 6027, 6016, 6965, 5665, 5541, 5404, 5268, 5129, 2766, 2781, 3317, 3431,
+    3514, 5062, 5034, 4750, 4179, 3860, 3458, 3126, 2848, 2659, 683, 2443, 2425, 2363, 3835, 3800,
+    2448, 3420, 3456, 3479, 3388, 3268, 3159, 2362, 1217, 3134, 3198, 3855, 3819, 2432, 3440, 3475,
+    3471, 3494, 3374, 3265, 2342, 2991, 1247, 3231, 3760, 3725, 2384, 3363, 3492, 3463, 3458, 3452,
+    3343, 2284, 3052, 3081, 1246,
+    // Q4o
+    2503, 2453, 5839, 5718, 6463, 3025, 5616, 5718, 5772, 5834, 5993, 5992, 4408, 3582, 3500, 5949,
+    5828, 6588, 5299, 2999, 5587, 5641, 5703, 5862, 5991, 4044, 3612, 3630, 6061, 5940, 6733, 5393,
+    5270, 2984, 5547, 5579, 5732, 5861, 3701, 3648, 3640, 5992, 6009, 6839, 5444, 5322, 5233, 2914,
+    5431, 5588, 5717, 3318, 3668, 3630, 5980, 5968, 6977, 5501, 5379, 5263, 5123, 2818, 5441, 5569,
+    3004, 3541, 3624, 6013, 6001, 6993, 5650, 5528, 5407, 5271, 5132, 2779, 5428, 2796, 3423, 3506,
+    6044, 6032, 7028, 5649, 5647, 5525, 5389, 5250, 5118, 2770, 2674, 3433, 3515, 5061, 5034, 4750,
+    4179, 3840, 3519, 3161, 2867, 2671, 2557, 652, 2407, 2346, 3823, 3787, 2397, 3408, 3435, 3467,
+    3485, 3366, 3256, 3264, 2326, 1203, 3196, 3728, 3693, 2348, 3331, 3451, 3459, 3450, 3444, 3334,
+    3343, 2268, 3048, 1202,
+    // K4o
+    2414, 5843, 5722, 6510, 2995, 5620, 5687, 5785, 5817, 5970, 5969, 6004, 4408, 3465, 5953, 5832,
+    6635, 5302, 2966, 5556, 5654, 5686, 5840, 5968, 5967, 4044, 3595, 6032, 5911, 6744, 5364, 5242,
+    2944, 5527, 5558, 5712, 5840, 5969, 3679, 3595, 6003, 6020, 6889, 5455, 5333, 5213, 2928, 5460,
+    5582, 5710, 5839, 3375, 3626, 5963, 5952, 6996, 5484, 5362, 5243, 5151, 2831, 5435, 5563, 5692,
+    3035, 3616, 5991, 5979, 7007, 5628, 5506, 5387, 5266, 5127, 2791, 5422, 5551, 2814, 3498, 6022,
+    6010, 7042, 5627, 5625, 5505, 5384, 5245, 5113, 2782, 5585, 2689, 3507, 6053, 6042, 7077, 5659,
+    5624, 5624, 5503, 5364, 5232, 5263, 2779, 2594, 3516, 5061, 5034, 4750, 4179, 3840, 3498, 3214,
+    2895, 2688, 2570, 2479, 625, 2328, 3696, 3660, 2311, 3299, 3418, 3418, 3445, 3435, 3325, 3334,
+    3342, 2252, 1159,
+    // A4o
+    5965, 5844, 6717, 3015, 5725, 5814, 5877, 5953, 6070, 6069, 6103, 6139, 4419, 6053, 5932, 6683,
+    5403, 3074, 5586, 5649, 5725, 5843, 5971, 5970, 6005, 4205, 6136, 6016, 6826, 5487, 5271, 3057,
+    5551, 5626, 5744, 5872, 6001, 6001, 3816, 6074, 6092, 6935, 5545, 5329, 5238, 3035, 5495, 5617,
+    5745, 5874, 6003, 3490, 6074, 6063, 7081, 5614, 5398, 5307, 5184, 2993, 5484, 5612, 5741, 5870,
+    3218, 6069, 6058, 7055, 5725, 5509, 5418, 5299, 5175, 2952, 5471, 5599, 5729, 2979, 6100, 6089,
+    7090, 5724, 5628, 5536, 5417, 5293, 5161, 2942, 5634, 5763, 2842, 6131, 6120, 7126, 5755, 5627,
+    5655, 5536, 5412, 5280, 5311, 2940, 5799, 2746, 6164, 6153, 7162, 5788, 5659, 5655, 5656, 5532,
+    5400, 5431, 5463, 2945, 2692, 5053, 5026, 4750, 4195, 3988, 3627, 3322, 3066, 2841, 2712, 2620,
+    2568, 766,
+    // 52s
+    4675, 4582, 1552, 3874, 3589, 3532, 3381, 3276, 3203, 3140, 3089, 2881, 3917, 4229, 4113, 3724,
+    1796, 3807, 3798, 3807, 3821, 3849, 3829, 3825, 3827, 3747, 4110, 3994, 3454, 3566, 1882, 3751,
+    3771, 3787, 3821, 3850, 3837, 3811, 3529, 4081, 3965, 3398, 3558, 3511, 1895, 3746, 3770, 3805,
+    3834, 3870, 3835, 3387, 3990, 3986, 3254, 3566, 3529, 3506, 1904, 3714, 3782, 3812, 3848, 3862,
+    3259, 3931, 3899, 3153, 3577, 3543, 3527, 3474, 1885, 3712, 3748, 3784, 3803, 3211, 3941, 3910,
+    3083, 3604, 3575, 3561, 3538, 3471, 1923, 3789, 3825, 3845, 3153, 3931, 3900, 3021, 3586, 3602,
+    3588, 3566, 3505, 3543, 1922, 3834, 3853, 3107, 3927, 3896, 2971, 3582, 3590, 3621, 3599, 3538,
+    3577, 3585, 1923, 3862, 2919, 3812, 3781, 2782, 3587, 3568, 3591, 3614, 3559, 3598, 3606, 3614,
+    1780,
+    // 53s
+    4682, 1723, 3946, 3774, 3543, 3541, 3436, 3364, 3301, 3250, 3041, 4798, 3928, 4234, 3795, 1850,
+    3936, 3915, 3946, 3960, 3988, 3968, 3964, 3969, 4659, 3757, 4116, 3630, 3692, 1961, 3867, 3913,
+    3929, 3963, 3992, 3979, 3953, 4499, 3663, 4079, 3408, 3672, 3625, 2050, 3843, 3867, 3930, 3959,
+    3995, 3960, 4439, 3506, 4104, 3402, 3701, 3667, 3599, 2069, 3845, 3913, 3943, 3979, 3993, 4380,
+    3378, 4017, 3301, 3712, 3682, 3620, 3600, 2050, 3843, 3879, 3915, 3935, 4390, 3330, 4028, 3232,
+    3739, 3714, 3680, 3664, 3598, 2087, 3920, 3957, 3976, 4380, 3272, 4017, 3170, 3721, 3740, 3707,
+    3692, 3631, 3670, 2087, 3965, 3985, 4376, 3226, 4013, 3121, 3717, 3728, 3740, 3725, 3665, 3703,
+    3712, 2087, 3994, 4282, 3038, 3898, 2931, 3725, 3707, 3710, 3741, 3685, 3724, 3732, 3740, 1945,
+    // 54s
+    1894, 3997, 3845, 3728, 3552, 3596, 3524, 3461, 3411, 3202, 4936, 4803, 3939, 3847, 1899, 4043,
+    4040, 4037, 4098, 4126, 4107, 4103, 4107, 4798, 4664, 3769, 3700, 3796, 2015, 3996, 4004, 4068,
+    4101, 4130, 4118, 4092, 4637, 4607, 3674, 3584, 3794, 3750, 2129, 3935, 4008, 4071, 4101, 4136,
+    4102, 4529, 4474, 3611, 3412, 3790, 3757, 3689, 2190, 3850, 4010, 4040, 4076, 4089, 4500, 4446,
+    3497, 3450, 3847, 3816, 3758, 3604, 2214, 3974, 4010, 4046, 4065, 4511, 4456, 3449, 3380, 3874,
+    3848, 3818, 3757, 3723, 2252, 4051, 4087, 4107, 4500, 4446, 3391, 3319, 3856, 3875, 3845, 3785,
+    3757, 3795, 2251, 4096, 4115, 4497, 4442, 3345, 3270, 3852, 3863, 3878, 3818, 3791, 3829, 3837,
+    2252, 4124, 4402, 4347, 3157, 3080, 3860, 3842, 3848, 3833, 3811, 3850, 3858, 3866, 2109,
+    // 55
+    6376, 6482, 6588, 6693, 6791, 6803, 6833, 6863, 6737, 6797, 6720, 6643, 6026, 1864, 5315, 5353,
+    5387, 5388, 5553, 5546, 5574, 5635, 6798, 6721, 6644, 6122, 5059, 1876, 5224, 5259, 5260, 5426,
+    5539, 5532, 5568, 6720, 6721, 6644, 6218, 5096, 4976, 1896, 5130, 5132, 5298, 5411, 5525, 5525,
+    6662, 6645, 6645, 6314, 5129, 5010, 4891, 1916, 4999, 5170, 5284, 5397, 5518, 6599, 6582, 6564,
+    6404, 5131, 5013, 4893, 4770, 1904, 4997, 5111, 5225, 5346, 6632, 6614, 6596, 6415, 5284, 5166,
+    5047, 4928, 4768, 1946, 5156, 5270, 5392, 6659, 6641, 6623, 6441, 5278, 5270, 5152, 5033, 4873,
+    4915, 1948, 5305, 5427, 6687, 6669, 6651, 6468, 5304, 5264, 5256, 5138, 4978, 5020, 5052, 1952,
+    5462, 6667, 6649, 6631, 6353, 5360, 5298, 5258, 5250, 5091, 5132, 5164, 5197, 1911,
+    // 65o
+    3413, 3314, 3283, 3267, 3191, 3168, 3119, 3069, 5463, 5419, 5332, 4750, 1378, 3300, 3220, 3160,
+    3020, 3120, 3073, 3021, 2969, 4582, 4445, 4308, 3273, 3188, 1464, 3549, 3595, 3623, 3718, 3759,
+    3746, 3743, 4420, 4386, 4248, 3173, 3106, 3393, 1595, 3556, 3591, 3688, 3735, 3772, 3760, 4332,
+    4273, 4243, 3136, 3043, 3437, 3396, 1724, 3522, 3628, 3705, 3742, 3781, 4250, 4191, 4136, 3114,
+    2905, 3462, 3428, 3359, 1795, 3449, 3633, 3670, 3709, 4251, 4192, 4137, 3040, 2995, 3551, 3518,
+    3457, 3287, 1825, 3667, 3704, 3743, 4273, 4214, 4159, 3016, 2948, 3590, 3563, 3530, 3458, 3489,
+    1868, 3749, 3787, 4269, 4210, 4154, 2969, 2898, 3577, 3596, 3564, 3492, 3524, 3565, 1869, 3797,
+    4197, 4117, 4058, 2920, 2847, 3574, 3585, 3599, 3527, 3559, 3600, 3608, 1869,
+    // 75o
+    3163, 3135, 3154, 3078, 3011, 3001, 2916, 5998, 5873, 5749, 6232, 3020, 4037, 3796, 3816, 3868,
+    3963, 3912, 3943, 3934, 5473, 5427, 5339, 4750, 3848, 1207, 3042, 3001, 2867, 2968, 2934, 2900,
+    2807, 4357, 4297, 4160, 3034, 3619, 2941, 1435, 3389, 3448, 3545, 3603, 3651, 3605, 4273, 4193,
+    4158, 2999, 3638, 2894, 3241, 1569, 3409, 3515, 3573, 3627, 3632, 4218, 4138, 4079, 3009, 3686,
+    2762, 3295, 3254, 1676, 3436, 3500, 3585, 3590, 4220, 4140, 4080, 2936, 3775, 2854, 3386, 3353,
+    3275, 1706, 3534, 3619, 3624, 4200, 4119, 4060, 2870, 3726, 2819, 3438, 3405, 3333, 3364, 1693,
+    3616, 3621, 4228, 4148, 4089, 2859, 3754, 2785, 3483, 3456, 3412, 3444, 3440, 1737, 3666, 4110,
+    4029, 3970, 2778, 3747, 2698, 3443, 3462, 3418, 3450, 3446, 3487, 1707,
+    // 85o
+    2983, 3006, 2929, 2897, 2844, 2797, 6030, 5910, 5785, 6322, 3018, 5619, 4025, 3703, 3758, 3854,
+    3835, 3822, 3848, 6135, 6015, 5890, 6463, 5306, 2955, 3621, 3613, 3668, 3764, 3866, 3822, 3821,
+    5442, 5435, 5347, 4750, 3833, 3456, 1039, 2829, 2709, 2815, 2782, 2761, 2685, 4129, 4129, 4068,
+    2859, 3530, 3444, 2735, 1408, 3240, 3344, 3429, 3493, 3510, 4078, 4078, 3998, 2872, 3582, 3496,
+    2616, 3097, 1521, 3295, 3386, 3451, 3474, 4080, 4080, 3999, 2799, 3671, 3585, 2712, 3194, 3144,
+    1551, 3420, 3485, 3508, 4088, 4087, 4007, 2765, 3651, 3679, 2678, 3272, 3228, 3259, 1574, 3483,
+    3535, 4074, 4074, 3994, 2712, 3637, 3637, 2655, 3330, 3287, 3318, 3314, 1562, 3532, 3988, 3988,
+    3908, 2668, 3662, 3638, 2584, 3348, 3310, 3341, 3365, 3361, 1576,
+    // 95o
+    2854, 2775, 2748, 2729, 2639, 6003, 5882, 5766, 6350, 3015, 5621, 5714, 4013, 3646, 3741, 3727,
+    3744, 3726, 6109, 5988, 5869, 6488, 5305, 2950, 5570, 3608, 3555, 3649, 3756, 3744, 3699, 6072,
+    6094, 5974, 6629, 5392, 5256, 2890, 3279, 3464, 3559, 3666, 3775, 3699, 5373, 5361, 5354, 4750,
+    3817, 3439, 3131, 875, 2541, 2660, 2629, 2609, 2546, 3969, 3933, 3933, 2732, 3474, 3387, 3301,
+    2460, 1360, 3120, 3215, 3307, 3340, 3969, 3934, 3934, 2656, 3562, 3475, 3389, 2568, 2982, 1391,
+    3249, 3341, 3374, 3982, 3947, 3947, 2627, 3547, 3575, 3489, 2535, 3069, 3100, 1419, 3368, 3401,
+    3997, 3961, 3961, 2606, 3561, 3561, 3590, 2514, 3153, 3185, 3209, 1443, 3398, 3869, 3834, 3833,
+    2520, 3545, 3520, 3520, 2454, 3184, 3215, 3239, 3235, 1401,
+    // T5o
+    2622, 2593, 2580, 2530, 5947, 5826, 5706, 6363, 3017, 5590, 5680, 5774, 4002, 3627, 3616, 3639,
+    3657, 6049, 5928, 5807, 6468, 5273, 2950, 5536, 5630, 3595, 3536, 3642, 3635, 3626, 6013, 6034,
+    5914, 6606, 5357, 5221, 2889, 5486, 3266, 3445, 3551, 3665, 3626, 6009, 5998, 6020, 6748, 5445,
+    5309, 5173, 2833, 2978, 3355, 3460, 3574, 3656, 5303, 5291, 5266, 4750, 3801, 3423, 3114, 2844,
+    718, 2487, 2472, 2454, 2392, 3859, 3824, 3788, 2515, 3453, 3367, 3281, 3195, 2407, 1233, 3045,
+    3140, 3204, 3871, 3836, 3800, 2484, 3442, 3465, 3379, 3293, 2389, 2911, 1261, 3173, 3237, 3891,
+    3855, 3820, 2468, 3461, 3457, 3485, 3399, 2370, 2999, 3028, 1291, 3270, 3796, 3761, 3725, 2419,
+    3478, 3449, 3449, 3477, 2311, 3059, 3088, 3117, 1290,
+    // J5o
+    2572, 2559, 2509, 6005, 5884, 5764, 6436, 3056, 5664, 5723, 5808, 5875, 4091, 3645, 3668, 3686,
+    6078, 5957, 5837, 6541, 5344, 2987, 5578, 5663, 5731, 3649, 3671, 3664, 3655, 6037, 6058, 5938,
+    6679, 5399, 5263, 2924, 5520, 5587, 3298, 3579, 3694, 3655, 6034, 6023, 6041, 6823, 5479, 5342,
+    5206, 2861, 5440, 3036, 3483, 3597, 3679, 6028, 6016, 6005, 6965, 5541, 5404, 5269, 5130, 2766,
+    2790, 3321, 3435, 3518, 5323, 5312, 5287, 4750, 3885, 3473, 3144, 2898, 2667, 727, 2494, 2476,
+    2415, 3872, 3837, 3801, 2464, 3468, 3492, 3405, 3314, 3163, 2410, 1261, 3173, 3237, 3892, 3856,
+    3821, 2449, 3488, 3484, 3511, 3420, 3268, 2390, 3028, 1291, 3270, 3797, 3762, 3727, 2400, 3504,
+    3476, 3476, 3498, 3347, 2332, 3088, 3117, 1290,
+    // Q5o
+    2520, 2470, 5984, 5863, 5742, 6460, 3018, 5651, 5709, 5794, 5863, 6031, 4069, 3624, 3642, 6096,
+    5975, 5854, 6605, 5330, 3004, 5615, 5670, 5733, 5900, 3714, 3660, 3652, 6027, 6048, 5928, 6711,
+    5386, 5297, 2939, 5526, 5589, 5757, 3335, 3686, 3648, 6019, 6007, 6025, 6849, 5465, 5349, 5213,
+    2875, 5441, 5613, 3056, 3589, 3672, 6013, 6002, 5991, 6994, 5528, 5407, 5271, 5132, 2779, 5429,
+    2804, 3427, 3510, 6053, 6041, 6030, 7013, 5685, 5563, 5427, 5292, 5118, 2810, 2723, 3472, 3554,
+    5323, 5312, 5287, 4750, 3865, 3533, 3179, 2916, 2680, 2602, 696, 2458, 2397, 3860, 3824, 3789,
+    2413, 3447, 3480, 3503, 3412, 3260, 3301, 2374, 1247, 3235, 3765, 3730, 3695, 2364, 3464, 3471,
+    3468, 3490, 3338, 3379, 2316, 3085, 1246,
+    // K5o
+    2431, 5988, 5867, 5746, 6507, 2985, 5620, 5722, 5777, 5840, 6008, 6007, 4069, 3607, 6067, 5946,
+    5826, 6616, 5302, 2963, 5594, 5649, 5712, 5880, 6009, 3692, 3607, 6038, 6059, 5939, 6761, 5397,
+    5277, 2953, 5556, 5582, 5750, 5879, 3393, 3644, 6002, 5991, 6009, 6868, 5448, 5329, 5241, 2888,
+    5435, 5607, 5736, 3087, 3664, 5991, 5980, 5969, 7007, 5507, 5387, 5266, 5127, 2791, 5422, 5551,
+    2822, 3502, 6031, 6019, 6009, 7026, 5663, 5543, 5422, 5287, 5113, 2822, 5595, 2737, 3546, 6063,
+    6051, 6040, 7062, 5662, 5662, 5541, 5406, 5232, 5273, 2819, 2643, 3555, 5323, 5312, 5287, 4750,
+    3864, 3513, 3231, 2945, 2696, 2615, 2525, 669, 2380, 3733, 3697, 3662, 2328, 3431, 3430, 3463,
+    3481, 3329, 3370, 3379, 2300, 1203,
+    // A5o
+    6088, 5967, 5847, 6555, 3093, 5650, 5717, 5816, 5843, 6011, 6010, 6045, 4230, 6171, 6051, 5930,
+    6698, 5331, 3077, 5618, 5717, 5744, 5912, 6041, 6041, 3829, 6109, 6131, 6011, 6807, 5393, 5302,
+    3060, 5590, 5617, 5785, 5914, 6043, 3508, 6113, 6102, 6120, 6953, 5484, 5393, 5274, 3050, 5484,
+    5656, 5785, 5914, 3271, 6069, 6058, 6047, 7056, 5509, 5418, 5299, 5175, 2952, 5471, 5600, 5729,
+    2987, 6109, 6098, 6087, 7075, 5666, 5574, 5455, 5335, 5161, 2982, 5644, 5773, 2891, 6141, 6130,
+    6119, 7110, 5665, 5693, 5574, 5454, 5280, 5321, 2980, 5808, 2795, 6173, 6162, 6151, 7146, 5697,
+    5693, 5694, 5574, 5400, 5440, 5473, 2985, 2742, 5314, 5304, 5279, 4750, 4013, 3641, 3339, 3115,
+    2850, 2758, 2666, 2614, 810,
+    // 62s
+    4663, 4569, 4320, 1345, 3377, 3343, 3200, 3097, 3038, 2990, 2939, 2887, 3603, 3979, 3863, 3748,
+    3259, 1688, 3556, 3576, 3617, 3664, 3703, 3690, 3688, 3384, 3949, 3833, 3718, 3224, 3326, 1701,
+    3552, 3600, 3648, 3687, 3723, 3712, 3246, 3863, 3859, 3743, 3088, 3345, 3322, 1716, 3572, 3625,
+    3670, 3706, 3743, 3148, 3831, 3799, 3796, 2988, 3383, 3366, 3339, 1730, 3560, 3639, 3675, 3713,
+    3063, 3802, 3771, 3739, 2930, 3425, 3410, 3388, 3326, 1715, 3637, 3674, 3711, 3038, 3823, 3791,
+    3760, 2882, 3462, 3448, 3430, 3400, 3398, 1755, 3715, 3752, 2992, 3819, 3787, 3756, 2833, 3450,
+    3481, 3464, 3434, 3432, 3470, 1756, 3761, 2945, 3729, 3694, 3662, 2782, 3447, 3470, 3498, 3469,
+    3466, 3505, 3513, 1756,
+    // 63s
+    4669, 4366, 1517, 3563, 3354, 3360, 3257, 3198, 3151, 3100, 3049, 4549, 3614, 3985, 3870, 3435,
+    1767, 3676, 3718, 3759, 3806, 3845, 3832, 3830, 4397, 3522, 3951, 3835, 3234, 3443, 1861, 3677,
+    3725, 3773, 3817, 3853, 3842, 4336, 3365, 3976, 3861, 3237, 3483, 3441, 1880, 3703, 3756, 3801,
+    3837, 3874, 4304, 3267, 3917, 3913, 3137, 3521, 3486, 3466, 1894, 3692, 3770, 3806, 3844, 4276,
+    3182, 3889, 3857, 3079, 3563, 3529, 3514, 3452, 1880, 3768, 3805, 3843, 4296, 3157, 3909, 3878,
+    3032, 3600, 3572, 3557, 3527, 3524, 1920, 3846, 3884, 4292, 3111, 3905, 3874, 2983, 3588, 3605,
+    3590, 3561, 3558, 3597, 1920, 3893, 4223, 3064, 3811, 3779, 2932, 3586, 3594, 3625, 3595, 3593,
+    3631, 3640, 1921,
+    // 64s
+    4450, 1688, 3634, 3539, 3371, 3417, 3358, 3311, 3261, 3210, 4687, 4554, 3625, 3990, 3505, 1821,
+    3805, 3834, 3898, 3944, 3983, 3970, 3968, 4535, 4500, 3533, 3957, 3410, 3569, 1940, 3793, 3866,
+    3914, 3959, 3994, 3983, 4453, 4394, 3499, 3974, 3247, 3596, 3554, 2035, 3800, 3853, 3925, 3961,
+    3999, 4424, 4366, 3386, 4031, 3285, 3656, 3623, 3558, 2059, 3822, 3900, 3937, 3975, 4396, 4338,
+    3301, 3975, 3227, 3698, 3667, 3606, 3578, 2044, 3899, 3936, 3973, 4417, 4358, 3276, 3995, 3181,
+    3735, 3709, 3675, 3652, 3650, 2084, 3977, 4014, 4413, 4354, 3230, 3991, 3132, 3723, 3742, 3709,
+    3686, 3684, 3722, 2085, 4023, 4343, 4264, 3183, 3896, 3082, 3720, 3732, 3743, 3721, 3719, 3757,
+    3765, 2085,
+    // 65s
+    1859, 3683, 3610, 3556, 3428, 3518, 3471, 3421, 3371, 4825, 4692, 4558, 3636, 3555, 1871, 3912,
+    3959, 3989, 4082, 4121, 4108, 4106, 4673, 4638, 4505, 3544, 3480, 3672, 1994, 3921, 3957, 4052,
+    4096, 4132, 4121, 4590, 4532, 4502, 3510, 3422, 3718, 3679, 2114, 3891, 3994, 4066, 4102, 4139,
+    4514, 4455, 4401, 3490, 3295, 3744, 3712, 3647, 2180, 3827, 3996, 4033, 4071, 4516, 4458, 4403,
+    3420, 3375, 3832, 3801, 3744, 3582, 2209, 4029, 4066, 4103, 4536, 4478, 4423, 3395, 3329, 3869,
+    3843, 3813, 3744, 3775, 2249, 4107, 4144, 4532, 4474, 4420, 3349, 3281, 3857, 3876, 3846, 3778,
+    3809, 3848, 2249, 4153, 4463, 4383, 4325, 3302, 3231, 3855, 3866, 3880, 3813, 3844, 3882, 3891,
+    2250,
+    // 66
+    6404, 6513, 6622, 6724, 6870, 6885, 6917, 6950, 6860, 6783, 6706, 6629, 6052, 1836, 5338, 5378,
+    5409, 5453, 5621, 5616, 5647, 6790, 6786, 6709, 6632, 6151, 5081, 1856, 5250, 5281, 5326, 5494,
+    5609, 5605, 6734, 6711, 6712, 6635, 6250, 5120, 5001, 1882, 5152, 5198, 5366, 5482, 5598, 6674,
+    6651, 6633, 6634, 6344, 5151, 5031, 4912, 1907, 5025, 5198, 5314, 5430, 6655, 6632, 6614, 6596,
+    6477, 5192, 5073, 4954, 4794, 1903, 5186, 5303, 5419, 6690, 6667, 6649, 6631, 6491, 5348, 5229,
+    5111, 4955, 4943, 1947, 5348, 5464, 6720, 6697, 6679, 6661, 6520, 5343, 5336, 5217, 5062, 5050,
+    5091, 1951, 5499, 6655, 6603, 6580, 6562, 6550, 5371, 5331, 5324, 5169, 5157, 5198, 5231, 1954,
+    // 76o
+    3155, 3127, 3147, 3180, 3129, 3120, 3070, 5833, 5763, 5715, 5642, 4751, 1345, 3053, 3038, 3027,
+    2936, 3063, 3029, 2977, 4502, 4438, 4301, 4164, 3031, 2957, 1441, 3465, 3542, 3604, 3711, 3759,
+    3747, 4418, 4334, 4299, 4162, 2997, 2935, 3314, 1579, 3507, 3573, 3681, 3735, 3773, 4363, 4279,
+    4220, 4190, 3007, 2917, 3388, 3350, 1713, 3494, 3611, 3696, 3735, 4313, 4229, 4170, 4115, 3030,
+    2825, 3444, 3411, 3333, 1789, 3499, 3683, 3722, 4325, 4241, 4182, 4127, 2981, 2940, 3544, 3511,
+    3442, 3332, 1822, 3717, 3756, 4354, 4269, 4210, 4155, 2970, 2907, 3589, 3562, 3521, 3504, 3536,
+    1866, 3800, 4351, 4245, 4182, 4127, 2922, 2856, 3578, 3597, 3557, 3539, 3571, 3612, 1867,
+    // 86o
+    2975, 2998, 3066, 3016, 2962, 2952, 6220, 6100, 5975, 5851, 6474, 2994, 3649, 3706, 3762, 3849,
+    3955, 3911, 3944, 5779, 5772, 5723, 5649, 4751, 3487, 1178, 2867, 2870, 2783, 2911, 2891, 2856,
+    4270, 4270, 4210, 4072, 2857, 3535, 2775, 1419, 3338, 3429, 3537, 3601, 3651, 4219, 4219, 4139,
+    4104, 2869, 3587, 2771, 3193, 1558, 3380, 3498, 3563, 3618, 4197, 4197, 4117, 4058, 2925, 3668,
+    2683, 3278, 3228, 1670, 3485, 3550, 3636, 4209, 4209, 4129, 4070, 2876, 3767, 2799, 3378, 3337,
+    3320, 1703, 3584, 3670, 4196, 4196, 4115, 4056, 2823, 3725, 2777, 3436, 3396, 3379, 3410, 1691,
+    3667, 4204, 4204, 4120, 4061, 2811, 3755, 2742, 3483, 3448, 3459, 3490, 3486, 1735,
+    // 96o
+    2846, 2917, 2867, 2848, 2793, 6194, 6073, 5953, 5829, 6499, 2989, 5710, 3637, 3648, 3738, 3846,
+    3833, 3822, 6163, 6179, 6059, 5935, 6640, 5390, 2929, 3288, 3558, 3648, 3755, 3865, 3822, 5753,
+    5738, 5731, 5657, 4751, 3472, 3145, 1015, 2702, 2628, 2758, 2739, 2717, 4110, 4074, 4074, 4014,
+    2729, 3479, 3393, 2614, 1397, 3210, 3326, 3418, 3484, 4092, 4056, 4056, 3976, 2787, 3563, 3477,
+    2539, 3069, 1515, 3344, 3435, 3502, 4104, 4068, 4068, 3988, 2738, 3663, 3577, 2657, 3178, 3188,
+    1548, 3469, 3536, 4118, 4083, 4083, 4002, 2717, 3649, 3678, 2636, 3262, 3273, 3304, 1572, 3533,
+    4085, 4046, 4045, 3965, 2664, 3637, 3637, 2613, 3322, 3333, 3364, 3360, 1560,
+    // T6o
+    2764, 2711, 2698, 2684, 6134, 6013, 5892, 5777, 6479, 2989, 5675, 5769, 3625, 3625, 3731, 3725,
+    3749, 6103, 6119, 5999, 5880, 6618, 5355, 2928, 5626, 3276, 3534, 3640, 3754, 3749, 6100, 6083,
+    6105, 5986, 6759, 5443, 5307, 2872, 3015, 3444, 3550, 3664, 3779, 5698, 5682, 5671, 5664, 4751,
+    3456, 3129, 2884, 858, 2455, 2601, 2583, 2563, 3982, 3946, 3911, 3911, 2646, 3455, 3368, 3282,
+    2378, 1357, 3145, 3240, 3338, 3993, 3957, 3922, 3922, 2595, 3553, 3467, 3381, 2511, 3004, 1390,
+    3275, 3372, 4012, 3977, 3942, 3941, 2579, 3545, 3573, 3487, 2492, 3092, 3124, 1420, 3405, 4012,
+    3973, 3937, 3937, 2562, 3566, 3566, 3594, 2470, 3182, 3213, 3242, 1450,
+    // J6o
+    2552, 2539, 2524, 6045, 5924, 5804, 5684, 6446, 2944, 5609, 5700, 5795, 3613, 3608, 3606, 3630,
+    6010, 6026, 5906, 5786, 6553, 5291, 2881, 5556, 5651, 3263, 3517, 3631, 3626, 6008, 5991, 6013,
+    5893, 6691, 5376, 5240, 2824, 5508, 3001, 3426, 3540, 3656, 6005, 5988, 5977, 6000, 6833, 5464,
+    5328, 5194, 2766, 2756, 3301, 3415, 3530, 5630, 5615, 5603, 5581, 4751, 3440, 3112, 2867, 2636,
+    680, 2462, 2445, 2424, 3872, 3837, 3801, 3766, 2446, 3434, 3348, 3262, 3143, 2381, 1215, 3139,
+    3236, 3892, 3856, 3821, 3786, 2430, 3430, 3454, 3368, 3249, 2362, 2996, 1245, 3269, 3892, 3852,
+    3817, 3781, 2414, 3451, 3447, 3475, 3357, 2340, 3085, 3114, 1274,
+    // Q6o
+    2517, 2503, 6093, 5972, 5851, 5732, 6495, 2980, 5672, 5731, 5817, 5894, 3694, 3634, 3658, 6029,
+    6045, 5925, 5805, 6601, 5350, 2916, 5587, 5673, 5750, 3306, 3659, 3654, 6021, 6004, 6026, 5907,
+    6740, 5406, 5271, 2857, 5530, 5607, 3031, 3568, 3684, 6019, 6003, 5992, 6010, 6884, 5487, 5351,
+    5216, 2793, 5422, 2814, 3437, 3552, 6021, 6004, 5993, 5982, 7031, 5557, 5421, 5286, 5112, 2767,
+    2690, 3437, 3553, 5651, 5636, 5624, 5601, 4751, 3516, 3154, 2895, 2691, 2572, 693, 2467, 2447,
+    3892, 3857, 3822, 3786, 2411, 3456, 3480, 3393, 3270, 3269, 2382, 1245, 3269, 3892, 3853, 3817,
+    3782, 2394, 3477, 3473, 3501, 3377, 3376, 2361, 3114, 1274,
+    // K6o
+    2463, 6064, 5943, 5823, 5703, 6506, 2940, 5651, 5711, 5796, 5873, 6042, 3672, 3614, 6040, 6056,
+    5936, 5816, 6651, 5330, 2930, 5617, 5667, 5744, 5912, 3364, 3650, 6004, 5988, 6009, 5890, 6758,
+    5386, 5299, 2871, 5524, 5601, 5769, 3062, 3676, 5997, 5981, 5970, 5988, 6897, 5467, 5346, 5211,
+    2805, 5416, 5589, 2832, 3544, 5999, 5982, 5971, 5960, 7045, 5537, 5416, 5281, 5107, 2779, 5588,
+    2704, 3545, 6039, 6022, 6011, 6001, 7065, 5694, 5573, 5438, 5268, 5266, 2817, 2649, 3589, 5651,
+    5636, 5624, 5601, 4751, 3496, 3206, 2924, 2707, 2584, 2531, 666, 2429, 3860, 3820, 3785, 3749,
+    2357, 3436, 3469, 3492, 3368, 3367, 3408, 2345, 1230,
+    // A6o
+    6075, 5951, 5831, 5711, 6554, 2905, 5619, 5722, 5772, 5849, 6017, 6017, 3672, 6014, 6032, 5911,
+    5792, 6664, 5301, 2888, 5595, 5645, 5722, 5890, 6020, 3342, 6018, 6003, 6025, 5905, 6810, 5396,
+    5277, 2884, 5516, 5593, 5761, 5891, 3108, 5979, 5963, 5952, 5971, 6912, 5443, 5325, 5204, 2817,
+    5408, 5581, 5710, 2860, 5980, 5964, 5953, 5943, 7059, 5514, 5395, 5275, 5100, 2791, 5581, 5710,
+    2720, 6020, 6005, 5994, 5983, 7079, 5670, 5551, 5431, 5261, 5260, 2828, 5755, 2664, 6052, 6037,
+    6026, 6015, 7115, 5670, 5671, 5551, 5381, 5380, 5421, 2833, 2611, 5539, 5492, 5475, 5452, 4751,
+    3496, 3186, 2966, 2732, 2599, 2544, 2492, 644,
+    // 72s
+    4652, 4557, 4307, 3973, 1185, 3177, 3051, 2954, 2896, 2861, 2826, 2736, 3256, 3848, 3732, 3616,
+    3535, 3071, 1551, 3384, 3434, 3507, 3564, 3609, 3567, 3115, 3765, 3757, 3640, 3559, 2951, 3163,
+    1565, 3411, 3489, 3547, 3592, 3598, 3022, 3737, 3702, 3697, 3616, 2857, 3209, 3187, 1584, 3452,
+    3516, 3567, 3573, 2966, 3735, 3700, 3668, 3699, 2799, 3277, 3259, 3224, 1603, 3514, 3593, 3599,
+    2904, 3717, 3682, 3650, 3653, 2763, 3329, 3312, 3282, 3279, 1591, 3592, 3598, 2891, 3744, 3709,
+    3677, 3680, 2728, 3372, 3356, 3331, 3354, 3352, 1632, 3639, 2814, 3647, 3611, 3580, 3670, 2644,
+    3333, 3362, 3337, 3361, 3358, 3397, 1604,
+    // 73s
+    4658, 4354, 4042, 1357, 3188, 3211, 3114, 3057, 3022, 2988, 2898, 4338, 3394, 3850, 3733, 3652,
+    3081, 1710, 3514, 3564, 3637, 3694, 3739, 3697, 4277, 3234, 3874, 3758, 3677, 3099, 3287, 1730,
+    3542, 3620, 3678, 3724, 3729, 4249, 3141, 3820, 3815, 3734, 3005, 3333, 3313, 1749, 3583, 3647,
+    3698, 3704, 4247, 3085, 3818, 3786, 3817, 2948, 3401, 3385, 3350, 1767, 3645, 3725, 3731, 4229,
+    3023, 3800, 3768, 3771, 2913, 3453, 3438, 3408, 3406, 1755, 3723, 3729, 4256, 3010, 3826, 3795,
+    3798, 2878, 3496, 3482, 3457, 3481, 3478, 1796, 3770, 4141, 2933, 3728, 3697, 3791, 2794, 3457,
+    3488, 3464, 3487, 3485, 3523, 1769,
+    // 74s
+    4439, 4091, 1529, 3373, 3222, 3274, 3217, 3183, 3149, 3059, 4476, 4417, 3405, 3855, 3774, 3257,
+    1790, 3634, 3705, 3778, 3836, 3881, 3838, 4398, 4318, 3373, 3875, 3794, 3109, 3404, 1889, 3667,
+    3745, 3802, 3853, 3859, 4369, 4290, 3260, 3932, 3851, 3153, 3471, 3432, 1913, 3714, 3777, 3829,
+    3835, 4367, 4288, 3204, 3903, 3935, 3097, 3539, 3504, 3476, 1932, 3776, 3855, 3861, 4349, 4270,
+    3142, 3885, 3888, 3062, 3591, 3557, 3534, 3531, 1920, 3854, 3860, 4376, 4297, 3129, 3912, 3915,
+    3028, 3634, 3605, 3583, 3607, 3604, 1961, 3901, 4261, 4181, 3052, 3814, 3908, 2944, 3595, 3611,
+    3589, 3613, 3610, 3649, 1933,
+    // 75s
+    4163, 1700, 3444, 3407, 3285, 3376, 3343, 3309, 3220, 4614, 4554, 4421, 3415, 3894, 3327, 1844,
+    3763, 3821, 3916, 3973, 4019, 3976, 4535, 4456, 4421, 3384, 3915, 3284, 3529, 1968, 3782, 3885,
+    3943, 3994, 4000, 4485, 4406, 4347, 3393, 3964, 3163, 3583, 3544, 2068, 3810, 3873, 3953, 3959,
+    4487, 4408, 4350, 3323, 4051, 3245, 3673, 3641, 3567, 2097, 3906, 3985, 3991, 4469, 4390, 4332,
+    3261, 4005, 3210, 3725, 3694, 3626, 3656, 2084, 3984, 3990, 4496, 4417, 4358, 3248, 4032, 3176,
+    3768, 3743, 3701, 3732, 3729, 2126, 4031, 4380, 4301, 4243, 3171, 4025, 3093, 3729, 3749, 3707,
+    3738, 3735, 3774, 2098,
+    // 76s
+    1828, 3452, 3439, 3431, 3348, 3463, 3430, 3379, 4752, 4689, 4555, 4422, 3409, 3339, 1850, 3834,
+    3910, 3971, 4075, 4121, 4110, 4674, 4591, 4556, 4423, 3378, 3319, 3598, 1979, 3875, 3941, 4045,
+    4096, 4133, 4623, 4540, 4482, 4452, 3388, 3303, 3671, 3635, 2104, 3865, 3979, 4058, 4095, 4577,
+    4494, 4436, 4382, 3410, 3219, 3728, 3696, 3622, 2175, 3875, 4046, 4083, 4590, 4507, 4448, 4394,
+    3363, 3322, 3825, 3795, 3729, 3626, 2206, 4078, 4116, 4616, 4533, 4475, 4421, 3350, 3289, 3869,
+    3843, 3805, 3790, 3821, 2247, 4157, 4614, 4510, 4448, 4393, 3303, 3240, 3858, 3878, 3839, 3825,
+    3855, 3894, 2247,
+    // 77
+    6430, 6541, 6646, 6796, 6944, 6962, 6958, 6884, 6846, 6769, 6692, 6654, 6076, 1818, 5358, 5395,
+    5468, 5515, 5684, 5647, 6831, 6779, 6774, 6698, 6659, 6178, 5100, 1844, 5266, 5340, 5387, 5557,
+    5639, 6772, 6720, 6697, 6698, 6660, 6274, 5136, 5016, 1875, 5172, 5219, 5389, 5472, 6755, 6703,
+    6680, 6663, 6702, 6411, 5206, 5086, 4930, 1909, 5207, 5382, 5465, 6739, 6687, 6664, 6646, 6667,
+    6546, 5249, 5130, 4974, 4962, 1908, 5371, 5453, 6776, 6724, 6701, 6683, 6704, 6562, 5406, 5288,
+    5132, 5125, 5113, 1953, 5498, 6670, 6618, 6595, 6577, 6737, 6558, 5372, 5364, 5209, 5201, 5189,
+    5231, 1952,
+    // 87o
+    2970, 2993, 3061, 3121, 3084, 3068, 6150, 6142, 6072, 6026, 6003, 4751, 1319, 2877, 2910, 2946,
+    2882, 3022, 2983, 4412, 4412, 4348, 4211, 4079, 2857, 2791, 1428, 3415, 3526, 3597, 3712, 3752,
+    4362, 4362, 4278, 4243, 4111, 2870, 2814, 3268, 1571, 3480, 3558, 3673, 3719, 4340, 4339, 4255,
+    4196, 4172, 2925, 2840, 3372, 3325, 1710, 3545, 3663, 3740, 4300, 4300, 4216, 4157, 4107, 2973,
+    2772, 3438, 3397, 3380, 1789, 3551, 3727, 4319, 4319, 4234, 4175, 4126, 2937, 2901, 3544, 3504,
+    3490, 3380, 1822, 3761, 4296, 4296, 4212, 4153, 4156, 2920, 2862, 3582, 3548, 3561, 3544, 3576,
+    1866,
+    // 97o
+    2840, 2912, 3006, 2969, 2910, 6284, 6267, 6147, 6023, 5935, 6649, 2971, 3310, 3653, 3744, 3842,
+    3956, 3904, 6136, 6088, 6081, 6034, 6010, 4751, 3171, 1157, 2742, 2792, 2728, 2871, 2844, 4252,
+    4213, 4213, 4153, 4021, 2729, 3486, 2657, 1411, 3310, 3413, 3529, 3585, 4234, 4195, 4195, 4114,
+    4085, 2787, 3570, 2696, 3167, 1555, 3431, 3549, 3606, 4222, 4183, 4183, 4103, 4049, 2867, 3662,
+    2630, 3263, 3274, 1670, 3536, 3593, 4241, 4202, 4202, 4121, 4068, 2831, 3768, 2760, 3370, 3384,
+    3367, 1704, 3627, 4177, 4138, 4138, 4057, 4056, 2773, 3719, 2733, 3421, 3435, 3418, 3450, 1690,
+    // T7o
+    2759, 2857, 2820, 2800, 6224, 6208, 6087, 5968, 5879, 6626, 2969, 5762, 3298, 3630, 3732, 3846,
+    3832, 6221, 6177, 6194, 6074, 5986, 6768, 5439, 2914, 3025, 3540, 3641, 3756, 3861, 6112, 6065,
+    6049, 6042, 6017, 4751, 3156, 2899, 1000, 2619, 2571, 2715, 2690, 4124, 4085, 4049, 4049, 3995,
+    2646, 3462, 3376, 2535, 1397, 3236, 3354, 3442, 4117, 4077, 4042, 4042, 3967, 2729, 3556, 3471,
+    2484, 3093, 1518, 3377, 3465, 4136, 4096, 4061, 4061, 3986, 2693, 3663, 3577, 2615, 3203, 3219,
+    1552, 3499, 4104, 4065, 4030, 4029, 4007, 2672, 3648, 3675, 2590, 3284, 3300, 3332, 1580,
+    // J7o
+    2697, 2660, 2641, 6131, 6114, 5994, 5874, 5794, 6561, 2922, 5693, 5788, 3286, 3608, 3723, 3708,
+    6129, 6085, 6101, 5982, 5898, 6700, 5371, 2865, 5645, 3012, 3517, 3632, 3738, 6127, 6082, 6066,
+    6088, 6005, 6842, 5460, 5325, 2808, 2797, 3392, 3507, 3613, 6067, 6020, 6005, 5993, 6024, 4751,
+    3140, 2883, 2680, 823, 2433, 2576, 2552, 3996, 3957, 3921, 3886, 3892, 2580, 3437, 3351, 3233,
+    2354, 1342, 3241, 3329, 4015, 3976, 3940, 3905, 3910, 2544, 3544, 3457, 3339, 2486, 3091, 1376,
+    3363, 3984, 3944, 3909, 3874, 3932, 2523, 3529, 3557, 3438, 2460, 3172, 3204, 1405,
+    // Q7o
+    2500, 2481, 6032, 6015, 5895, 5775, 5691, 6503, 2875, 5615, 5707, 5811, 3274, 3599, 3589, 6025,
+    5981, 5998, 5878, 5794, 6610, 5296, 2817, 5564, 5668, 2999, 3508, 3615, 6024, 5979, 5963, 5986,
+    5901, 6749, 5382, 5247, 2758, 5488, 2783, 3382, 3489, 6029, 5984, 5968, 5958, 6016, 6897, 5478,
+    5343, 5174, 2770, 2658, 3419, 3525, 6005, 5958, 5942, 5931, 5949, 4751, 3124, 2866, 2662, 2543,
+    650, 2438, 2413, 3894, 3855, 3819, 3784, 3754, 2395, 3424, 3338, 3219, 3252, 2356, 1201, 3227,
+    3863, 3823, 3788, 3752, 3775, 2373, 3413, 3437, 3318, 3351, 2330, 3076, 1229,
+    // K7o
+    2458, 6073, 6056, 5936, 5816, 5732, 6539, 2910, 5671, 5725, 5826, 5903, 3344, 3616, 6037, 5993,
+    6010, 5890, 5806, 6646, 5349, 2850, 5582, 5682, 5760, 3036, 3642, 6030, 5986, 5970, 5992, 5908,
+    6785, 5401, 5266, 2791, 5502, 5579, 2810, 3516, 6036, 5991, 5975, 5965, 6019, 6932, 5493, 5358,
+    5188, 2796, 5579, 2717, 3547, 6037, 5993, 5977, 5966, 5991, 7080, 5564, 5429, 5259, 5258, 2777,
+    2619, 3547, 6025, 5978, 5963, 5951, 5970, 4751, 3190, 2901, 2688, 2598, 2504, 667, 2435, 3863,
+    3823, 3788, 3752, 3775, 2353, 3439, 3462, 3344, 3371, 3370, 2351, 1229,
+    // A7o
+    6074, 6058, 5937, 5818, 5702, 6556, 2907, 5658, 5712, 5813, 5890, 6059, 3339, 6078, 6034, 6051,
+    5931, 5815, 6702, 5337, 2903, 5584, 5684, 5761, 5930, 3100, 6039, 5994, 5978, 6000, 5885, 6804,
+    5388, 5268, 2842, 5503, 5581, 5750, 2855, 6044, 5999, 5983, 5973, 5995, 6952, 5481, 5361, 5191,
+    2847, 5581, 5754, 2750, 6045, 6001, 5985, 5974, 5968, 7100, 5551, 5431, 5261, 5260, 2828, 5755,
+    2650, 6087, 6042, 6026, 6016, 6009, 7120, 5709, 5589, 5419, 5422, 5421, 2873, 2637, 5899, 5851,
+    5836, 5824, 5970, 4751, 3185, 2959, 2729, 2628, 2532, 2517, 665,
+    // 82s
+    4736, 4641, 4335, 4022, 3678, 1201, 3040, 2956, 2902, 2869, 2847, 2772, 3105, 3801, 3792, 3676,
+    3590, 3475, 2941, 1579, 3368, 3449, 3531, 3595, 3610, 3009, 3772, 3737, 3732, 3646, 3531, 2859,
+    3148, 1598, 3417, 3505, 3570, 3585, 2958, 3775, 3740, 3708, 3733, 3618, 2805, 3223, 3192, 1622,
+    3531, 3596, 3617, 2925, 3783, 3748, 3717, 3715, 3712, 2771, 3300, 3274, 3298, 1643, 3595, 3643,
+    2875, 3772, 3736, 3705, 3703, 3672, 2747, 3358, 3333, 3357, 3354, 1632, 3642, 2831, 3705, 3669,
+    3638, 3728, 3671, 2677, 3374, 3350, 3378, 3402, 3399, 1645,
+    // 83s
+    4647, 4342, 4030, 3685, 1201, 3051, 2967, 2914, 2881, 2859, 2785, 4138, 3105, 3773, 3656, 3575,
+    3491, 2951, 1579, 3373, 3454, 3536, 3600, 3615, 4109, 3009, 3721, 3712, 3631, 3547, 2870, 3153,
+    1598, 3422, 3510, 3575, 3590, 4112, 2958, 3724, 3688, 3718, 3634, 2816, 3227, 3197, 1622, 3536,
+    3601, 3622, 4120, 2925, 3732, 3697, 3700, 3728, 2782, 3304, 3279, 3303, 1643, 3600, 3648, 4109,
+    2875, 3720, 3685, 3688, 3688, 2759, 3363, 3338, 3362, 3359, 1632, 3647, 4024, 2831, 3653, 3617,
+    3712, 3687, 2689, 3379, 3355, 3383, 3407, 3404, 1645,
+    // 84s
+    4428, 4080, 3755, 1372, 3062, 3127, 3074, 3041, 3020, 2946, 4259, 4259, 3244, 3773, 3692, 3607,
+    2961, 1738, 3503, 3583, 3666, 3730, 3745, 4229, 4229, 3128, 3830, 3748, 3664, 3018, 3276, 1762,
+    3552, 3641, 3705, 3721, 4233, 4232, 3077, 3806, 3836, 3752, 2965, 3351, 3322, 1786, 3667, 3732,
+    3752, 4241, 4241, 3044, 3814, 3817, 3845, 2931, 3428, 3405, 3428, 1808, 3730, 3779, 4229, 4229,
+    2994, 3802, 3805, 3805, 2909, 3486, 3464, 3487, 3485, 1797, 3777, 4144, 4144, 2950, 3735, 3829,
+    3805, 2839, 3503, 3481, 3509, 3532, 3530, 1809,
+    // 85s
+    4152, 3802, 1544, 3247, 3138, 3233, 3201, 3181, 3107, 4397, 4397, 4337, 3254, 3812, 3728, 3136,
+    1818, 3622, 3724, 3806, 3870, 3886, 4350, 4350, 4271, 3267, 3864, 3780, 3028, 3392, 1922, 3676,
+    3764, 3829, 3850, 4352, 4352, 4273, 3196, 3952, 3868, 3113, 3488, 3440, 1951, 3797, 3862, 3882,
+    4360, 4360, 4281, 3163, 3933, 3961, 3080, 3565, 3523, 3553, 1972, 3860, 3909, 4349, 4349, 4269,
+    3113, 3922, 3922, 3057, 3623, 3582, 3613, 3610, 1961, 3907, 4264, 4264, 4185, 3069, 3945, 3921,
+    2988, 3640, 3603, 3634, 3658, 3655, 1974,
+    // 86s
+    3828, 1673, 3279, 3284, 3205, 3322, 3301, 3267, 4531, 4531, 4472, 4339, 3248, 3815, 3171, 1829,
+    3715, 3804, 3909, 3973, 4019, 4485, 4485, 4406, 4371, 3260, 3867, 3167, 3483, 1958, 3756, 3870,
+    3934, 3987, 4465, 4465, 4386, 4327, 3313, 3947, 3087, 3567, 3519, 2062, 3858, 3922, 4002, 4477,
+    4477, 4398, 4340, 3265, 4044, 3192, 3665, 3626, 3612, 2093, 3955, 4035, 4465, 4465, 4386, 4328,
+    3215, 4005, 3170, 3724, 3685, 3671, 3701, 2083, 4033, 4473, 4473, 4390, 4331, 3202, 4033, 3135,
+    3769, 3735, 3747, 3778, 3775, 2124,
+    // 87s
+    1803, 3286, 3318, 3353, 3296, 3424, 3385, 4667, 4667, 4604, 4471, 4343, 3245, 3183, 1838, 3788,
+    3895, 3966, 4077, 4115, 4621, 4621, 4538, 4503, 4376, 3257, 3204, 3555, 1972, 3851, 3927, 4038,
+    4082, 4601, 4601, 4518, 4460, 4436, 3309, 3229, 3657, 3612, 2101, 3915, 4029, 4101, 4565, 4565,
+    4482, 4424, 4375, 3355, 3168, 3722, 3683, 3668, 2174, 3926, 4088, 4584, 4584, 4501, 4442, 4393,
+    3320, 3284, 3826, 3788, 3776, 3673, 2207, 4121, 4561, 4561, 4478, 4419, 4422, 3302, 3246, 3863,
+    3830, 3844, 3830, 3860, 2246,
+    // 88
+    6455, 6562, 6714, 6866, 7016, 6997, 6870, 6870, 6832, 6755, 6717, 6676, 6100, 1806, 5371, 5450,
+    5526, 5573, 5710, 6813, 6813, 6761, 6757, 6719, 6678, 6198, 5112, 1838, 5282, 5357, 5406, 5542,
+    6798, 6798, 6746, 6723, 6763, 6722, 6337, 5187, 5031, 1877, 5350, 5399, 5535, 6783, 6783, 6731,
+    6708, 6729, 6766, 6476, 5258, 5102, 5095, 1913, 5387, 5528, 6768, 6768, 6716, 6693, 6714, 6733,
+    6613, 5303, 5148, 5140, 5128, 1913, 5516, 6669, 6669, 6617, 6594, 6754, 6736, 6595, 5430, 5275,
+    5267, 5260, 5248, 1954,
+    // 98o
+    2835, 2907, 3001, 3074, 3031, 6428, 6419, 6411, 6341, 6336, 6318, 4751, 1298, 2750, 2832, 2891,
+    2841, 2976, 4355, 4354, 4354, 4290, 4159, 4030, 2729, 2671, 1420, 3386, 3510, 3588, 3695, 4337,
+    4337, 4337, 4252, 4223, 4095, 2787, 2738, 3242, 1568, 3530, 3609, 3716, 4325, 4325, 4325, 4241,
+    4187, 4166, 2868, 2787, 3357, 3371, 1709, 3596, 3706, 4292, 4292, 4292, 4208, 4154, 4108, 2928,
+    2733, 3429, 3443, 3426, 1789, 3594, 4260, 4260, 4260, 4176, 4175, 4120, 2886, 2856, 3529, 3543,
+    3529, 3419, 1822,
+    // T8o
+    2754, 2851, 2965, 2922, 6303, 6298, 6282, 6162, 6074, 5984, 6776, 2955, 3040, 3634, 3736, 3846,
+    3952, 6414, 6405, 6358, 6350, 6344, 6326, 4751, 2919, 1142, 2659, 2735, 2685, 2822, 4227, 4227,
+    4187, 4187, 4133, 4004, 2646, 3468, 2578, 1411, 3336, 3445, 3552, 4219, 4219, 4180, 4180, 4105,
+    4079, 2729, 3563, 2641, 3190, 1558, 3468, 3578, 4219, 4219, 4180, 4180, 4105, 4055, 2827, 3666,
+    2588, 3292, 3308, 1679, 3601, 4187, 4187, 4148, 4148, 4126, 4067, 2785, 3765, 2713, 3391, 3411,
+    3426, 1712,
+    // J8o
+    2692, 2805, 2762, 6211, 6206, 6189, 6070, 5986, 5895, 6708, 2907, 5781, 3028, 3613, 3722, 3829,
+    6209, 6204, 6160, 6177, 6093, 6002, 6851, 5456, 2849, 2804, 3487, 3597, 3704, 6392, 6383, 6336,
+    6321, 6352, 6333, 4751, 2903, 2692, 965, 2596, 2547, 2683, 4099, 4099, 4059, 4024, 4030, 3978,
+    2580, 3444, 3326, 2511, 1382, 3332, 3442, 4099, 4099, 4059, 4024, 4030, 3958, 2678, 3546, 3428,
+    2459, 3180, 1504, 3465, 4067, 4067, 4028, 3992, 4050, 3970, 2636, 3646, 3528, 2583, 3283, 3298,
+    1536,
+    // Q8o
+    2645, 2602, 6107, 6102, 6086, 5966, 5882, 5800, 6618, 2859, 5700, 5805, 3016, 3599, 3706, 6106,
+    6101, 6057, 6074, 5990, 5904, 6758, 5378, 2800, 5624, 2791, 3473, 3580, 6111, 6106, 6062, 6046,
+    6105, 6019, 6906, 5475, 5305, 2812, 2699, 3509, 3616, 6355, 6347, 6300, 6284, 6311, 6340, 4751,
+    2887, 2676, 2587, 792, 2408, 2545, 3978, 3978, 3938, 3903, 3873, 3882, 2529, 3427, 3308, 3340,
+    2329, 1328, 3329, 3946, 3946, 3906, 3871, 3894, 3894, 2487, 3526, 3408, 3440, 2454, 3171, 1361,
+    // K8o
+    2441, 6001, 5996, 5980, 5860, 5776, 5689, 6548, 2810, 5612, 5716, 5821, 3004, 3581, 5995, 5990,
+    5946, 5964, 5879, 5793, 6650, 5292, 2750, 5535, 5640, 2778, 3455, 6000, 5995, 5951, 5936, 5994,
+    5908, 6798, 5389, 5219, 2762, 5645, 2685, 3492, 6006, 6001, 5957, 5942, 5967, 6023, 6946, 5486,
+    5316, 5319, 2780, 2588, 3528, 6301, 6293, 6245, 6230, 6257, 6276, 4751, 2872, 2659, 2569, 2475,
+    623, 2406, 3824, 3824, 3785, 3749, 3772, 3736, 2337, 3406, 3288, 3320, 3352, 2324, 1186,
+    // A8o
+    6072, 6067, 6051, 5931, 5815, 5738, 6589, 2882, 5638, 5742, 5843, 5921, 3077, 6032, 6027, 5983,
+    6001, 5885, 5807, 6692, 5319, 2822, 5561, 5662, 5740, 2829, 6037, 6032, 5988, 5973, 5999, 5922,
+    6839, 5415, 5245, 2833, 5666, 5745, 2728, 6043, 6038, 5994, 5979, 5972, 6033, 6987, 5508, 5339,
+    5341, 2845, 5745, 2663, 6046, 6040, 5997, 5981, 5975, 6007, 7136, 5580, 5410, 5413, 5412, 2833,
+    2607, 6195, 6187, 6139, 6124, 6278, 6280, 4751, 2940, 2707, 2609, 2546, 2490, 665,
+    // 92s
+    4717, 4705, 4399, 4046, 3691, 3419, 1180, 2911, 2870, 2838, 2818, 2756, 2984, 3744, 3744, 3736,
+    3649, 3533, 3457, 2818, 1571, 3340, 3432, 3521, 3555, 2930, 3746, 3746, 3711, 3736, 3620, 3543,
+    2776, 3121, 1595, 3463, 3553, 3586, 2902, 3759, 3759, 3724, 3722, 3718, 3641, 2743, 3207, 3235,
+    1621, 3579, 3613, 2882, 3774, 3774, 3738, 3736, 3705, 3741, 2721, 3290, 3318, 3342, 1644, 3611,
+    2800, 3668, 3668, 3633, 3723, 3667, 3674, 2662, 3321, 3350, 3373, 3371, 1604,
+    // 93s
+    4731, 4411, 4061, 3735, 3427, 1220, 2959, 2918, 2887, 2867, 2805, 4120, 2998, 3756, 3748, 3666,
+    3577, 3461, 2862, 1611, 3377, 3469, 3558, 3592, 4122, 2945, 3758, 3723, 3753, 3663, 3548, 2821,
+    3156, 1635, 3501, 3590, 3624, 4135, 2917, 3771, 3736, 3739, 3761, 3646, 2788, 3242, 3270, 1662,
+    3616, 3650, 4149, 2896, 3786, 3750, 3753, 3748, 3745, 2767, 3325, 3353, 3377, 1684, 3649, 4027,
+    2815, 3680, 3645, 3739, 3710, 3679, 2708, 3357, 3385, 3409, 3406, 1645,
+    // 94s
+    4418, 4068, 3742, 3434, 1220, 2970, 2929, 2898, 2879, 2818, 4125, 4090, 2998, 3727, 3646, 3561,
+    3477, 2873, 1612, 3382, 3474, 3563, 3597, 4127, 4092, 2945, 3706, 3732, 3648, 3564, 2832, 3160,
+    1636, 3505, 3594, 3628, 4140, 4105, 2917, 3719, 3718, 3745, 3661, 2799, 3246, 3274, 1662, 3621,
+    3654, 4154, 4120, 2896, 3734, 3733, 3733, 3761, 2778, 3329, 3358, 3381, 1684, 3653, 4031, 3996,
+    2815, 3628, 3718, 3694, 3694, 2720, 3361, 3389, 3413, 3410, 1645,
+    // 95s
+    4141, 3791, 3504, 1392, 2981, 3089, 3058, 3039, 2979, 4245, 4211, 4210, 3137, 3762, 3677, 3593,
+    2883, 1771, 3511, 3602, 3692, 3726, 4246, 4212, 4212, 3064, 3849, 3764, 3680, 2980, 3283, 1800,
+    3635, 3724, 3758, 4259, 4225, 4225, 3036, 3835, 3862, 3778, 2948, 3369, 3400, 1826, 3751, 3785,
+    4274, 4239, 4239, 3015, 3849, 3849, 3877, 2927, 3452, 3483, 3506, 1849, 3783, 4151, 4116, 4116,
+    2934, 3835, 3811, 3811, 2869, 3484, 3514, 3538, 3535, 1810,
+    // 96s
+    3818, 3514, 1521, 3127, 3061, 3179, 3160, 3138, 4380, 4345, 4345, 4286, 3131, 3764, 3680, 3022,
+    1807, 3594, 3708, 3797, 3862, 4364, 4329, 4329, 4250, 3185, 3847, 3763, 2954, 3366, 1916, 3723,
+    3812, 3878, 4376, 4342, 4342, 4262, 3138, 3945, 3861, 3060, 3472, 3484, 1948, 3845, 3911, 4391,
+    4356, 4356, 4277, 3117, 3932, 3960, 3039, 3556, 3567, 3598, 1970, 3909, 4360, 4321, 4321, 4242,
+    3066, 3922, 3922, 3016, 3616, 3627, 3658, 3655, 1959,
+    // 97s
+    3535, 1652, 3161, 3209, 3153, 3283, 3257, 4516, 4478, 4478, 4418, 4291, 3127, 3768, 3059, 1821,
+    3689, 3790, 3901, 3958, 4500, 4462, 4462, 4382, 4354, 3182, 3852, 3095, 3459, 1955, 3805, 3920,
+    3976, 4490, 4452, 4452, 4373, 4320, 3257, 3942, 3036, 3553, 3565, 2062, 3908, 3964, 4509, 4470,
+    4470, 4391, 4339, 3222, 4045, 3154, 3658, 3673, 3658, 2094, 3997, 4447, 4409, 4409, 4330, 4328,
+    3167, 3999, 3127, 3710, 3724, 3710, 3740, 2082,
+    // 98s
+    1783, 3166, 3243, 3301, 3257, 3380, 4613, 4613, 4613, 4550, 4422, 4297, 3123, 3068, 1830, 3761,
+    3881, 3958, 4061, 4597, 4597, 4597, 4514, 4485, 4360, 3178, 3132, 3530, 1969, 3899, 3976, 4080,
+    4587, 4587, 4587, 4504, 4452, 4430, 3254, 3178, 3642, 3657, 2101, 3964, 4071, 4558, 4558, 4558,
+    4475, 4422, 4376, 3312, 3130, 3714, 3729, 3714, 2175, 3967, 4527, 4527, 4527, 4444, 4442, 4388,
+    3271, 3241, 3812, 3826, 3815, 3712, 2206,
+    // 99
+    6472, 6625, 6779, 6933, 7045, 6885, 6849, 6849, 6810, 6772, 6731, 6691, 6115, 1800, 5382, 5463,
+    5539, 5553, 6871, 6835, 6835, 6783, 6817, 6776, 6736, 6256, 5123, 1839, 5455, 5532, 5546, 6857,
+    6821, 6821, 6769, 6785, 6822, 6781, 6397, 5199, 5191, 1881, 5525, 5539, 6844, 6808, 6807, 6755,
+    6771, 6790, 6827, 6537, 5271, 5263, 5256, 1918, 5527, 6692, 6656, 6656, 6604, 6758, 6740, 6759,
+    6640, 5285, 5277, 5270, 5258, 1914,
+    // T9o
+    2748, 2846, 2960, 3067, 6681, 6636, 6628, 6620, 6588, 6582, 6571, 4751, 1283, 2664, 2774, 2848,
+    2792, 4337, 4328, 4328, 4328, 4270, 4141, 4013, 2646, 2588, 1420, 3412, 3540, 3641, 4330, 4321,
+    4321, 4321, 4243, 4216, 4087, 2729, 2683, 3265, 1571, 3566, 3668, 4330, 4321, 4321, 4321, 4243,
+    4192, 4170, 2827, 2745, 3385, 3404, 1719, 3691, 4279, 4271, 4271, 4271, 4244, 4185, 4139, 2919,
+    2686, 3480, 3499, 3515, 1839,
+    // J9o
+    2686, 2800, 2907, 6322, 6285, 6281, 6265, 6181, 6090, 6001, 6859, 2891, 2816, 3581, 3692, 3794,
+    6667, 6622, 6614, 6566, 6597, 6590, 6578, 4751, 2709, 1107, 2636, 2710, 2653, 4209, 4201, 4201,
+    4161, 4167, 4115, 3987, 2580, 3417, 2554, 1396, 3430, 3532, 4209, 4201, 4201, 4161, 4167, 4095,
+    4069, 2678, 3520, 2615, 3276, 1544, 3555, 4159, 4150, 4150, 4111, 4169, 4088, 4038, 2771, 3616,
+    2556, 3371, 3387, 1664,
+    // Q9o
+    2640, 2747, 6219, 6182, 6178, 6162, 6078, 5992, 5902, 6766, 2841, 5761, 2804, 3567, 3669, 6225,
+    6188, 6184, 6140, 6193, 6107, 6017, 6914, 5436, 2853, 2704, 3604, 3706, 6647, 6602, 6594, 6546,
+    6570, 6598, 6585, 4751, 2693, 2598, 935, 2571, 2515, 4088, 4080, 4080, 4040, 4011, 4019, 3967,
+    2529, 3400, 3433, 2486, 1368, 3419, 4037, 4029, 4029, 3990, 4012, 4012, 3940, 2621, 3496, 3528,
+    2427, 3259, 1488,
+    // K9o
+    2586, 6109, 6072, 6067, 6052, 5967, 5881, 5796, 6659, 2792, 5672, 5777, 2792, 3545, 6115, 6077,
+    6073, 6030, 6083, 5996, 5911, 6806, 5350, 2803, 5781, 2692, 3582, 6121, 6083, 6079, 6036, 6055,
+    6112, 6026, 6954, 5447, 5450, 2821, 2628, 3618, 6617, 6572, 6563, 6516, 6539, 6563, 6592, 4751,
+    2677, 2581, 2519, 766, 2376, 3916, 3907, 3907, 3868, 3891, 3855, 3863, 2471, 3376, 3408, 3440,
+    2297, 1313,
+    // A9o
+    6029, 5992, 5987, 5972, 5856, 5779, 5693, 6557, 2781, 5591, 5696, 5802, 2797, 6034, 5997, 5992,
+    5949, 5970, 5893, 5808, 6705, 5272, 2792, 5700, 5806, 2696, 6040, 6003, 5998, 5955, 5943, 6008,
+    5923, 6853, 5369, 5372, 2810, 5811, 2632, 6047, 6009, 6005, 5962, 5950, 5982, 6039, 7001, 5467,
+    5470, 5473, 2836, 2576, 6446, 6401, 6393, 6345, 6495, 6504, 6525, 4751, 2678, 2580, 2517, 2461,
+    622,
+    // T2s
+    4717, 4686, 4463, 4097, 3712, 3431, 3182, 1166, 2821, 2804, 2785, 2725, 2905, 3726, 3717, 3717,
+    3739, 3623, 3546, 3438, 2732, 1571, 3363, 3456, 3519, 2874, 3738, 3729, 3729, 3724, 3720, 3642,
+    3535, 2713, 3143, 1597, 3488, 3550, 2858, 3757, 3749, 3749, 3743, 3712, 3746, 3639, 2692, 3229,
+    3257, 1624, 3582, 2811, 3683, 3675, 3675, 3761, 3704, 3712, 3715, 2634, 3289, 3317, 3345, 1624,
+    // T3s
+    4713, 4475, 4112, 3757, 3439, 3223, 1206, 2869, 2853, 2834, 2774, 4101, 2919, 3729, 3729, 3756,
+    3666, 3550, 3474, 2776, 1611, 3401, 3493, 3556, 4114, 2889, 3742, 3741, 3741, 3763, 3647, 3570,
+    2758, 3178, 1638, 3525, 3587, 4133, 2873, 3761, 3761, 3760, 3755, 3751, 3674, 2737, 3264, 3293,
+    1665, 3619, 4041, 2825, 3686, 3686, 3776, 3747, 3716, 3751, 2680, 3324, 3352, 3381, 1664,
+    // T4s
+    4499, 4124, 3772, 3484, 3231, 1246, 2917, 2901, 2882, 2823, 4137, 4102, 2934, 3741, 3767, 3682,
+    3593, 3478, 2820, 1652, 3437, 3530, 3593, 4149, 4114, 2903, 3753, 3752, 3779, 3690, 3574, 2802,
+    3213, 1678, 3562, 3624, 4168, 4134, 2887, 3772, 3771, 3771, 3793, 3678, 2782, 3299, 3327, 1706,
+    3656, 4076, 4041, 2840, 3698, 3788, 3764, 3759, 3755, 2725, 3359, 3387, 3415, 1705,
+    // T5s
+    4131, 3779, 3491, 3239, 1246, 2927, 2912, 2894, 2835, 4141, 4106, 4072, 2934, 3746, 3661, 3577,
+    3493, 2830, 1652, 3441, 3534, 3596, 4153, 4118, 4084, 2903, 3735, 3758, 3673, 3589, 2813, 3216,
+    1678, 3565, 3628, 4172, 4138, 4103, 2887, 3754, 3750, 3777, 3693, 2793, 3303, 3331, 1706, 3659,
+    4080, 4046, 4011, 2840, 3771, 3743, 3743, 3770, 2737, 3362, 3390, 3419, 1705,
+    // T6s
+    3807, 3502, 3273, 1375, 2899, 3032, 3015, 2995, 4258, 4224, 4189, 4189, 3055, 3744, 3659, 3575,
+    2804, 1768, 3535, 3627, 3721, 4270, 4235, 4201, 4201, 3005, 3841, 3756, 3672, 2925, 3305, 1800,
+    3660, 3754, 4289, 4255, 4220, 4220, 2989, 3833, 3860, 3776, 2906, 3392, 3423, 1827, 3786, 4289,
+    4251, 4216, 4216, 2972, 3854, 3854, 3881, 2884, 3480, 3511, 3539, 1855,
+    // T7s
+    3524, 3284, 1507, 3047, 3006, 3137, 3113, 4395, 4356, 4322, 4322, 4268, 3051, 3748, 3664, 2946,
+    1807, 3620, 3734, 3820, 4389, 4350, 4316, 4316, 4242, 3129, 3841, 3757, 2901, 3390, 1919, 3755,
+    3840, 4407, 4369, 4334, 4334, 4261, 3094, 3945, 3861, 3020, 3497, 3514, 1951, 3873, 4377, 4339,
+    4304, 4304, 4282, 3072, 3931, 3958, 2994, 3577, 3594, 3624, 1977,
+    // T8s
+    3299, 1638, 3082, 3154, 3111, 3236, 4492, 4492, 4453, 4453, 4400, 4275, 3048, 3752, 2982, 1821,
+    3715, 3820, 3923, 4486, 4486, 4447, 4447, 4374, 4348, 3125, 3845, 3043, 3482, 1958, 3840, 3947,
+    4487, 4487, 4449, 4449, 4375, 4325, 3218, 3945, 2996, 3581, 3598, 2070, 3968, 4456, 4456, 4418,
+    4418, 4395, 4337, 3177, 4042, 3109, 3679, 3698, 3715, 2101,
+    // T9s
+    1769, 3084, 3188, 3259, 3210, 4596, 4588, 4588, 4588, 4530, 4405, 4280, 3044, 2989, 1830, 3786,
+    3910, 4008, 4591, 4582, 4582, 4582, 4505, 4479, 4354, 3122, 3079, 3553, 1971, 3934, 4032, 4592,
+    4584, 4584, 4584, 4506, 4456, 4434, 3214, 3137, 3670, 3689, 2109, 4052, 4544, 4535, 4535, 4535,
+    4509, 4451, 4405, 3301, 3084, 3762, 3782, 3798, 2220,
+    // TT
+    6488, 6643, 6798, 6913, 6945, 6909, 6872, 6872, 6873, 6832, 6791, 6751, 6131, 1801, 5576, 5656,
+    5702, 6932, 6896, 6859, 6859, 6846, 6878, 6837, 6797, 6272, 5301, 1843, 5649, 5695, 6919, 6883,
+    6847, 6847, 6834, 6847, 6884, 6843, 6414, 5377, 5369, 1886, 5688, 6769, 6732, 6696, 6696, 6821,
+    6798, 6817, 6854, 6520, 5422, 5414, 5406, 1925,
+    // JTo
+    2683, 2797, 2904, 6895, 6850, 6805, 6797, 6827, 6793, 6789, 6781, 4751, 1297, 2644, 2759, 2865,
+    4355, 4347, 4338, 4338, 4344, 4288, 4160, 4031, 2583, 2569, 1423, 3538, 3662, 4356, 4347, 4339,
+    4339, 4345, 4269, 4242, 4114, 2681, 2668, 3380, 1575, 3688, 4305, 4297, 4288, 4288, 4346, 4262,
+    4211, 4189, 2774, 2760, 3497, 3516, 1726,
+    // QTo
+    2636, 2743, 6373, 6335, 6299, 6295, 6315, 6229, 6139, 6050, 6922, 2917, 2722, 3732, 3835, 6881,
+    6836, 6791, 6782, 6773, 6802, 6796, 6788, 4751, 2621, 1126, 2620, 2727, 4235, 4226, 4218, 4218,
+    4184, 4192, 4141, 4012, 2531, 3557, 2538, 1400, 3552, 4184, 4176, 4167, 4167, 4186, 4186, 4114,
+    4087, 2624, 3653, 2630, 3388, 1551,
+    // KTo
+    2583, 6262, 6225, 6188, 6184, 6204, 6118, 6033, 5943, 6814, 2867, 5956, 2710, 3711, 6269, 6231,
+    6195, 6191, 6183, 6234, 6149, 6059, 6963, 5618, 2885, 2638, 3747, 6863, 6818, 6773, 6764, 6756,
+    6776, 6805, 6795, 4751, 2606, 2535, 957, 2588, 4062, 4054, 4045, 4045, 4064, 4029, 4037, 3985,
+    2474, 3533, 3566, 2501, 1376,
+    // ATo
+    6182, 6144, 6108, 6104, 6092, 6015, 5929, 5845, 6712, 2856, 5875, 5981, 2715, 6188, 6151, 6114,
+    6110, 6071, 6131, 6045, 5961, 6861, 5540, 2874, 5986, 2642, 6195, 6157, 6121, 6117, 6077, 6104,
+    6161, 6076, 7010, 5638, 5641, 2900, 2626, 6710, 6664, 6619, 6611, 6729, 6734, 6758, 6786, 4751,
+    2606, 2534, 2515, 814,
+    // J2s
+    4717, 4686, 4444, 4158, 3753, 3451, 3194, 2981, 1134, 2788, 2769, 2709, 2842, 3708, 3700, 3692,
+    3721, 3714, 3636, 3528, 3389, 2698, 1557, 3455, 3518, 2826, 3728, 3720, 3711, 3741, 3706, 3740,
+    3632, 3493, 2677, 3227, 1584, 3549, 2779, 3654, 3645, 3637, 3758, 3698, 3706, 3709, 3570, 2620,
+    3286, 3315, 1583,
+    // J3s
+    4713, 4456, 4173, 3797, 3459, 3236, 3023, 1174, 2837, 2818, 2758, 4084, 2857, 3712, 3704, 3738,
+    3757, 3641, 3564, 3425, 2743, 1597, 3493, 3555, 4104, 2841, 3732, 3723, 3758, 3749, 3745, 3668,
+    3529, 2723, 3262, 1625, 3587, 4012, 2793, 3657, 3649, 3774, 3741, 3710, 3745, 3606, 2666, 3322,
+    3350, 1624,
+    // J4s
+    4480, 4185, 3813, 3503, 3244, 3065, 1214, 2885, 2866, 2807, 4120, 4085, 2871, 3715, 3750, 3773,
+    3683, 3568, 3459, 2788, 1638, 3529, 3592, 4139, 4104, 2855, 3735, 3769, 3765, 3787, 3672, 3563,
+    2768, 3297, 1665, 3623, 4047, 4012, 2808, 3660, 3786, 3758, 3753, 3749, 3640, 2711, 3356, 3385,
+    1664,
+    // J5s
+    4207, 3825, 3519, 3289, 3073, 1254, 2932, 2914, 2855, 4154, 4120, 4085, 2886, 3761, 3784, 3699,
+    3610, 3463, 2832, 1678, 3565, 3628, 4174, 4139, 4105, 2870, 3780, 3776, 3803, 3714, 3567, 2812,
+    3331, 1706, 3660, 4081, 4047, 4012, 2822, 3796, 3769, 3769, 3791, 3644, 2756, 3391, 3419, 1705,
+    // J6s
+    3796, 3490, 3261, 3045, 1211, 2904, 2886, 2867, 4155, 4121, 4086, 4052, 2869, 3729, 3645, 3560,
+    3444, 2806, 1635, 3534, 3628, 4175, 4140, 4106, 4071, 2853, 3725, 3749, 3664, 3548, 2787, 3301,
+    1663, 3659, 4175, 4136, 4102, 4067, 2836, 3746, 3742, 3769, 3654, 2765, 3389, 3417, 1690,
+    // J7s
+    3513, 3272, 3082, 1343, 2878, 3009, 2985, 4274, 4236, 4201, 4167, 4173, 2993, 3729, 3644, 3529,
+    2782, 1754, 3629, 3714, 4293, 4255, 4220, 4186, 4191, 2957, 3833, 3749, 3633, 2901, 3392, 1787,
+    3747, 4263, 4224, 4190, 4155, 4212, 2936, 3819, 3846, 3730, 2875, 3472, 3503, 1813,
+    // J8s
+    3288, 3091, 1475, 3026, 2983, 3108, 4372, 4372, 4333, 4299, 4304, 4253, 2989, 3733, 3617, 2924,
+    1793, 3714, 3821, 4373, 4373, 4335, 4300, 4306, 4235, 3081, 3833, 3717, 2877, 3476, 1906, 3842,
+    4342, 4342, 4303, 4269, 4326, 4247, 3040, 3930, 3815, 2990, 3577, 3593, 1937,
+    // J9s
+    3103, 1606, 3060, 3131, 3081, 4476, 4468, 4468, 4430, 4435, 4384, 4259, 2985, 3704, 2960, 1807,
+    3808, 3905, 4478, 4469, 4469, 4431, 4437, 4366, 4339, 3077, 3805, 3018, 3568, 1945, 3926, 4429,
+    4421, 4421, 4383, 4440, 4360, 4310, 3164, 3898, 2965, 3660, 3676, 2056,
+    // JTs
+    1782, 3064, 3172, 3273, 4615, 4607, 4598, 4598, 4604, 4549, 4424, 4299, 2983, 2970, 1833, 3908,
+    4027, 4617, 4608, 4600, 4600, 4606, 4531, 4504, 4379, 3076, 3062, 3666, 1975, 4051, 4568, 4560,
+    4552, 4552, 4609, 4526, 4475, 4453, 3163, 3149, 3780, 3799, 2116,
+    // JJ
+    6673, 6829, 6945, 6940, 6904, 6868, 6831, 6870, 6868, 6827, 6787, 6725, 6301, 1803, 5646, 5692,
+    6928, 6892, 6855, 6819, 6858, 6842, 6874, 6833, 6772, 6443, 5367, 1846, 5685, 6778, 6741, 6705,
+    6669, 6846, 6794, 6807, 6844, 6783, 6549, 5412, 5404, 1885,
+    // QJo
+    2619, 2726, 6996, 6950, 6905, 6860, 6890, 6918, 6883, 6881, 6869, 4751, 1082, 2586, 2694, 4203,
+    4194, 4186, 4177, 4183, 4191, 4136, 4007, 3843, 2515, 2508, 1356, 3517, 4152, 4144, 4135, 4127,
+    4185, 4185, 4109, 4082, 3918, 2608, 2600, 3356, 1507,
+    // KJo
+    2565, 6260, 6222, 6186, 6150, 6181, 6199, 6114, 6025, 5904, 6979, 2846, 2604, 3712, 6982, 6936,
+    6891, 6846, 6876, 6864, 6892, 6889, 6876, 4751, 2504, 913, 2555, 4030, 4022, 4013, 4005, 4063,
+    4024, 4032, 3980, 3816, 2458, 3533, 2471, 1332,
+    // AJo
+    6179, 6142, 6105, 6069, 6069, 6096, 6010, 5926, 5805, 6877, 2835, 5976, 2609, 6186, 6148, 6112,
+    6076, 6076, 6075, 6126, 6042, 5921, 7025, 5632, 2861, 2593, 6839, 6793, 6748, 6703, 6860, 6833,
+    6853, 6881, 6868, 4751, 2504, 2485, 770,
+    // Q2s
+    4717, 4686, 4444, 4139, 3809, 3484, 3212, 2994, 2885, 1105, 2753, 2693, 2794, 3698, 3690, 3681,
+    3703, 3703, 3734, 3626, 3486, 3494, 2663, 1544, 3517, 2746, 3624, 3615, 3607, 3720, 3695, 3699,
+    3703, 3563, 3571, 2605, 3284, 1543,
+    // Q3s
+    4713, 4456, 4155, 3854, 3492, 3254, 3036, 2928, 1145, 2802, 2743, 4074, 2808, 3702, 3693, 3720,
+    3746, 3738, 3661, 3521, 3530, 2708, 1584, 3554, 3982, 2761, 3627, 3619, 3736, 3739, 3704, 3738,
+    3598, 3607, 2651, 3320, 1583,
+    // Q4s
+    4480, 4167, 3869, 3536, 3262, 3078, 2970, 1185, 2851, 2791, 4109, 4074, 2823, 3705, 3731, 3762,
+    3781, 3665, 3556, 3564, 2753, 1625, 3591, 4017, 3982, 2775, 3630, 3748, 3755, 3746, 3742, 3633,
+    3641, 2696, 3354, 1624,
+    // Q5s
+    4189, 3881, 3552, 3307, 3086, 3012, 1225, 2898, 2840, 4144, 4109, 4075, 2837, 3742, 3773, 3797,
+    3707, 3560, 3598, 2797, 1665, 3627, 4051, 4017, 3982, 2790, 3758, 3766, 3762, 3784, 3637, 3675,
+    2741, 3388, 1665,
+    // Q6s
+    3866, 3528, 3286, 3095, 2984, 1222, 2907, 2887, 4175, 4141, 4106, 4072, 2835, 3751, 3774, 3689,
+    3568, 3569, 2805, 1663, 3659, 4175, 4137, 4103, 4068, 2818, 3771, 3768, 3795, 3674, 3674, 2784,
+    3417, 1690,
+    // Q7s
+    3502, 3260, 3069, 2959, 1183, 2881, 2857, 4178, 4140, 4105, 4071, 4042, 2820, 3721, 3636, 3520,
+    3552, 2782, 1622, 3621, 4148, 4109, 4075, 4040, 4063, 2799, 3710, 3734, 3617, 3649, 2756, 3381,
+    1649,
+    // Q8s
+    3277, 3079, 2996, 1314, 2855, 2979, 4258, 4258, 4220, 4185, 4156, 4164, 2944, 3720, 3604, 3635,
+    2758, 1741, 3716, 4227, 4227, 4189, 4154, 4176, 4176, 2903, 3818, 3702, 3733, 2871, 3472, 1773,
+    // Q9s
+    3092, 3003, 1446, 3003, 2953, 4363, 4355, 4355, 4316, 4287, 4296, 4244, 2940, 3692, 3723, 2899,
+    1780, 3800, 4315, 4306, 4306, 4268, 4290, 4290, 4219, 3027, 3785, 3816, 2846, 3555, 1892,
+    // QTs
+    3020, 1623, 3044, 3145, 4502, 4493, 4485, 4485, 4452, 4461, 4409, 4284, 2938, 3841, 2943, 1811,
+    3925, 4453, 4445, 4437, 4437, 4455, 4455, 4384, 4358, 3025, 3935, 3030, 3678, 1952,
+    // QJs
+    1583, 3013, 3115, 4472, 4463, 4455, 4447, 4453, 4461, 4406, 4281, 4121, 2924, 2916, 1770, 3893,
+    4423, 4415, 4407, 4399, 4456, 4456, 4381, 4354, 4195, 3010, 3003, 3647, 1911,
+    // QQ
+    6854, 6970, 6930, 6894, 6858, 6821, 6824, 6860, 6858, 6817, 6755, 6792, 6466, 1807, 5676, 6780,
+    6744, 6707, 6671, 6812, 6812, 6796, 6828, 6766, 6803, 6573, 5395, 1845,
+    // KQo
+    2548, 7072, 7026, 6981, 6935, 6929, 6956, 6984, 6949, 6945, 6975, 4751, 870, 2523, 3998, 3989,
+    3981, 3972, 4022, 4022, 4030, 3975, 3811, 3819, 2442, 2441, 1288,
+    // AQo
+    6177, 6140, 6103, 6067, 6035, 6073, 6092, 6007, 5886, 5918, 7041, 2821, 2561, 6931, 6885, 6839,
+    6794, 6914, 6926, 6914, 6942, 6937, 6966, 4751, 2455, 726,
+    // K2s
+    4717, 4686, 4444, 4139, 3791, 3533, 3239, 3010, 2898, 2811, 1080, 2677, 2713, 3593, 3585, 3577,
+    3690, 3657, 3695, 3695, 3556, 3564, 3572, 2591, 1502,
+    // K3s
+    4713, 4456, 4155, 3835, 3541, 3281, 3052, 2940, 2854, 1120, 2727, 3952, 2728, 3596, 3588, 3706,
+    3700, 3700, 3731, 3591, 3599, 3608, 2637, 1543,
+    // K4s
+    4480, 4167, 3851, 3586, 3289, 3093, 2982, 2897, 1160, 2776, 3986, 3952, 2742, 3600, 3717, 3716,
+    3743, 3735, 3626, 3634, 3642, 2682, 1584,
+    // K5s
+    4189, 3863, 3601, 3334, 3102, 3024, 2939, 1200, 2824, 4021, 3986, 3952, 2757, 3728, 3727, 3758,
+    3777, 3630, 3668, 3676, 2727, 1624,
+    // K6s
+    3847, 3578, 3314, 3111, 2997, 2945, 1198, 2871, 4145, 4107, 4072, 4038, 2784, 3733, 3764, 3787,
+    3666, 3667, 3705, 2769, 1650,
+    // K7s
+    3562, 3293, 3093, 3009, 2920, 1198, 2877, 4148, 4109, 4075, 4040, 4063, 2780, 3735, 3758, 3642,
+    3669, 3669, 2775, 1649,
+    // K8s
+    3267, 3067, 2983, 2895, 1158, 2851, 4111, 4111, 4073, 4039, 4061, 4026, 2766, 3705, 3589, 3620,
+    3652, 2752, 1608,
+    // K9s
+    3081, 2991, 2932, 1290, 2825, 4199, 4191, 4191, 4152, 4175, 4140, 4148, 2890, 3672, 3703, 3735,
+    2727, 1727,
+    // KTs
+    3009, 2942, 1467, 3017, 4338, 4330, 4321, 4321, 4340, 4305, 4313, 4262, 2887, 3822, 3854, 2911,
+    1787,
+    // KJs
+    2915, 1427, 2987, 4308, 4300, 4291, 4283, 4340, 4302, 4310, 4259, 4099, 2873, 3823, 2884, 1747,
+    // KQs
+    1387, 2958, 4277, 4269, 4261, 4253, 4301, 4301, 4309, 4254, 4095, 4103, 2858, 2857, 1706,
+    // KK
+    6988, 6775, 6739, 6703, 6666, 6807, 6771, 6807, 6805, 6743, 6779, 6815, 6589, 1805,
+    // AKo
+    6984, 6938, 6892, 6846, 6966, 6941, 6968, 6996, 6953, 6983, 7012, 4751, 683,
+    // A2s
+    4728, 4698, 4457, 4243, 3909, 3632, 3397, 3152, 3030, 2942, 2890, 1211,
+    // A3s
+    4724, 4468, 4287, 3953, 3640, 3439, 3194, 3072, 2984, 2933, 1251,
+    // A4s
+    4491, 4304, 3968, 3685, 3447, 3235, 3114, 3027, 2976, 1291,
+    // A5s
+    4326, 3981, 3700, 3492, 3244, 3156, 3069, 3018, 1331,
+    // A6s
+    3847, 3559, 3354, 3135, 3011, 2958, 2907, 1177,
+    // A7s
+    3558, 3348, 3132, 3037, 2947, 2930, 1197,
+    // A8s
+    3330, 3111, 3020, 2959, 2906, 1197,
+    // A9s
+    3085, 2994, 2934, 2881, 1157,
+    // ATs
+    3013, 2945, 2926, 1334,
+    // AJs
+    2919, 2899, 1294,
+    // AQs
+    2872, 1254,
+    // AKs
+    1214,
+];
@@ -0,0 +1,637 @@
+use std::fmt;
+use rand::{thread_rng, Rng};
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::prelude::*;
+use crate::card::{Card, cards_to_u32};
+use crate::canonical::PreflopClass;
+use crate::hand_history::Street;
+use crate::preflop::{class_equity_vs_random, class_std_error_vs_random, MAX_OPPONENTS};
+use crate::scheduler::{Scheduler, Topic};
+use crate::scoring::{Bands, ScoreTotals, Scoring, ScoringRule, Truth};
+use crate::state::{self, StateError};
+use crate::stats::{Answer, Session};
+use crate::Deck;
+
+/// How close a guess came, as the trainer flashes it.
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Grade {
+    Green,
+    Yellow,
+    Red,
+}
+
+impl Grade {
+    /// Grades the distance between a guess and the truth, in percentage
+    /// points, with the fixed bands: green within 10 points, yellow within
+    /// 20. Either sign is fine.
+    pub fn from_error(error: f64) -> Grade {
+        let error = error.abs();
+        if error <= 10.0 {
+            Grade::Green
+        } else if error <= 20.0 {
+            Grade::Yellow
+        } else {
+            Grade::Red
+        }
+    }
+}
+
+/// The outcome of one guess. All figures are percentages.
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct QuizResult {
+    pub guess: f64,
+    pub equity: f64,
+    /// Standard error of `equity`, which comes from a simulation.
+    pub std_error: f64,
+    /// `guess - equity`: positive when the guess was too high.
+    pub error: f64,
+    pub grade: Grade,
+    /// The penalty under the quiz's scoring rule.
+    pub score: f64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum QuizError {
+    NoQuestion,
+    AlreadyAnswered,
+    /// Guesses are percentages, so must be in 0..=100.
+    GuessOutOfRange,
+    /// Only a preflop scheduler can pick the quiz's questions.
+    WrongTopic,
+}
+
+impl fmt::Display for QuizError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            QuizError::NoQuestion => write!(f, "no question has been dealt"),
+            QuizError::AlreadyAnswered => write!(f, "this question has already been answered"),
+            QuizError::GuessOutOfRange => write!(f, "a guess must be a percentage between 0 and 100"),
+            QuizError::WrongTopic => write!(f, "the quiz asks preflop questions, so needs a preflop scheduler"),
+        }
+    }
+}
+
+impl std::error::Error for QuizError {}
+
+/// The trainer's loop: deal two cards, take a guess at their preflop equity
+/// against random hands, and grade it. Every question comes from a seeded
+/// deck, so a hand can be shared by its seed and dealt again.
+///
+/// Guesses are graded and scored by a `ScoringRule`, the fixed bands unless
+/// another is set, with running totals kept for the session. Every answer
+/// is also kept for the session's `Report`, and filed with the `Scheduler`
+/// if one is picking the questions.
+///
+/// `export_state` saves all of that as versioned JSON for `import_state`.
+#[wasm_bindgen]
+#[derive(Debug)]
+pub struct Quiz {
+    opponents: u32,
+    seed: u64,
+    hole_cards: Vec<Card>,
+    truth: Truth,
+    result: Option<QuizResult>,
+    scoring: Box<dyn ScoringRule>,
+    totals: ScoreTotals,
+    session: Session,
+    scheduler: Option<Scheduler>,
+}
+
+#[wasm_bindgen]
+impl Quiz {
+    /// A heads-up quiz: one random opponent.
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Quiz {
+        Quiz {
+            opponents: 1,
+            seed: 0,
+            hole_cards: Vec::new(),
+            truth: Truth { equity: 0.0, std_error: 0.0 },
+            result: None,
+            scoring: Box::new(Bands::MVP),
+            totals: ScoreTotals::default(),
+            session: Session::new(),
+            scheduler: None,
+        }
+    }
+
+    /// A quiz against 1 to 9 random opponents.
+    pub fn with_opponents(opponents: u32) -> Option<Quiz> {
+        (1..=MAX_OPPONENTS).contains(&opponents).then_some(Quiz { opponents, ..Quiz::new() })
+    }
+
+    pub fn opponents(&self) -> u32 {
+        self.opponents
+    }
+
+    /// Deals a new question, chosen by the scheduler if there is one, and
+    /// returns its hole cards.
+    pub fn deal(&mut self) -> Vec<u32> {
+        let seed = match &self.scheduler {
+            Some(scheduler) => scheduler.next_seed(&mut thread_rng()),
+            None => thread_rng().gen(),
+        };
+        self.deal_with_seed(seed)
+    }
+
+    /// Deals the question shuffled from `seed`.
+    pub fn deal_with_seed(&mut self, seed: u64) -> Vec<u32> {
+        let hole_cards = Deck::with_seed(seed).draw(2);
+        self.ask(seed, hole_cards);
+        cards_to_u32(&self.hole_cards)
+    }
+
+    /// The seed of the current question.
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    pub fn hole_cards(&self) -> Vec<u32> {
+        cards_to_u32(&self.hole_cards)
+    }
+
+    /// The grade of the current question once it has been answered.
+    pub fn result(&self) -> Option<QuizResult> {
+        self.result
+    }
+
+    /// Switches to one of the built-in scoring rules, starting the totals
+    /// afresh.
+    pub fn set_scoring(&mut self, scoring: Scoring) {
+        self.set_scoring_rule(scoring.rule());
+    }
+
+    /// The name of the scoring rule in use.
+    pub fn scoring(&self) -> String {
+        self.scoring.name().to_string()
+    }
+
+    /// Running totals since the quiz started or its scoring rule changed.
+    pub fn totals(&self) -> ScoreTotals {
+        self.totals
+    }
+
+    #[wasm_bindgen(js_name = answer)]
+    pub fn answer_js(&mut self, guess: f64) -> Result<QuizResult, JsError> {
+        Ok(self.answer(guess)?)
+    }
+
+    #[wasm_bindgen(js_name = set_scheduler)]
+    pub fn set_scheduler_js(&mut self, scheduler: Scheduler) -> Result<(), JsError> {
+        Ok(self.set_scheduler(scheduler)?)
+    }
+
+    /// A copy of the scheduler picking the questions, if there is one.
+    #[wasm_bindgen(js_name = scheduler)]
+    pub fn scheduler_js(&self) -> Option<Scheduler> {
+        self.scheduler.clone()
+    }
+
+    /// Everything needed to pick up where the trainee left off, as JSON.
+    pub fn export_state(&self) -> String {
+        state::save(&SavedQuiz {
+            opponents: self.opponents,
+            scoring: self.scoring.name().to_string(),
+            totals: self.totals,
+            question: (!self.hole_cards.is_empty()).then(|| SavedQuestion {
+                seed: self.seed,
+                hole_cards: self.hole_cards.clone(),
+                result: self.result,
+            }),
+            session: self.session.clone(),
+            scheduler: self.scheduler.clone(),
+        })
+    }
+
+    #[wasm_bindgen(js_name = import_state)]
+    pub fn import_state_js(json: &str) -> Result<Quiz, JsError> {
+        Ok(Quiz::import_state(json)?)
+    }
+
+    /// The session report as a plain object.
+    pub fn report(&self) -> Result<JsValue, JsError> {
+        Ok(serde_wasm_bindgen::to_value(&self.session.report())?)
+    }
+
+    /// The session report as CSV.
+    pub fn report_csv(&self) -> String {
+        self.session.report().to_csv()
+    }
+}
+
+impl Quiz {
+    /// Grades `guess` against the current question. Each question takes
+    /// one answer.
+    pub fn answer(&mut self, guess: f64) -> Result<QuizResult, QuizError> {
+        if self.hole_cards.is_empty() {
+            return Err(QuizError::NoQuestion);
+        }
+        if self.result.is_some() {
+            return Err(QuizError::AlreadyAnswered);
+        }
+        if !(0.0..=100.0).contains(&guess) {
+            return Err(QuizError::GuessOutOfRange);
+        }
+
+        let result = self.grade(guess);
+        self.totals.add(result.score, result.grade);
+        // every question is two distinct dealt cards, and `set_scheduler`
+        // only takes preflop schedulers, so neither record can turn it down
+        self.session.record(&self.hole_cards, Street::Preflop, &result).expect("dealt hole cards");
+        if let Some(scheduler) = &mut self.scheduler {
+            scheduler.record(&self.hole_cards, &result).expect("preflop scheduler");
+        }
+        self.result = Some(result);
+        Ok(result)
+    }
+
+    // What `guess` earns on the current question.
+    fn grade(&self, guess: f64) -> QuizResult {
+        QuizResult {
+            guess,
+            equity: self.truth.equity,
+            std_error: self.truth.std_error,
+            error: guess - self.truth.equity,
+            grade: self.scoring.grade(guess, self.truth),
+            score: self.scoring.score(guess, self.truth),
+        }
+    }
+
+    // Makes `hole_cards`, dealt from `seed`, the current question.
+    fn ask(&mut self, seed: u64, hole_cards: Vec<Card>) {
+        let class = PreflopClass::from_cards(&hole_cards).unwrap();
+        self.seed = seed;
+        self.hole_cards = hole_cards;
+        self.truth = Truth {
+            equity: class_equity_vs_random(class, self.opponents).unwrap(),
+            std_error: class_std_error_vs_random(class, self.opponents).unwrap(),
+        };
+        self.result = None;
+    }
+
+    /// Lets `scheduler` pick the questions `deal` asks and learn from the
+    /// answers.
+    pub fn set_scheduler(&mut self, scheduler: Scheduler) -> Result<(), QuizError> {
+        if scheduler.topic() != Topic::Preflop {
+            return Err(QuizError::WrongTopic);
+        }
+        self.scheduler = Some(scheduler);
+        Ok(())
+    }
+
+    pub fn scheduler(&self) -> Option<&Scheduler> {
+        self.scheduler.as_ref()
+    }
+
+    /// Restores a quiz saved by `export_state` in this or an earlier
+    /// version. A custom scoring rule can't be saved, so a quiz that used one
+    /// comes back with the fixed bands and fresh totals.
+    pub fn import_state(json: &str) -> Result<Quiz, StateError> {
+        let saved: SavedQuiz = state::load(json)?;
+        let mut quiz = Quiz::with_opponents(saved.opponents).ok_or(StateError::Invalid("opponents must be 1 to 9"))?;
+        let known_rule = match Scoring::from_name(&saved.scoring) {
+            Some(scoring) => {
+                quiz.scoring = scoring.rule();
+                quiz.totals = saved.totals;
+                true
+            }
+            None => {
+                quiz.scoring = Box::new(Bands::MVP);
+                false
+            }
+        };
+        quiz.session = saved.session;
+        if let Some(question) = saved.question {
+            if Deck::with_seed(question.seed).draw(2) != question.hole_cards {
+                return Err(StateError::Invalid("hole cards must be the ones the seed deals"));
+            }
+            quiz.ask(question.seed, question.hole_cards);
+            if let Some(saved_result) = question.result {
+                quiz.result = Some(quiz.check_answer(saved_result, known_rule)?);
+            }
+        }
+        if let Some(scheduler) = saved.scheduler {
+            scheduler.check().map_err(StateError::Invalid)?;
+            quiz.set_scheduler(scheduler).map_err(|_| StateError::Invalid("the scheduler must be for preflop questions"))?;
+        }
+        Ok(quiz)
+    }
+
+    // The current question's answer regraded from a save's `saved` guess.
+    // It must be the last answer in the session and agree with the saved
+    // result, except that answers under a rule that didn't come back are
+    // regraded under the bands that replace it.
+    fn check_answer(&self, saved: QuizResult, known_rule: bool) -> Result<QuizResult, StateError> {
+        if !(0.0..=100.0).contains(&saved.guess) {
+            return Err(StateError::Invalid("a saved guess must be 0 to 100"));
+        }
+        let result = self.grade(saved.guess);
+        let graded = if known_rule { saved } else { QuizResult { grade: result.grade, score: result.score, ..saved } };
+        if graded != result {
+            return Err(StateError::Invalid("the saved answer must be what its guess earns"));
+        }
+
+        let answer = Answer {
+            class: PreflopClass::from_cards(&self.hole_cards).unwrap(),
+            street: Street::Preflop,
+            guess: result.guess,
+            equity: result.equity,
+        };
+        if self.session.answers().last() != Some(&answer) {
+            return Err(StateError::Invalid("an answered question must be the last one in the session"));
+        }
+        Ok(result)
+    }
+
+    /// The true equity of the current question, in percent.
+    pub fn equity(&self) -> f64 {
+        self.truth.equity
+    }
+
+    /// Every answer given so far.
+    pub fn session(&self) -> &Session {
+        &self.session
+    }
+
+    /// Scores answers with `rule` from now on, starting the totals afresh
+    /// since scores under different rules don't add up.
+    pub fn set_scoring_rule(&mut self, rule: Box<dyn ScoringRule>) {
+        self.scoring = rule;
+        self.totals = ScoreTotals::default();
+    }
+}
+
+// What `export_state` saves.
+#[derive(Serialize, Deserialize)]
+struct SavedQuiz {
+    opponents: u32,
+    /// The `ScoringRule::name` of the rule in use.
+    scoring: String,
+    totals: ScoreTotals,
+    question: Option<SavedQuestion>,
+    session: Session,
+    scheduler: Option<Scheduler>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct SavedQuestion {
+    #[serde(with = "crate::state::u64_string")]
+    seed: u64,
+    hole_cards: Vec<Card>,
+    result: Option<QuizResult>,
+}
+
+impl Default for Quiz {
+    fn default() -> Self {
+        Quiz::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn grade_boundaries() {
+        assert_eq!(Grade::from_error(0.0), Grade::Green);
+        assert_eq!(Grade::from_error(-10.0), Grade::Green);
+        assert_eq!(Grade::from_error(10.5), Grade::Yellow);
+        assert_eq!(Grade::from_error(-20.0), Grade::Yellow);
+        assert_eq!(Grade::from_error(20.1), Grade::Red);
+    }
+
+    #[test]
+    fn grades_a_guess_against_the_table() {
+        let mut quiz = Quiz::new();
+        quiz.deal_with_seed(8842);
+        let truth = quiz.equity();
+        assert!(truth > 0.0 && truth < 100.0);
+
+        let result = quiz.answer(truth + 12.0).unwrap();
+        assert_eq!(result.grade, Grade::Yellow);
+        assert!((result.error - 12.0).abs() < 1e-9);
+        assert_eq!(quiz.result(), Some(result));
+        assert_eq!(quiz.answer(truth), Err(QuizError::AlreadyAnswered));
+
+        quiz.deal();
+        assert_eq!(quiz.result(), None);
+        assert_eq!(quiz.answer(quiz.equity()).unwrap().grade, Grade::Green);
+
+        let totals = quiz.totals();
+        assert_eq!((totals.answered, totals.green, totals.yellow), (2, 1, 1));
+        assert_eq!(totals.mean(), Some(0.5));
+
+        let report = quiz.session().report();
+        assert_eq!(report.overall.answered, 2);
+        assert!((report.overall.bias - 6.0).abs() < 1e-9);
+        assert!(quiz.report_csv().starts_with("section,group,"));
+    }
+
+    #[test]
+    fn takes_any_scoring_rule() {
+        let mut quiz = Quiz::new();
+        quiz.set_scoring(Scoring::Squared);
+        assert_eq!(quiz.scoring(), "squared");
+
+        quiz.deal_with_seed(1);
+        let first = quiz.answer(quiz.equity() - 5.0).unwrap();
+        assert!((first.score - 25.0).abs() < 1e-9);
+        assert_eq!(first.grade, Grade::Green);
+        // heads-up figures are exact
+        assert_eq!(first.std_error, 0.0);
+        quiz.deal_with_seed(2);
+        quiz.answer(quiz.equity() + 3.0).unwrap();
+        assert!((quiz.totals().total - 34.0).abs() < 1e-9);
+
+        // a rule of our own, and the totals start again
+        #[derive(Debug)]
+        struct Harsh;
+        impl ScoringRule for Harsh {
+            fn name(&self) -> &'static str {
+                "harsh"
+            }
+            fn score(&self, guess: f64, truth: Truth) -> f64 {
+                if guess == truth.equity { 0.0 } else { 1.0 }
+            }
+            fn grade(&self, guess: f64, truth: Truth) -> Grade {
+                if guess == truth.equity { Grade::Green } else { Grade::Red }
+            }
+        }
+        quiz.set_scoring_rule(Box::new(Harsh));
+        assert_eq!(quiz.totals(), ScoreTotals::default());
+        quiz.deal_with_seed(3);
+        assert_eq!(quiz.answer(quiz.equity() + 1.0).unwrap().grade, Grade::Red);
+        assert_eq!(quiz.totals().red, 1);
+    }
+
+    #[test]
+    fn same_seed_same_question() {
+        let mut a = Quiz::new();
+        let mut b = Quiz::with_opponents(3).unwrap();
+        assert_eq!(a.deal_with_seed(8842), b.deal_with_seed(8842));
+        assert_eq!(b.seed(), 8842);
+        // more opponents, less equity
+        assert!(b.equity() < a.equity());
+
+        let mut c = Quiz::new();
+        c.deal();
+        let mut replay = Quiz::new();
+        assert_eq!(replay.deal_with_seed(c.seed()), c.hole_cards());
+    }
+
+    #[test]
+    fn scheduler_picks_and_learns() {
+        let mut quiz = Quiz::new();
+        assert_eq!(quiz.set_scheduler(Scheduler::new(Topic::Flop)), Err(QuizError::WrongTopic));
+        quiz.set_scheduler(Scheduler::new(Topic::Preflop)).unwrap();
+
+        quiz.deal_with_seed(5);
+        let class = PreflopClass::from_cards(&quiz.hole_cards).unwrap().to_string();
+        // thirty points off in whichever direction stays a valid guess
+        let miss = if quiz.equity() > 50.0 { quiz.equity() - 30.0 } else { quiz.equity() + 30.0 };
+        quiz.answer(miss).unwrap();
+        let scheduler = quiz.scheduler().unwrap();
+        assert_eq!(scheduler.turn(), 1);
+        assert_eq!(scheduler.class_record(&class).unwrap().misses, 1);
+    }
+
+    #[test]
+    fn state_round_trips() {
+        let mut quiz = Quiz::with_opponents(2).unwrap();
+        quiz.set_scoring(Scoring::Absolute);
+        quiz.set_scheduler(Scheduler::new(Topic::Preflop)).unwrap();
+        quiz.deal_with_seed(8842);
+        quiz.answer(40.0).unwrap();
+        // too big for a JavaScript number, so saved as a string
+        let seed = u64::MAX - 7;
+        quiz.deal_with_seed(seed);
+
+        let json = quiz.export_state();
+        assert!(json.contains(&format!("\"seed\":\"{}\"", seed)), "{}", json);
+        let restored = Quiz::import_state(&json).unwrap();
+        assert_eq!(restored.opponents(), 2);
+        assert_eq!(restored.scoring(), "absolute");
+        assert_eq!(restored.totals(), quiz.totals());
+        assert_eq!((restored.seed(), restored.hole_cards()), (seed, quiz.hole_cards()));
+        assert_eq!(restored.equity(), quiz.equity());
+        assert_eq!(restored.result(), None);
+        assert_eq!(restored.session(), quiz.session());
+        assert_eq!(restored.scheduler(), quiz.scheduler());
+        assert_eq!(restored.export_state(), json);
+    }
+
+    #[test]
+    fn imports_a_scheduler_saved_before_versioning() {
+        let mut scheduler = Scheduler::new(Topic::Preflop);
+        let mut quiz = Quiz::new();
+        quiz.deal_with_seed(3);
+        let result = quiz.answer(50.0).unwrap();
+        scheduler.record(&quiz.hole_cards, &result).unwrap();
+
+        let restored = Quiz::import_state(&scheduler.to_json()).unwrap();
+        assert_eq!(restored.scheduler(), Some(&scheduler));
+        assert_eq!(restored.scoring(), "bands");
+        assert!(restored.session().answers().is_empty());
+    }
+
+    #[test]
+    fn import_rejects_impossible_state() {
+        let mut quiz = Quiz::new();
+        quiz.deal_with_seed(1);
+        let saved: serde_json::Value = serde_json::from_str(&quiz.export_state()).unwrap();
+        let tampered = |pointer: &str, value: serde_json::Value| {
+            let mut saved = saved.clone();
+            *saved.pointer_mut(pointer).unwrap() = value;
+            Quiz::import_state(&saved.to_string())
+        };
+        assert!(matches!(tampered("/question/hole_cards", serde_json::json!(["As", "As"])), Err(StateError::Invalid(_))));
+        assert!(matches!(tampered("/opponents", serde_json::json!(12)), Err(StateError::Invalid(_))));
+        assert!(matches!(tampered("/question/seed", serde_json::json!("-1")), Err(StateError::Json(_))));
+    }
+
+    #[test]
+    fn import_rejects_hole_cards_the_seed_does_not_deal() {
+        let mut quiz = Quiz::new();
+        quiz.deal_with_seed(1);
+        let mut saved: serde_json::Value = serde_json::from_str(&quiz.export_state()).unwrap();
+        *saved.pointer_mut("/question/seed").unwrap() = serde_json::json!("2");
+        assert!(matches!(Quiz::import_state(&saved.to_string()), Err(StateError::Invalid(_))));
+    }
+
+    #[test]
+    fn import_rejects_a_result_the_guess_did_not_earn() {
+        let mut quiz = Quiz::new();
+        quiz.deal_with_seed(1);
+        let saved: serde_json::Value = serde_json::from_str(&quiz.export_state()).unwrap();
+        quiz.answer(50.0).unwrap();
+        let answered: serde_json::Value = serde_json::from_str(&quiz.export_state()).unwrap();
+        let restored = Quiz::import_state(&answered.to_string()).unwrap();
+        assert_eq!(restored.result(), quiz.result());
+
+        let tampered = |pointer: &str, value: serde_json::Value| {
+            let mut answered = answered.clone();
+            *answered.pointer_mut(pointer).unwrap() = value;
+            Quiz::import_state(&answered.to_string())
+        };
+        assert!(matches!(tampered("/question/result/equity", serde_json::json!(99.0)), Err(StateError::Invalid(_))));
+        assert!(matches!(tampered("/question/result/score", serde_json::json!(1000.0)), Err(StateError::Invalid(_))));
+        assert!(matches!(tampered("/question/result/grade", serde_json::json!("Red")), Err(StateError::Invalid(_))));
+        assert!(matches!(tampered("/question/result/guess", serde_json::json!(120.0)), Err(StateError::Invalid(_))));
+
+        // an answer for a question the session never saw answered
+        let mut unanswered = saved.clone();
+        *unanswered.pointer_mut("/question/result").unwrap() = answered["question"]["result"].clone();
+        assert!(matches!(Quiz::import_state(&unanswered.to_string()), Err(StateError::Invalid(_))));
+    }
+
+    #[test]
+    fn import_rejects_a_corrupt_scheduler() {
+        let mut quiz = Quiz::new();
+        quiz.set_scheduler(Scheduler::new(Topic::Preflop)).unwrap();
+        quiz.deal_with_seed(1);
+        quiz.answer(50.0).unwrap();
+        let class = PreflopClass::from_cards(&quiz.hole_cards).unwrap().to_string();
+        let mut saved: serde_json::Value = serde_json::from_str(&quiz.export_state()).unwrap();
+        *saved.pointer_mut(&format!("/scheduler/records/{}/leitner_box", class)).unwrap() = serde_json::json!(7);
+        assert!(matches!(Quiz::import_state(&saved.to_string()), Err(StateError::Invalid(_))));
+    }
+
+    #[test]
+    fn custom_rules_come_back_as_the_fixed_bands() {
+        #[derive(Debug)]
+        struct Special;
+        impl ScoringRule for Special {
+            fn name(&self) -> &'static str {
+                "coach's special"
+            }
+            fn score(&self, _: f64, _: Truth) -> f64 {
+                0.0
+            }
+        }
+
+        let mut quiz = Quiz::new();
+        quiz.set_scoring_rule(Box::new(Special));
+        quiz.deal_with_seed(1);
+        quiz.answer(50.0).unwrap();
+        let restored = Quiz::import_state(&quiz.export_state()).unwrap();
+        assert_eq!(restored.scoring(), "bands");
+        assert_eq!(restored.totals(), ScoreTotals::default());
+        assert_eq!(restored.session().answers().len(), 1);
+    }
+
+    #[test]
+    fn rejects_bad_answers() {
+        let mut quiz = Quiz::new();
+        assert_eq!(quiz.answer(50.0), Err(QuizError::NoQuestion));
+        quiz.deal();
+        assert_eq!(quiz.answer(-1.0), Err(QuizError::GuessOutOfRange));
+        assert_eq!(quiz.answer(f64::NAN), Err(QuizError::GuessOutOfRange));
+        assert_eq!(quiz.answer(100.5), Err(QuizError::GuessOutOfRange));
+        assert!(quiz.answer(100.0).is_ok());
+
+        assert!(Quiz::with_opponents(0).is_none());
+        assert!(Quiz::with_opponents(10).is_none());
+    }
+}
@@ -0,0 +1,361 @@
+use std::fmt;
+use std::str::FromStr;
+use wasm_bindgen::prelude::*;
+use crate::card::{Card, CardErrorKind, CardParseError, Rank, Suit, cards_to_u32, parse_one};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RangeErrorKind {
+    InvalidRank(char),
+    InvalidSuit(char),
+    /// The token is not a pair, suited/offsuit class, span or explicit combo.
+    InvalidToken,
+    /// The weight after `:` is not a number in 0..=1.
+    InvalidWeight,
+    /// The two ends of a dash span do not describe the same kind of hand.
+    InvalidSpan,
+    DuplicateCard,
+}
+
+/// Error from `Range::parse`. `position` is the 0-based character offset in
+/// the input where the problem starts.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RangeParseError {
+    pub position: usize,
+    pub kind: RangeErrorKind,
+}
+
+impl fmt::Display for RangeParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            RangeErrorKind::InvalidRank(c) => write!(f, "invalid rank '{}'", c)?,
+            RangeErrorKind::InvalidSuit(c) => write!(f, "invalid suit '{}'", c)?,
+            RangeErrorKind::InvalidToken => write!(f, "unrecognised hand")?,
+            RangeErrorKind::InvalidWeight => write!(f, "weight must be a number between 0 and 1")?,
+            RangeErrorKind::InvalidSpan => write!(f, "both ends of a span must be the same kind of hand")?,
+            RangeErrorKind::DuplicateCard => write!(f, "combo uses the same card twice")?,
+        }
+        write!(f, " at position {}", self.position)
+    }
+}
+
+impl std::error::Error for RangeParseError {}
+
+/// Two specific hole cards and how often they are in the range (0..=1).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct WeightedCombo {
+    pub cards: [Card; 2],
+    pub weight: f64,
+}
+
+/// A weighted set of hole-card combos, usually parsed from standard range
+/// notation such as `"QQ+, AKs, A2s-A5s, KQo:0.5, AhKh"`.
+#[wasm_bindgen]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Range {
+    combos: Vec<WeightedCombo>,
+}
+
+#[wasm_bindgen]
+impl Range {
+    pub fn len(&self) -> usize {
+        self.combos.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.combos.is_empty()
+    }
+
+    /// Sum of all combo weights, i.e. the number of combos the range is
+    /// worth once partial weights are taken into account.
+    pub fn total_weight(&self) -> f64 {
+        self.combos.iter().map(|c| c.weight).sum()
+    }
+
+    /// Every combo's two cards, flattened into pairs.
+    pub fn cards(&self) -> Vec<u32> {
+        let cards: Vec<Card> = self.combos.iter().flat_map(|c| c.cards).collect();
+        cards_to_u32(&cards)
+    }
+
+    pub fn weights(&self) -> Vec<f64> {
+        self.combos.iter().map(|c| c.weight).collect()
+    }
+}
+
+impl Range {
+    pub fn parse(text: &str) -> Result<Range, RangeParseError> {
+        let chars: Vec<char> = text.chars().collect();
+        let mut range = Range::default();
+
+        let mut i = 0;
+        while i < chars.len() {
+            if is_separator(chars[i]) {
+                i += 1;
+                continue;
+            }
+            let start = i;
+            while i < chars.len() && !is_separator(chars[i]) {
+                i += 1;
+            }
+            range.parse_token(&chars[start..i], start)?;
+        }
+
+        Ok(range)
+    }
+
+    pub fn combos(&self) -> &[WeightedCombo] {
+        &self.combos
+    }
+
+    /// Adds `cards` to the range, replacing the weight if it is already in.
+    pub fn insert(&mut self, cards: [Card; 2], weight: f64) {
+        let cards = if cards[0] > cards[1] { cards } else { [cards[1], cards[0]] };
+        match self.combos.iter_mut().find(|c| c.cards == cards) {
+            Some(existing) => existing.weight = weight,
+            None => self.combos.push(WeightedCombo { cards, weight }),
+        }
+    }
+
+    fn parse_token(&mut self, token: &[char], offset: usize) -> Result<(), RangeParseError> {
+        let (hand, weight) = match token.iter().position(|&c| c == ':') {
+            Some(colon) => (&token[..colon], parse_weight(&token[colon + 1..], offset + colon + 1)?),
+            None => (token, 1.0),
+        };
+
+        if let Some(dash) = hand.iter().position(|&c| c == '-') {
+            let low = parse_class(&hand[..dash], offset)?;
+            let high = parse_class(&hand[dash + 1..], offset + dash + 1)?;
+            for class in span(low, high).ok_or(RangeParseError { position: offset, kind: RangeErrorKind::InvalidSpan })? {
+                self.insert_class(class, weight);
+            }
+        } else if hand.last() == Some(&'+') {
+            let base = parse_class(&hand[..hand.len() - 1], offset)?;
+            for class in plus(base) {
+                self.insert_class(class, weight);
+            }
+        } else if hand.len() >= 4 {
+            self.insert(parse_combo(hand, offset)?, weight);
+        } else {
+            let class = parse_class(hand, offset)?;
+            self.insert_class(class, weight);
+        }
+
+        Ok(())
+    }
+
+    fn insert_class(&mut self, class: HandClass, weight: f64) {
+        for (s1, &suit1) in Suit::ALL.iter().enumerate() {
+            for (s2, &suit2) in Suit::ALL.iter().enumerate() {
+                let wanted = if class.high == class.low {
+                    s1 < s2
+                } else {
+                    match class.suited {
+                        Some(true) => s1 == s2,
+                        Some(false) => s1 != s2,
+                        None => true,
+                    }
+                };
+                if wanted {
+                    let first = Card::new(class.high, suit1);
+                    let second = Card::new(class.low, suit2);
+                    self.insert([first, second], weight);
+                }
+            }
+        }
+    }
+}
+
+impl FromStr for Range {
+    type Err = RangeParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Range::parse(s)
+    }
+}
+
+/// Parses standard range notation, throwing the parse error's message in JS.
+#[wasm_bindgen]
+pub fn parse_range(text: &str) -> Result<Range, JsError> {
+    Ok(Range::parse(text)?)
+}
+
+fn is_separator(c: char) -> bool {
+    c == ',' || c.is_whitespace()
+}
+
+/// Two ranks plus suitedness; `suited` is `None` for pairs and for classes
+/// like "AK" that cover both.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct HandClass {
+    high: Rank,
+    low: Rank,
+    suited: Option<bool>,
+}
+
+fn parse_rank(c: char, position: usize) -> Result<Rank, RangeParseError> {
+    Rank::from_char(c).ok_or(RangeParseError { position, kind: RangeErrorKind::InvalidRank(c) })
+}
+
+// A card error from `chars`, moved to where `chars` starts in the input.
+fn card_error(error: CardParseError, offset: usize) -> RangeParseError {
+    let kind = match error.kind {
+        CardErrorKind::WrongLength => RangeErrorKind::InvalidToken,
+        CardErrorKind::InvalidRank(c) => RangeErrorKind::InvalidRank(c),
+        CardErrorKind::InvalidSuit(c) => RangeErrorKind::InvalidSuit(c),
+        CardErrorKind::Duplicate(_) => RangeErrorKind::DuplicateCard,
+    };
+    RangeParseError { position: offset + error.position, kind }
+}
+
+/// Two explicit cards back to back, read as leniently as `parse_cards`
+/// reads them, so "10h9h" and "A♠K♠" are combos too.
+fn parse_combo(chars: &[char], offset: usize) -> Result<[Card; 2], RangeParseError> {
+    let (first, next) = parse_one(chars, 0).map_err(|e| card_error(e, offset))?;
+    let (second, end) = parse_one(chars, next).map_err(|e| card_error(e, offset))?;
+    if end != chars.len() {
+        return Err(RangeParseError { position: offset + end, kind: RangeErrorKind::InvalidToken });
+    }
+    if first == second {
+        return Err(RangeParseError { position: offset + next, kind: RangeErrorKind::DuplicateCard });
+    }
+    Ok([first, second])
+}
+
+fn parse_class(chars: &[char], offset: usize) -> Result<HandClass, RangeParseError> {
+    if chars.len() != 2 && chars.len() != 3 {
+        return Err(RangeParseError { position: offset, kind: RangeErrorKind::InvalidToken });
+    }
+
+    let first = parse_rank(chars[0], offset)?;
+    let second = parse_rank(chars[1], offset + 1)?;
+    let suited = match chars.get(2) {
+        None => None,
+        Some('s') => Some(true),
+        Some('o') => Some(false),
+        Some(&c) => return Err(RangeParseError { position: offset + 2, kind: RangeErrorKind::InvalidSuit(c) }),
+    };
+    if first == second && suited.is_some() {
+        return Err(RangeParseError { position: offset + 2, kind: RangeErrorKind::InvalidToken });
+    }
+
+    Ok(HandClass { high: first.max(second), low: first.min(second), suited })
+}
+
+fn parse_weight(chars: &[char], position: usize) -> Result<f64, RangeParseError> {
+    let text: String = chars.iter().collect();
+    match text.parse::<f64>() {
+        Ok(weight) if (0.0..=1.0).contains(&weight) => Ok(weight),
+        _ => Err(RangeParseError { position, kind: RangeErrorKind::InvalidWeight }),
+    }
+}
+
+/// Ranks from `low` to `high`, both included.
+fn ranks_between(low: Rank, high: Rank) -> impl Iterator<Item = Rank> {
+    Rank::ALL[low.index()..=high.index()].iter().copied()
+}
+
+/// "TT+" is every pair from tens up, "ATs+" raises the kicker up to a king.
+fn plus(base: HandClass) -> Vec<HandClass> {
+    if base.high == base.low {
+        ranks_between(base.low, Rank::Ace).map(|r| HandClass { high: r, low: r, suited: None }).collect()
+    } else {
+        ranks_between(base.low, base.high).filter(|&k| k != base.high).map(|k| HandClass { low: k, ..base }).collect()
+    }
+}
+
+/// "TT-77" covers the pairs in between, "A2s-A5s" the kickers in between.
+fn span(a: HandClass, b: HandClass) -> Option<Vec<HandClass>> {
+    let a_pair = a.high == a.low;
+    let b_pair = b.high == b.low;
+
+    if a_pair && b_pair {
+        let (low, high) = (a.low.min(b.low), a.low.max(b.low));
+        Some(ranks_between(low, high).map(|r| HandClass { high: r, low: r, suited: None }).collect())
+    } else if !a_pair && !b_pair && a.high == b.high && a.suited == b.suited {
+        let (low, high) = (a.low.min(b.low), a.low.max(b.low));
+        Some(ranks_between(low, high).map(|k| HandClass { low: k, ..a }).collect())
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::card::new_card;
+
+    fn combo_count(text: &str) -> usize {
+        Range::parse(text).unwrap().len()
+    }
+
+    fn error(text: &str) -> RangeParseError {
+        Range::parse(text).unwrap_err()
+    }
+
+    #[test]
+    fn counts_hand_classes() {
+        assert_eq!(combo_count("TT"), 6);
+        assert_eq!(combo_count("AKs"), 4);
+        assert_eq!(combo_count("KQo"), 12);
+        assert_eq!(combo_count("AK"), 16);
+        assert_eq!(combo_count(""), 0);
+    }
+
+    #[test]
+    fn expands_plus_and_spans() {
+        assert_eq!(combo_count("TT+"), 5 * 6);
+        assert_eq!(combo_count("ATs+"), 4 * 4);
+        assert_eq!(combo_count("A2s-A5s"), 4 * 4);
+        assert_eq!(combo_count("A5s-A2s"), 4 * 4);
+        assert_eq!(combo_count("77-99"), 3 * 6);
+        assert_eq!(combo_count("QQ+, AKs"), 18 + 4);
+    }
+
+    #[test]
+    fn overlapping_entries_are_not_double_counted() {
+        assert_eq!(combo_count("AK, AKs, AhKh"), 16);
+        assert_eq!(combo_count("QQ+ KK"), 18);
+    }
+
+    #[test]
+    fn explicit_combo_and_weights() {
+        let range = Range::parse("AhKh, QQ:0.5").unwrap();
+        let ah = new_card("Ah").unwrap();
+        let kh = new_card("Kh").unwrap();
+
+        assert_eq!(range.len(), 7);
+        assert!(range.combos().iter().any(|c| c.cards.contains(&ah) && c.cards.contains(&kh) && c.weight == 1.0));
+        assert_eq!(range.total_weight(), 1.0 + 6.0 * 0.5);
+    }
+
+    #[test]
+    fn later_weight_wins() {
+        let range: Range = "AA:0.25, AsAh".parse().unwrap();
+        assert_eq!(range.total_weight(), 5.0 * 0.25 + 1.0);
+    }
+
+    #[test]
+    fn reports_error_positions() {
+        assert_eq!(error("QQ+, AXs"), RangeParseError { position: 6, kind: RangeErrorKind::InvalidRank('X') });
+        assert_eq!(error("AKx"), RangeParseError { position: 2, kind: RangeErrorKind::InvalidSuit('x') });
+        assert_eq!(error("TT, AhKz"), RangeParseError { position: 7, kind: RangeErrorKind::InvalidSuit('z') });
+        assert_eq!(error("AK:1.5"), RangeParseError { position: 3, kind: RangeErrorKind::InvalidWeight });
+        assert_eq!(error("A2s-K5s"), RangeParseError { position: 0, kind: RangeErrorKind::InvalidSpan });
+        assert_eq!(error("  AhAh"), RangeParseError { position: 4, kind: RangeErrorKind::DuplicateCard });
+        assert_eq!(error("AKQJ+"), RangeParseError { position: 0, kind: RangeErrorKind::InvalidToken });
+        assert_eq!(error("TTs"), RangeParseError { position: 2, kind: RangeErrorKind::InvalidToken });
+        assert_eq!(error("QQ, 10h1s"), RangeParseError { position: 7, kind: RangeErrorKind::InvalidRank('1') });
+        assert_eq!(error("K♠Q♠J"), RangeParseError { position: 4, kind: RangeErrorKind::InvalidToken });
+    }
+
+    #[test]
+    fn explicit_combos_read_like_cards() {
+        assert_eq!(Range::parse("10h9h").unwrap(), Range::parse("Th9h").unwrap());
+        assert_eq!(Range::parse("a♥K♡, qsJS").unwrap(), Range::parse("AhKh, QsJs").unwrap());
+    }
+
+    #[test]
+    fn error_message_names_the_position() {
+        assert_eq!(error("QQ+, AXs").to_string(), "invalid rank 'X' at position 6");
+    }
+}
@@ -0,0 +1,40 @@
+// Shared between the crate and build.rs, which uses it to lay out the
+// generated lookup tables, so this file must only depend on core.
+
+const fn build_rank_counts() -> [[u32; 8]; 14] {
+    let mut table = [[0u32; 8]; 14];
+    table[0][0] = 1;
+    let mut m = 1;
+    while m < 14 {
+        let mut k = 0;
+        while k < 8 {
+            let mut c = 0;
+            while c <= 4 && c <= k {
+                table[m][k] += table[m - 1][k - c];
+                c += 1;
+            }
+            k += 1;
+        }
+        m += 1;
+    }
+    table
+}
+
+/// `RANK_COUNTS[m][k]` is the number of ways to spread `k` cards over `m`
+/// ranks with at most four cards of any rank.
+pub const RANK_COUNTS: [[u32; 8]; 14] = build_rank_counts();
+
+/// Perfect hash of a hand's rank counts (deuce first) into
+/// `0..RANK_COUNTS[13][n]`, where `n` is the number of cards. Hands with the
+/// same ranks get the same index regardless of suits.
+pub fn rank_count_index(counts: &[u8; 13], n: usize) -> usize {
+    let mut index = 0;
+    let mut remaining = n;
+    for (r, &count) in counts.iter().enumerate() {
+        for j in 0..count as usize {
+            index += RANK_COUNTS[12 - r][remaining - j] as usize;
+        }
+        remaining -= count as usize;
+    }
+    index
+}
@@ -0,0 +1,312 @@
+use std::collections::hash_map::{Entry, HashMap};
+use std::fmt;
+use itertools::Itertools;
+use rand::thread_rng;
+use crate::card::Card;
+use crate::card_set::CardSet;
+use crate::equity::estimate_multiway_equity;
+use crate::hand_history::{Action, ActionKind, HandHistory, Street};
+use crate::showdown_shares;
+
+/// One moment in a replayed hand: a street's cards coming out, or a player
+/// about to act.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ReplayStep {
+    pub street: Street,
+    pub board: Vec<Card>,
+    /// Chips in the middle before `action`.
+    pub pot: f64,
+    /// The decision being made, or `None` when the street has just been dealt.
+    pub action: Option<Action>,
+    /// Equity, in percent, of each player still in the hand whose hole cards
+    /// are known, in seat order. Players whose cards were never seen are left
+    /// out, so the figures are among the known hands only.
+    pub equities: Vec<(String, f64)>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ReplayError {
+    /// The hand reaches a street whose board cards it doesn't list.
+    MissingBoard(Street),
+    /// Preflop equities are estimated, so need at least one iteration.
+    NoIterations,
+    /// A card is both in someone's hand and on the board, or in two hands.
+    DuplicateCards,
+}
+
+impl fmt::Display for ReplayError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReplayError::MissingBoard(street) => write!(f, "the hand reaches the {:?} without its board cards", street),
+            ReplayError::NoIterations => write!(f, "preflop equities need at least one iteration"),
+            ReplayError::DuplicateCards => write!(f, "a card is dealt twice"),
+        }
+    }
+}
+
+impl std::error::Error for ReplayError {}
+
+/// Walks `hand` street by street. Blinds and antes are posted silently;
+/// every other action gets a step showing the equities as the player
+/// decided. Streets dealt after everyone is all in still get a step each.
+///
+/// Equities from the flop on are exact. Preflop they are estimated from
+/// `iterations` random boards.
+pub fn replay(hand: &HandHistory, iterations: u32) -> Result<Vec<ReplayStep>, ReplayError> {
+    if iterations == 0 {
+        return Err(ReplayError::NoIterations);
+    }
+    let known: Vec<(&str, [Card; 2])> = hand.seats.iter()
+        .filter_map(|seat| Some((seat.player.as_str(), seat.hole_cards?)))
+        .collect();
+
+    let mut replay = Replay {
+        hand, known, iterations,
+        steps: Vec::new(),
+        cache: HashMap::new(),
+        street: None,
+        pot: 0.0,
+        folded: Vec::new(),
+    };
+    let mut committed: HashMap<&str, f64> = HashMap::new();
+    let mut street = Street::Preflop;
+
+    for action in &hand.actions {
+        if action.street != street {
+            street = action.street;
+            committed.clear();
+        }
+        let player = action.player.as_str();
+        let spent = committed.entry(player).or_default();
+        let added = match action.kind {
+            ActionKind::Ante(amount) => {
+                replay.pot += amount;
+                continue;
+            }
+            ActionKind::SmallBlind(amount) | ActionKind::BigBlind(amount) => {
+                replay.pot += amount;
+                *spent += amount;
+                continue;
+            }
+            ActionKind::Uncalled(amount) => {
+                replay.pot -= amount;
+                *spent -= amount;
+                continue;
+            }
+            ActionKind::Fold | ActionKind::Check => 0.0,
+            ActionKind::Call(amount) | ActionKind::Bet(amount) => amount,
+            ActionKind::Raise { to, .. } => to - *spent,
+        };
+
+        replay.deal_to(action.street)?;
+        replay.step(action.street, Some(action.clone()))?;
+        *committed.entry(player).or_default() += added;
+        replay.pot += added;
+        if action.kind == ActionKind::Fold {
+            replay.folded.push(player);
+        }
+    }
+
+    // an all-in runs the board out with nobody left to act
+    let last_street = [Street::River, Street::Turn, Street::Flop, Street::Preflop].into_iter()
+        .find(|s| hand.board.len() >= s.board_len())
+        .unwrap();
+    replay.deal_to(last_street)?;
+    Ok(replay.steps)
+}
+
+struct Replay<'a> {
+    hand: &'a HandHistory,
+    known: Vec<(&'a str, [Card; 2])>,
+    iterations: u32,
+    steps: Vec<ReplayStep>,
+    // equities by board length and the known players still in
+    cache: HashMap<(usize, Vec<&'a str>), Vec<f64>>,
+    street: Option<Street>,
+    pot: f64,
+    folded: Vec<&'a str>,
+}
+
+impl<'a> Replay<'a> {
+    // adds a step for each street dealt since the last one, up to `street`
+    fn deal_to(&mut self, street: Street) -> Result<(), ReplayError> {
+        for next in [Street::Preflop, Street::Flop, Street::Turn, Street::River] {
+            if next <= street && self.street.is_none_or(|s| next > s) {
+                self.step(next, None)?;
+                self.street = Some(next);
+            }
+        }
+        Ok(())
+    }
+
+    fn step(&mut self, street: Street, action: Option<Action>) -> Result<(), ReplayError> {
+        let board = self.hand.board.get(..street.board_len()).ok_or(ReplayError::MissingBoard(street))?.to_vec();
+        let live: Vec<(&'a str, [Card; 2])> = self.known.iter().copied()
+            .filter(|(player, _)| !self.folded.contains(player))
+            .collect();
+
+        let key = (board.len(), live.iter().map(|&(player, _)| player).collect());
+        let iterations = self.iterations;
+        let equities = match self.cache.entry(key) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => {
+                let hands: Vec<[Card; 2]> = live.iter().map(|&(_, cards)| cards).collect();
+                entry.insert(known_equities(&hands, &board, iterations)?)
+            }
+        };
+
+        let equities = live.iter().zip(equities.iter()).map(|(&(player, _), &e)| (player.to_string(), e)).collect();
+        self.steps.push(ReplayStep { street, board, pot: self.pot, action, equities });
+        Ok(())
+    }
+}
+
+/// Equity of each of `hands` on `board`: exact once at most two cards are
+/// to come, otherwise from `iterations` random runouts.
+fn known_equities(hands: &[[Card; 2]], board: &[Card], iterations: u32) -> Result<Vec<f64>, ReplayError> {
+    match hands.len() {
+        0 => return Ok(Vec::new()),
+        1 => return Ok(vec![100.0]),
+        _ => {}
+    }
+
+    let hole_cards: Vec<Card> = hands.iter().flatten().copied().collect();
+    let dead: CardSet = hole_cards.iter().chain(board).collect();
+    if dead.len() != hole_cards.len() + board.len() {
+        return Err(ReplayError::DuplicateCards);
+    }
+
+    let missing = 5 - board.len();
+    if missing > 2 {
+        if iterations == 0 {
+            return Err(ReplayError::NoIterations);
+        }
+        let results = estimate_multiway_equity(&hole_cards, board, hands.len() as u32, iterations, &mut thread_rng())
+            .expect("distinct cards and at least one iteration");
+        return Ok(results.iter().map(|r| r.equity).collect());
+    }
+
+    let hands: Vec<Vec<Card>> = hands.iter().map(|h| h.to_vec()).collect();
+    let mut totals = vec![0.0; hands.len()];
+    let mut runouts = 0;
+    for runout in (CardSet::FULL - dead).iter().combinations(missing) {
+        let mut full_board = board.to_vec();
+        full_board.extend(runout);
+        for (total, share) in totals.iter_mut().zip(showdown_shares(&hands, &full_board)) {
+            *total += share;
+        }
+        runouts += 1;
+    }
+    Ok(totals.iter().map(|t| 100.0 * t / runouts as f64).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::card::parse_cards;
+    use crate::equity::enumerate_equity;
+    use crate::hand_history::{parse_hand_histories, parse_hand_history};
+
+    fn equity(step: &ReplayStep, player: &str) -> f64 {
+        step.equities.iter().find(|(p, _)| p == player).unwrap().1
+    }
+
+    fn street_starts(steps: &[ReplayStep]) -> Vec<&ReplayStep> {
+        steps.iter().filter(|s| s.action.is_none()).collect()
+    }
+
+    #[test]
+    fn replays_a_showdown_street_by_street() {
+        let hand = parse_hand_history(include_str!("../testdata/cash_showdown.txt")).unwrap();
+        let steps = replay(&hand, 2000).unwrap();
+
+        // four streets plus twelve decisions
+        assert_eq!(steps.len(), 16);
+        let starts = street_starts(&steps);
+        let streets: Vec<Street> = starts.iter().map(|s| s.street).collect();
+        assert_eq!(streets, [Street::Preflop, Street::Flop, Street::Turn, Street::River]);
+        assert_eq!(starts[1].board.len(), 3);
+        assert!((starts[1].pot - 0.15).abs() < 1e-9);
+        assert!((starts[3].pot - 0.71).abs() < 1e-9);
+
+        for step in &steps {
+            let players: Vec<&str> = step.equities.iter().map(|(p, _)| p.as_str()).collect();
+            assert_eq!(players, ["Hero", "nit_lord"]);
+            let total: f64 = step.equities.iter().map(|(_, e)| e).sum();
+            assert!((total - 100.0).abs() < 1e-6);
+        }
+
+        // AK is ahead of K7 before the flop, and drawing thin to two pair after it
+        assert!(equity(starts[0], "Hero") > 65.0);
+        let flop = enumerate_equity(&parse_cards("AhKd").unwrap(), &parse_cards("Kh7d").unwrap(), &starts[1].board).unwrap();
+        assert!((equity(starts[1], "Hero") - flop.equity).abs() < 1e-9);
+        assert!(flop.equity < 20.0);
+        assert_eq!(equity(starts[3], "nit_lord"), 100.0);
+
+        let last = steps.last().unwrap();
+        assert_eq!(last.action.as_ref().unwrap().player, "Hero");
+        assert!((last.pot - 1.11).abs() < 1e-9);
+    }
+
+    #[test]
+    fn all_in_runout_gets_every_street() {
+        let hand = parse_hand_history(include_str!("../testdata/tournament_side_pot.txt")).unwrap();
+        let steps = replay(&hand, 2000).unwrap();
+
+        let starts = street_starts(&steps);
+        assert_eq!(starts.len(), 4);
+        for start in &starts[1..] {
+            assert_eq!(start.pot, 3405.0);
+            assert_eq!(start.equities.len(), 3);
+        }
+        // on 2h 8c Jd the queens are best, and the ace on the turn hands it to AK
+        let flop = starts[1];
+        assert!(equity(flop, "Hero") > equity(flop, "Villain_B") && equity(flop, "Hero") > equity(flop, "ShortyMcShort"));
+        assert_eq!(equity(starts[3], "ShortyMcShort"), 100.0);
+
+        // folder folds before any cards are known to matter
+        assert_eq!(steps[1].action.as_ref().unwrap().kind, ActionKind::Fold);
+        assert_eq!(steps[1].pot, 95.0);
+    }
+
+    #[test]
+    fn only_known_players_are_counted() {
+        let hands = parse_hand_histories(include_str!("../testdata/session.txt")).unwrap();
+        let steps = replay(&hands[1], 1000).unwrap();
+
+        assert_eq!(street_starts(&steps).len(), 3);
+        assert!(steps.iter().all(|s| s.equities == vec![("Hero".to_string(), 100.0)]));
+        assert_eq!(steps.len(), 3 + 10);
+    }
+
+    #[test]
+    fn exact_when_two_cards_to_come() {
+        let aces = parse_cards("AsAh").unwrap();
+        let kings = parse_cards("KsKh").unwrap();
+        let board = parse_cards("Kd 7c 2h").unwrap();
+
+        let equities = known_equities(&[[aces[0], aces[1]], [kings[0], kings[1]]], &board, 0).unwrap();
+        let expected = enumerate_equity(&aces, &kings, &board).unwrap();
+        assert!((equities[0] - expected.equity).abs() < 1e-9);
+        assert!((equities[0] + equities[1] - 100.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn stops_at_a_street_with_no_board() {
+        let mut hand = parse_hand_history(include_str!("../testdata/cash_showdown.txt")).unwrap();
+        hand.board.truncate(3);
+        assert_eq!(replay(&hand, 100), Err(ReplayError::MissingBoard(Street::Turn)));
+        hand.board.truncate(2);
+        assert_eq!(replay(&hand, 100), Err(ReplayError::MissingBoard(Street::Flop)));
+    }
+
+    #[test]
+    fn preflop_needs_iterations() {
+        let hand = parse_hand_history(include_str!("../testdata/cash_showdown.txt")).unwrap();
+        assert_eq!(replay(&hand, 0), Err(ReplayError::NoIterations));
+        let aces = parse_cards("AsAh").unwrap();
+        let kings = parse_cards("KsKh").unwrap();
+        assert_eq!(known_equities(&[[aces[0], aces[1]], [kings[0], kings[1]]], &[], 0), Err(ReplayError::NoIterations));
+        assert_eq!(known_equities(&[[aces[0], aces[1]], [aces[0], kings[1]]], &[], 100), Err(ReplayError::DuplicateCards));
+    }
+}
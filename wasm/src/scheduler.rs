@@ -0,0 +1,316 @@
+use std::collections::BTreeMap;
+use itertools::Itertools;
+use rand::{thread_rng, Rng};
+use serde::{de, Deserialize, Serialize};
+use wasm_bindgen::prelude::*;
+use crate::canonical::{flop_class, PreflopClass};
+use crate::card::{Card, cards_from_u32};
+use crate::quiz::{Grade, QuizResult};
+use crate::Deck;
+
+/// Questions to wait before a class in each Leitner box comes due again.
+pub const BOX_INTERVALS: [u32; 5] = [0, 5, 15, 40, 100];
+
+// how much more often a due class is dealt than one resting in the same box
+const DUE_BOOST: f64 = 4.0;
+
+/// What the scheduler tracks: starting hands by `PreflopClass`, or flops up
+/// to a change of suits.
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Topic {
+    Preflop,
+    Flop,
+}
+
+impl Topic {
+    /// Cards a question deals: the first this many off the deck.
+    pub fn cards(self) -> usize {
+        match self {
+            Topic::Preflop => 2,
+            Topic::Flop => 3,
+        }
+    }
+
+    /// The class `cards` belong to, named like "AKs" or "As Kd 7s".
+    pub fn class(self, cards: &[Card]) -> Option<String> {
+        match self {
+            Topic::Preflop => PreflopClass::from_cards(cards).map(|class| class.to_string()),
+            Topic::Flop => flop_class(cards).map(|flop| flop.iter().join(" ")),
+        }
+    }
+}
+
+/// How a trainee has done on one class.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ClassRecord {
+    /// Leitner box, from 0 for classes still being missed up to
+    /// `BOX_INTERVALS.len() - 1` for ones that are known.
+    pub leitner_box: usize,
+    /// The question number from which the class is due for review.
+    pub due: u32,
+    pub attempts: u32,
+    /// Answers graded red.
+    pub misses: u32,
+    /// Sum of the absolute errors, in percentage points.
+    pub total_error: f64,
+}
+
+impl ClassRecord {
+    pub fn mean_error(&self) -> f64 {
+        if self.attempts == 0 { 0.0 } else { self.total_error / self.attempts as f64 }
+    }
+}
+
+/// Leitner-style spaced repetition over hand classes. A green answer moves a
+/// class up a box and puts it away for longer, yellow keeps it where it is,
+/// and red sends it back to the first box, due straight away.
+///
+/// Hands are still dealt from a `Deck`, so common classes come up more than
+/// rare ones, but each deal is kept only with a chance that favours unseen,
+/// due and low-box classes. The scheduler hands out the deck's seed, which
+/// `Quiz::deal_with_seed` turns into the same question.
+#[wasm_bindgen]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Scheduler {
+    topic: Topic,
+    /// Questions answered so far.
+    turn: u32,
+    records: BTreeMap<String, ClassRecord>,
+}
+
+#[wasm_bindgen]
+impl Scheduler {
+    #[wasm_bindgen(constructor)]
+    pub fn new(topic: Topic) -> Scheduler {
+        Scheduler { topic, turn: 0, records: BTreeMap::new() }
+    }
+
+    pub fn topic(&self) -> Topic {
+        self.topic
+    }
+
+    pub fn turn(&self) -> u32 {
+        self.turn
+    }
+
+    #[wasm_bindgen(js_name = next_seed)]
+    pub fn next_seed_js(&self) -> u64 {
+        self.next_seed(&mut thread_rng())
+    }
+
+    #[wasm_bindgen(js_name = record)]
+    pub fn record_js(&mut self, cards: Vec<u32>, result: &QuizResult) -> Result<(), JsError> {
+        let cards = cards_from_u32(&cards).ok_or_else(|| JsError::new("invalid card"))?;
+        self.record(&cards, result).ok_or_else(|| JsError::new(&format!("not a {:?} question", self.topic)))
+    }
+
+    /// Up to `n` classes most in need of work: lowest box first, then the
+    /// largest mean error.
+    pub fn weak_spots(&self, n: usize) -> Vec<String> {
+        let mut seen: Vec<(&String, &ClassRecord)> = self.records.iter().collect();
+        seen.sort_by(|(_, a), (_, b)| a.leitner_box.cmp(&b.leitner_box).then(b.mean_error().total_cmp(&a.mean_error())));
+        seen.into_iter().take(n).map(|(class, _)| class.clone()).collect()
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap()
+    }
+
+    #[wasm_bindgen(js_name = from_json)]
+    pub fn from_json_js(json: &str) -> Result<Scheduler, JsError> {
+        Ok(Scheduler::from_json(json)?)
+    }
+}
+
+impl Scheduler {
+    /// A deck seed for the next question. Deals are drawn from seeded decks
+    /// until one is kept, each with a chance of its class's weight over the
+    /// largest weight there is.
+    pub fn next_seed(&self, rng: &mut impl Rng) -> u64 {
+        loop {
+            let seed = rng.gen();
+            let cards = Deck::with_seed(seed).draw(self.topic.cards());
+            let weight = self.topic.class(&cards).map_or(0.0, |class| self.weight(&class));
+            if rng.gen::<f64>() * DUE_BOOST < weight {
+                return seed;
+            }
+        }
+    }
+
+    /// Files the answer to a question dealt as `cards`, or returns `None`
+    /// if they aren't a question of this topic.
+    pub fn record(&mut self, cards: &[Card], result: &QuizResult) -> Option<()> {
+        let class = self.topic.class(cards)?;
+        self.turn = self.turn.saturating_add(1);
+
+        let last_box = BOX_INTERVALS.len() - 1;
+        let record = self.records.entry(class).or_default();
+        record.leitner_box = match result.grade {
+            Grade::Green => (record.leitner_box + 1).min(last_box),
+            Grade::Yellow => record.leitner_box,
+            Grade::Red => 0,
+        };
+        record.due = self.turn.saturating_add(BOX_INTERVALS[record.leitner_box]);
+        record.attempts = record.attempts.saturating_add(1);
+        record.total_error += result.error.abs();
+        if result.grade == Grade::Red {
+            record.misses = record.misses.saturating_add(1);
+        }
+        Some(())
+    }
+
+    /// What is known about `class`, named as `Topic::class` names it.
+    pub fn class_record(&self, class: &str) -> Option<&ClassRecord> {
+        self.records.get(class)
+    }
+
+    // Unseen classes weigh 1. Seen ones halve with each box, and count
+    // `DUE_BOOST` times over once due.
+    fn weight(&self, class: &str) -> f64 {
+        match self.records.get(class) {
+            None => 1.0,
+            Some(record) => {
+                let weight = 0.5f64.powi(record.leitner_box as i32);
+                if record.due <= self.turn { weight * DUE_BOOST } else { weight }
+            }
+        }
+    }
+
+    /// Reads `to_json` output, rejecting records that `record` couldn't
+    /// have written.
+    pub fn from_json(json: &str) -> Result<Scheduler, serde_json::Error> {
+        let scheduler: Scheduler = serde_json::from_str(json)?;
+        scheduler.check().map_err(de::Error::custom)?;
+        Ok(scheduler)
+    }
+
+    /// Whether every record is one `record` could have written.
+    pub fn check(&self) -> Result<(), &'static str> {
+        if self.records.values().any(|record| record.leitner_box >= BOX_INTERVALS.len()) {
+            return Err("Leitner box out of range");
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+    use crate::card::{cards_to_u32, parse_cards};
+
+    fn result(grade: Grade, error: f64) -> QuizResult {
+        QuizResult { guess: 50.0 + error, equity: 50.0, std_error: 0.0, error, grade, score: 0.0 }
+    }
+
+    fn dealt(scheduler: &Scheduler, rng: &mut ChaCha8Rng) -> String {
+        let seed = scheduler.next_seed(rng);
+        scheduler.topic.class(&Deck::with_seed(seed).draw(2)).unwrap()
+    }
+
+    #[test]
+    fn leitner_boxes() {
+        let mut scheduler = Scheduler::new(Topic::Preflop);
+        let suited_connector = parse_cards("6h5h").unwrap();
+
+        scheduler.record(&suited_connector, &result(Grade::Green, 4.0)).unwrap();
+        scheduler.record(&suited_connector, &result(Grade::Green, 2.0)).unwrap();
+        let record = scheduler.class_record("65s").unwrap();
+        assert_eq!((record.leitner_box, record.due), (2, 2 + BOX_INTERVALS[2]));
+
+        scheduler.record(&parse_cards("5d6d").unwrap(), &result(Grade::Yellow, -15.0)).unwrap();
+        assert_eq!(scheduler.class_record("65s").unwrap().leitner_box, 2);
+
+        scheduler.record(&suited_connector, &result(Grade::Red, 25.0)).unwrap();
+        let record = scheduler.class_record("65s").unwrap();
+        assert_eq!((record.leitner_box, record.due), (0, 4));
+        assert_eq!((record.attempts, record.misses), (4, 1));
+        assert!((record.mean_error() - 11.5).abs() < 1e-9);
+
+        assert_eq!(scheduler.record(&parse_cards("6h5h4h").unwrap(), &result(Grade::Red, 25.0)), None);
+        assert_eq!(scheduler.turn(), 4);
+    }
+
+    #[test]
+    fn deals_missed_classes_more_and_known_ones_less() {
+        let mut scheduler = Scheduler::new(Topic::Preflop);
+        for _ in 0..3 {
+            scheduler.record(&parse_cards("AsAd").unwrap(), &result(Grade::Green, 1.0)).unwrap();
+        }
+        scheduler.record(&parse_cards("7c2d").unwrap(), &result(Grade::Red, 30.0)).unwrap();
+
+        let mut rng = ChaCha8Rng::seed_from_u64(23);
+        let deals: Vec<String> = (0..4000).map(|_| dealt(&scheduler, &mut rng)).collect();
+        let share = |class: &str| deals.iter().filter(|c| *c == class).count() as f64 / deals.len() as f64;
+
+        // left alone, 72o comes up 12 times in 1326 and AA 6 times
+        assert!(share("72o") > 2.0 * 12.0 / 1326.0, "72o share was {}", share("72o"));
+        assert!(share("AA") < 0.5 * 6.0 / 1326.0, "AA share was {}", share("AA"));
+    }
+
+    #[test]
+    fn seed_deals_the_scheduled_hand_in_a_quiz() {
+        use crate::quiz::Quiz;
+        let scheduler = Scheduler::new(Topic::Preflop);
+        let seed = scheduler.next_seed(&mut ChaCha8Rng::seed_from_u64(5));
+        let mut quiz = Quiz::new();
+        assert_eq!(quiz.deal_with_seed(seed), cards_to_u32(&Deck::with_seed(seed).draw(2)));
+    }
+
+    #[test]
+    fn weak_spots_and_flops() {
+        let mut scheduler = Scheduler::new(Topic::Flop);
+        scheduler.record(&parse_cards("Ah Kh 7h").unwrap(), &result(Grade::Green, 5.0)).unwrap();
+        scheduler.record(&parse_cards("9s 8s 2d").unwrap(), &result(Grade::Yellow, 12.0)).unwrap();
+        scheduler.record(&parse_cards("Qc Qd 3h").unwrap(), &result(Grade::Yellow, 18.0)).unwrap();
+        // the same flop in other suits
+        scheduler.record(&parse_cards("Ad Kd 7d").unwrap(), &result(Grade::Green, 5.0)).unwrap();
+
+        let weak = scheduler.weak_spots(3);
+        assert_eq!(weak.len(), 3);
+        assert_eq!(scheduler.class_record(&weak[0]).unwrap().total_error, 18.0);
+        assert_eq!(scheduler.class_record(&weak[2]).unwrap().leitner_box, 2);
+        let seed = scheduler.next_seed(&mut ChaCha8Rng::seed_from_u64(1));
+        assert!(Topic::Flop.class(&Deck::with_seed(seed).draw(3)).is_some());
+    }
+
+    #[test]
+    fn state_round_trips_through_json() {
+        let mut scheduler = Scheduler::new(Topic::Preflop);
+        scheduler.record(&parse_cards("AhJc").unwrap(), &result(Grade::Red, -22.0)).unwrap();
+        scheduler.record(&parse_cards("TsTd").unwrap(), &result(Grade::Green, 3.0)).unwrap();
+
+        let json = scheduler.to_json();
+        assert!(json.contains("\"AJo\""), "{}", json);
+        assert_eq!(Scheduler::from_json(&json).unwrap(), scheduler);
+        assert!(Scheduler::from_json("{\"topic\":\"Turn\"}").is_err());
+    }
+
+    #[test]
+    fn rejects_records_it_could_not_have_written() {
+        let json = r#"{"topic":"Preflop","turn":1,"records":{"AA":{"leitner_box":5,"due":1,"attempts":1,"misses":0,"total_error":1.0}}}"#;
+        let error = Scheduler::from_json(json).unwrap_err();
+        assert!(error.to_string().contains("Leitner box"), "{}", error);
+        assert!(Scheduler::from_json(&json.replace("\"leitner_box\":5", "\"leitner_box\":4")).is_ok());
+    }
+
+    #[test]
+    fn turn_saturates() {
+        let json = r#"{"topic":"Preflop","turn":4294967290,"records":{"AA":{"leitner_box":4,"due":0,"attempts":1,"misses":0,"total_error":1.0}}}"#;
+        let mut scheduler = Scheduler::from_json(json).unwrap();
+        scheduler.record(&parse_cards("AsAd").unwrap(), &result(Grade::Yellow, 12.0)).unwrap();
+        assert_eq!(scheduler.turn(), 4294967291);
+        assert_eq!(scheduler.class_record("AA").unwrap().due, u32::MAX);
+    }
+
+    #[test]
+    fn counts_saturate() {
+        let json = r#"{"topic":"Preflop","turn":1,"records":{"AA":{"leitner_box":0,"due":0,"attempts":4294967295,"misses":4294967295,"total_error":1.0}}}"#;
+        let mut scheduler = Scheduler::from_json(json).unwrap();
+        scheduler.record(&parse_cards("AsAd").unwrap(), &result(Grade::Red, 40.0)).unwrap();
+        let record = scheduler.class_record("AA").unwrap();
+        assert_eq!((record.attempts, record.misses), (u32::MAX, u32::MAX));
+    }
+}
@@ -0,0 +1,283 @@
+use std::fmt;
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::prelude::*;
+use crate::quiz::Grade;
+
+/// What a guess is scored against: an equity and the standard error it was
+/// estimated with, both in percent. Exact figures have a standard error of 0.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Truth {
+    pub equity: f64,
+    pub std_error: f64,
+}
+
+/// A way of scoring an equity guess. Scores are penalties: 0 for a perfect
+/// guess and higher for worse ones, so totals can be compared within a rule
+/// but not across rules.
+pub trait ScoringRule: fmt::Debug {
+    fn name(&self) -> &'static str;
+
+    /// The penalty for guessing `guess` percent.
+    fn score(&self, guess: f64, truth: Truth) -> f64;
+
+    /// The colour to flash for `guess`. Unless a rule says otherwise, that is
+    /// the fixed 10 and 20 point bands.
+    fn grade(&self, guess: f64, truth: Truth) -> Grade {
+        Grade::from_error(guess - truth.equity)
+    }
+}
+
+/// Distance from the truth, in percentage points.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct AbsoluteError;
+
+impl ScoringRule for AbsoluteError {
+    fn name(&self) -> &'static str {
+        "absolute"
+    }
+
+    fn score(&self, guess: f64, truth: Truth) -> f64 {
+        (guess - truth.equity).abs()
+    }
+}
+
+/// Distance from the truth as a percentage of the truth, so missing a 10%
+/// hand by 5 points costs as much as missing a 60% hand by 30.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct RelativeError;
+
+impl ScoringRule for RelativeError {
+    fn name(&self) -> &'static str {
+        "relative"
+    }
+
+    fn score(&self, guess: f64, truth: Truth) -> f64 {
+        100.0 * (guess - truth.equity).abs() / truth.equity.max(f64::EPSILON)
+    }
+}
+
+/// Squared distance in percentage points, punishing big misses hardest.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SquaredError;
+
+impl ScoringRule for SquaredError {
+    fn name(&self) -> &'static str {
+        "squared"
+    }
+
+    fn score(&self, guess: f64, truth: Truth) -> f64 {
+        (guess - truth.equity).powi(2)
+    }
+}
+
+/// The log score of the guess read as a win probability, less that of the
+/// truth: the bits lost by forecasting with the guess. Guesses of 0 or 100
+/// are treated as 0.1 and 99.9 so a confident miss costs a lot rather than
+/// everything.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct LogScore;
+
+impl ScoringRule for LogScore {
+    fn name(&self) -> &'static str {
+        "log"
+    }
+
+    fn score(&self, guess: f64, truth: Truth) -> f64 {
+        let p = (guess / 100.0).clamp(0.001, 0.999);
+        let q = (truth.equity / 100.0).clamp(0.0, 1.0);
+        // q log(q / p), taking 0 log 0 as 0
+        let term = |q: f64, p: f64| if q > 0.0 { q * (q / p).log2() } else { 0.0 };
+        term(q, p) + term(1.0 - q, 1.0 - p)
+    }
+}
+
+/// Green and yellow bands of `green` and `yellow` points either side of the
+/// truth, each widened by `widen` standard errors so an estimated truth
+/// doesn't mark down a guess that is within its noise. Scores 0 for green,
+/// 1 for yellow and 2 for red.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Bands {
+    pub green: f64,
+    pub yellow: f64,
+    pub widen: f64,
+}
+
+impl Bands {
+    /// The trainer's original fixed bands.
+    pub const MVP: Bands = Bands { green: 10.0, yellow: 20.0, widen: 0.0 };
+}
+
+impl Default for Bands {
+    fn default() -> Self {
+        Bands::MVP
+    }
+}
+
+impl ScoringRule for Bands {
+    fn name(&self) -> &'static str {
+        if self.widen > 0.0 { "widened bands" } else { "bands" }
+    }
+
+    fn score(&self, guess: f64, truth: Truth) -> f64 {
+        match self.grade(guess, truth) {
+            Grade::Green => 0.0,
+            Grade::Yellow => 1.0,
+            Grade::Red => 2.0,
+        }
+    }
+
+    fn grade(&self, guess: f64, truth: Truth) -> Grade {
+        let error = (guess - truth.equity).abs();
+        let slack = self.widen * truth.std_error;
+        if error <= self.green + slack {
+            Grade::Green
+        } else if error <= self.yellow + slack {
+            Grade::Yellow
+        } else {
+            Grade::Red
+        }
+    }
+}
+
+/// The built-in rules, for picking one from JS.
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Scoring {
+    Bands,
+    /// The same bands widened by two standard errors.
+    WidenedBands,
+    Absolute,
+    Relative,
+    Squared,
+    Log,
+}
+
+impl Scoring {
+    pub const ALL: [Scoring; 6] = [
+        Scoring::Bands, Scoring::WidenedBands, Scoring::Absolute, Scoring::Relative, Scoring::Squared, Scoring::Log,
+    ];
+
+    /// The built-in rule whose `ScoringRule::name` is `name`.
+    pub fn from_name(name: &str) -> Option<Scoring> {
+        Scoring::ALL.into_iter().find(|scoring| scoring.rule().name() == name)
+    }
+
+    pub fn rule(self) -> Box<dyn ScoringRule> {
+        match self {
+            Scoring::Bands => Box::new(Bands::MVP),
+            Scoring::WidenedBands => Box::new(Bands { widen: 2.0, ..Bands::MVP }),
+            Scoring::Absolute => Box::new(AbsoluteError),
+            Scoring::Relative => Box::new(RelativeError),
+            Scoring::Squared => Box::new(SquaredError),
+            Scoring::Log => Box::new(LogScore),
+        }
+    }
+}
+
+/// Running totals of the guesses scored under one rule.
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ScoreTotals {
+    pub answered: u32,
+    /// Sum of the scores.
+    pub total: f64,
+    pub green: u32,
+    pub yellow: u32,
+    pub red: u32,
+}
+
+#[wasm_bindgen]
+impl ScoreTotals {
+    /// Average score per guess, once there has been one.
+    pub fn mean(&self) -> Option<f64> {
+        (self.answered > 0).then(|| self.total / self.answered as f64)
+    }
+}
+
+impl ScoreTotals {
+    pub fn add(&mut self, score: f64, grade: Grade) {
+        self.answered += 1;
+        self.total += score;
+        match grade {
+            Grade::Green => self.green += 1,
+            Grade::Yellow => self.yellow += 1,
+            Grade::Red => self.red += 1,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn exact(equity: f64) -> Truth {
+        Truth { equity, std_error: 0.0 }
+    }
+
+    #[test]
+    fn a_perfect_guess_scores_nothing() {
+        for scoring in Scoring::ALL {
+            let rule = scoring.rule();
+            for equity in [0.0, 12.5, 50.0, 85.2, 100.0] {
+                let score = rule.score(equity, exact(equity));
+                assert!(score.abs() < 0.01, "{} scored {} for a perfect guess of {}", rule.name(), score, equity);
+            }
+        }
+    }
+
+    #[test]
+    fn built_in_rules_are_found_by_name() {
+        for scoring in Scoring::ALL {
+            assert_eq!(Scoring::from_name(scoring.rule().name()), Some(scoring));
+        }
+        assert_eq!(Scoring::from_name("harsh"), None);
+    }
+
+    #[test]
+    fn error_rules() {
+        let truth = exact(40.0);
+        assert_eq!(AbsoluteError.score(30.0, truth), 10.0);
+        assert_eq!(AbsoluteError.score(50.0, truth), 10.0);
+        assert_eq!(RelativeError.score(30.0, truth), 25.0);
+        assert_eq!(RelativeError.score(10.0, exact(20.0)), RelativeError.score(30.0, exact(60.0)));
+        assert_eq!(SquaredError.score(30.0, truth), 100.0);
+        assert_eq!(SquaredError.score(10.0, truth), 900.0);
+    }
+
+    #[test]
+    fn log_score_is_bits_lost() {
+        // calling a coin flip 25% costs 0.5 log2(0.5 / 0.25) + 0.5 log2(0.5 / 0.75) bits
+        let cost = LogScore.score(25.0, exact(50.0));
+        assert!((cost - 0.2075).abs() < 1e-4, "cost was {}", cost);
+        // misses the same size cost more near the extremes
+        assert!(LogScore.score(80.0, exact(90.0)) > LogScore.score(40.0, exact(50.0)));
+        // a sure thing called impossible is expensive but finite
+        let confident_miss = LogScore.score(0.0, exact(100.0));
+        assert!(confident_miss.is_finite() && confident_miss > 9.0);
+    }
+
+    #[test]
+    fn bands_widen_with_std_error() {
+        let noisy = Truth { equity: 50.0, std_error: 1.5 };
+        assert_eq!(Bands::MVP.grade(62.0, noisy), Grade::Yellow);
+        assert_eq!(Bands::MVP.score(62.0, noisy), 1.0);
+        let widened = Bands { widen: 2.0, ..Bands::MVP };
+        assert_eq!(widened.grade(62.0, noisy), Grade::Green);
+        assert_eq!(widened.grade(74.0, noisy), Grade::Red);
+        assert_eq!(widened.score(74.0, noisy), 2.0);
+        // exact truths get no slack
+        assert_eq!(widened.grade(62.0, exact(50.0)), Grade::Yellow);
+    }
+
+    #[test]
+    fn totals_accumulate() {
+        let mut totals = ScoreTotals::default();
+        assert_eq!(totals.mean(), None);
+        totals.add(0.0, Grade::Green);
+        totals.add(2.0, Grade::Red);
+        totals.add(1.0, Grade::Yellow);
+        assert_eq!(totals.answered, 3);
+        assert_eq!((totals.green, totals.yellow, totals.red), (1, 1, 1));
+        assert_eq!(totals.mean(), Some(1.0));
+    }
+}
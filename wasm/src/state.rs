@@ -0,0 +1,141 @@
+use std::fmt;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{json, Value};
+
+/// Version of the JSON written by `Quiz::export_state`. Bump it whenever the
+/// saved shape changes, and add a step to `MIGRATIONS` that upgrades the
+/// previous version.
+pub const STATE_VERSION: u64 = 1;
+
+// MIGRATIONS[v] turns version v into version v + 1
+const MIGRATIONS: [fn(Value) -> Result<Value, StateError>; STATE_VERSION as usize] = [
+    from_scheduler,
+];
+
+#[derive(Debug)]
+pub enum StateError {
+    Json(serde_json::Error),
+    /// Saved by a newer build than this one.
+    UnsupportedVersion(u64),
+    /// Valid JSON, but not a saved state of any version.
+    NotState,
+    /// A saved state with a value that makes no sense, like an impossible hand.
+    Invalid(&'static str),
+}
+
+impl fmt::Display for StateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StateError::Json(e) => write!(f, "invalid saved state: {}", e),
+            StateError::UnsupportedVersion(v) => write!(f, "saved state version {} is newer than this build's {}", v, STATE_VERSION),
+            StateError::NotState => write!(f, "not a saved state"),
+            StateError::Invalid(what) => write!(f, "invalid saved state: {}", what),
+        }
+    }
+}
+
+impl std::error::Error for StateError {}
+
+impl From<serde_json::Error> for StateError {
+    fn from(e: serde_json::Error) -> Self {
+        StateError::Json(e)
+    }
+}
+
+/// `state` as JSON, stamped with `STATE_VERSION`.
+pub fn save<T: Serialize>(state: &T) -> String {
+    let mut value = serde_json::to_value(state).unwrap();
+    if let Value::Object(fields) = &mut value {
+        fields.insert("version".to_string(), json!(STATE_VERSION));
+    }
+    value.to_string()
+}
+
+/// Reads JSON written by `save` in this or any earlier version, migrating
+/// it to the current shape first.
+pub fn load<T: DeserializeOwned>(json: &str) -> Result<T, StateError> {
+    let mut value: Value = serde_json::from_str(json)?;
+    let mut version = match &value {
+        Value::Object(fields) => match fields.get("version") {
+            Some(v) => v.as_u64().ok_or(StateError::NotState)?,
+            None => 0,
+        },
+        _ => return Err(StateError::NotState),
+    };
+    if version > STATE_VERSION {
+        return Err(StateError::UnsupportedVersion(version));
+    }
+
+    while version < STATE_VERSION {
+        value = MIGRATIONS[version as usize](value)?;
+        version += 1;
+    }
+    Ok(serde_json::from_value(value)?)
+}
+
+/// Saves a `u64` as a string, since JavaScript numbers lose precision past
+/// 2^53. Use with `#[serde(with = "crate::state::u64_string")]`.
+pub mod u64_string {
+    use serde::{de, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(value: &u64, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(value)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u64, D::Error> {
+        String::deserialize(deserializer)?.parse().map_err(de::Error::custom)
+    }
+}
+
+// Version 0 is what `Scheduler::to_json` wrote before there was a saved
+// state: the scheduler alone.
+fn from_scheduler(scheduler: Value) -> Result<Value, StateError> {
+    if scheduler.get("topic").is_none() || scheduler.get("records").is_none() {
+        return Err(StateError::NotState);
+    }
+    Ok(json!({
+        "version": 1,
+        "opponents": 1,
+        "scoring": "bands",
+        "totals": { "answered": 0, "total": 0.0, "green": 0, "yellow": 0, "red": 0 },
+        "question": null,
+        "session": { "answers": [] },
+        "scheduler": scheduler,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Saved {
+        opponents: u32,
+        scoring: String,
+    }
+
+    #[test]
+    fn saves_with_a_version() {
+        let json = save(&Saved { opponents: 3, scoring: "log".to_string() });
+        let value: Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["version"], json!(STATE_VERSION));
+        assert_eq!(load::<Saved>(&json).unwrap(), Saved { opponents: 3, scoring: "log".to_string() });
+    }
+
+    #[test]
+    fn migrates_a_bare_scheduler() {
+        let saved: Saved = load(r#"{"topic":"Preflop","turn":2,"records":{}}"#).unwrap();
+        assert_eq!(saved, Saved { opponents: 1, scoring: "bands".to_string() });
+    }
+
+    #[test]
+    fn rejects_what_it_cannot_read() {
+        assert!(matches!(load::<Saved>(r#"{"version":99}"#), Err(StateError::UnsupportedVersion(99))));
+        assert!(matches!(load::<Saved>(r#"{"turn":2}"#), Err(StateError::NotState)));
+        assert!(matches!(load::<Saved>("[1, 2]"), Err(StateError::NotState)));
+        assert!(matches!(load::<Saved>(r#"{"version":"one"}"#), Err(StateError::NotState)));
+        assert!(matches!(load::<Saved>("{"), Err(StateError::Json(_))));
+    }
+}
@@ -0,0 +1,204 @@
+use std::fmt::Write;
+use serde::{Deserialize, Serialize};
+use crate::canonical::{PreflopClass, StartingHandGroup};
+use crate::card::Card;
+use crate::hand_history::Street;
+use crate::quiz::QuizResult;
+
+/// Width, in percentage points, of the guess buckets in a calibration curve.
+pub const CALIBRATION_BUCKET: f64 = 10.0;
+
+/// One answered question, as kept for the session report.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Answer {
+    pub class: PreflopClass,
+    pub street: Street,
+    pub guess: f64,
+    pub equity: f64,
+}
+
+/// Every answer given in a session, in order.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Session {
+    answers: Vec<Answer>,
+}
+
+impl Session {
+    pub fn new() -> Session {
+        Session::default()
+    }
+
+    pub fn answers(&self) -> &[Answer] {
+        &self.answers
+    }
+
+    /// Files the answer to a question about `hole_cards` on `street`, or
+    /// returns `None` if they aren't two different cards.
+    pub fn record(&mut self, hole_cards: &[Card], street: Street, result: &QuizResult) -> Option<()> {
+        let class = PreflopClass::from_cards(hole_cards)?;
+        self.answers.push(Answer { class, street, guess: result.guess, equity: result.equity });
+        Some(())
+    }
+
+    pub fn report(&self) -> Report {
+        let group = |name: String, answers: &[&Answer]| Group::new(name, answers);
+        let all: Vec<&Answer> = self.answers.iter().collect();
+        let by = |matches: &dyn Fn(&Answer) -> bool| -> Vec<&Answer> {
+            self.answers.iter().filter(|a| matches(a)).collect()
+        };
+
+        let by_category = StartingHandGroup::ALL.iter()
+            .map(|&kind| (kind.to_string(), by(&|a| a.class.group() == kind)))
+            .filter(|(_, answers)| !answers.is_empty())
+            .map(|(name, answers)| group(name, &answers))
+            .collect();
+
+        let by_street = [Street::Preflop, Street::Flop, Street::Turn, Street::River].iter()
+            .map(|&street| (format!("{:?}", street).to_lowercase(), by(&|a| a.street == street)))
+            .filter(|(_, answers)| !answers.is_empty())
+            .map(|(name, answers)| group(name, &answers))
+            .collect();
+
+        // a guess of 100 goes in the top bucket rather than one of its own
+        let buckets = (100.0 / CALIBRATION_BUCKET) as usize;
+        let bucket = |guess: f64| ((guess / CALIBRATION_BUCKET) as usize).min(buckets - 1);
+        let calibration = (0..buckets)
+            .map(|b| {
+                let (low, high) = (b as f64 * CALIBRATION_BUCKET, (b + 1) as f64 * CALIBRATION_BUCKET);
+                (format!("{}-{}", low, high), by(&|a| bucket(a.guess) == b))
+            })
+            .filter(|(_, answers)| !answers.is_empty())
+            .map(|(name, answers)| group(name, &answers))
+            .collect();
+
+        Report { overall: group("all".to_string(), &all), by_category, by_street, calibration }
+    }
+}
+
+/// How a set of guesses compared with the truth. Figures are in percent;
+/// with no answers they are all 0.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Group {
+    pub name: String,
+    pub answered: u32,
+    pub mean_guess: f64,
+    pub mean_equity: f64,
+    pub mean_absolute_error: f64,
+    /// `mean_guess - mean_equity`: positive when the hands were overrated.
+    pub bias: f64,
+}
+
+impl Group {
+    fn new(name: String, answers: &[&Answer]) -> Group {
+        let n = answers.len().max(1) as f64;
+        let mean = |f: &dyn Fn(&Answer) -> f64| answers.iter().map(|a| f(a)).sum::<f64>() / n;
+        let (mean_guess, mean_equity) = (mean(&|a| a.guess), mean(&|a| a.equity));
+        Group {
+            name,
+            answered: answers.len() as u32,
+            mean_guess,
+            mean_equity,
+            mean_absolute_error: mean(&|a| (a.guess - a.equity).abs()),
+            bias: mean_guess - mean_equity,
+        }
+    }
+}
+
+/// A session summed up: overall, by starting-hand group and street, and a
+/// calibration curve of the true equity behind each bucket of guesses.
+/// Groups with no answers are left out.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Report {
+    pub overall: Group,
+    pub by_category: Vec<Group>,
+    pub by_street: Vec<Group>,
+    /// Buckets of `CALIBRATION_BUCKET` points by guess, named like "40-50".
+    pub calibration: Vec<Group>,
+}
+
+impl Report {
+    /// The report as a single CSV table, one row per group, with a header.
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("section,group,answered,mean_guess,mean_equity,mean_absolute_error,bias\n");
+        let sections = [
+            ("overall", std::slice::from_ref(&self.overall)),
+            ("category", &self.by_category[..]),
+            ("street", &self.by_street[..]),
+            ("calibration", &self.calibration[..]),
+        ];
+        for (section, groups) in sections {
+            for g in groups {
+                writeln!(csv, "{},{},{},{:.2},{:.2},{:.2},{:.2}",
+                    section, g.name, g.answered, g.mean_guess, g.mean_equity, g.mean_absolute_error, g.bias).unwrap();
+            }
+        }
+        csv
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::card::parse_cards;
+    use crate::quiz::Grade;
+
+    fn answer(session: &mut Session, hand: &str, street: Street, guess: f64, equity: f64) {
+        let result = QuizResult { guess, equity, std_error: 0.0, error: guess - equity, grade: Grade::from_error(guess - equity), score: 0.0 };
+        session.record(&parse_cards(hand).unwrap(), street, &result).unwrap();
+    }
+
+    fn session() -> Session {
+        let mut session = Session::new();
+        answer(&mut session, "8h7h", Street::Preflop, 50.0, 40.0);
+        answer(&mut session, "6s5s", Street::Preflop, 45.0, 38.0);
+        answer(&mut session, "KdKc", Street::Preflop, 75.0, 82.0);
+        answer(&mut session, "Ah4h", Street::Flop, 62.0, 59.0);
+        answer(&mut session, "Tc2d", Street::Preflop, 100.0, 31.0);
+        session
+    }
+
+    #[test]
+    fn overall_error_and_bias() {
+        let report = session().report();
+        assert_eq!(report.overall.answered, 5);
+        assert!((report.overall.mean_absolute_error - (10.0 + 7.0 + 7.0 + 3.0 + 69.0) / 5.0).abs() < 1e-9);
+        assert!((report.overall.bias - (10.0 + 7.0 - 7.0 + 3.0 + 69.0) / 5.0).abs() < 1e-9);
+        assert_eq!(Session::new().report().overall.answered, 0);
+        assert_eq!(Session::new().report().overall.bias, 0.0);
+    }
+
+    #[test]
+    fn groups_by_category_and_street() {
+        let report = session().report();
+
+        let names: Vec<&str> = report.by_category.iter().map(|g| g.name.as_str()).collect();
+        assert_eq!(names, ["pair", "suited ace", "suited connector", "other offsuit"]);
+        // overrating suited connectors
+        let connectors = &report.by_category[2];
+        assert_eq!(connectors.answered, 2);
+        assert!((connectors.bias - 8.5).abs() < 1e-9);
+        assert!(report.by_category[0].bias < 0.0);
+
+        let streets: Vec<(&str, u32)> = report.by_street.iter().map(|g| (g.name.as_str(), g.answered)).collect();
+        assert_eq!(streets, [("preflop", 4), ("flop", 1)]);
+    }
+
+    #[test]
+    fn calibration_buckets_by_guess() {
+        let report = session().report();
+        let buckets: Vec<(&str, u32)> = report.calibration.iter().map(|g| (g.name.as_str(), g.answered)).collect();
+        assert_eq!(buckets, [("40-50", 1), ("50-60", 1), ("60-70", 1), ("70-80", 1), ("90-100", 1)]);
+        assert_eq!(report.calibration[4].mean_equity, 31.0);
+    }
+
+    #[test]
+    fn exports_csv() {
+        let csv = session().report().to_csv();
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines[0], "section,group,answered,mean_guess,mean_equity,mean_absolute_error,bias");
+        assert_eq!(lines[1], "overall,all,5,66.40,50.00,19.20,16.40");
+        assert!(lines.contains(&"category,suited connector,2,47.50,39.00,8.50,8.50"));
+        assert!(lines.contains(&"street,flop,1,62.00,59.00,3.00,3.00"));
+        assert_eq!(lines.len(), 1 + 1 + 4 + 2 + 5);
+    }
+}